@@ -1 +1,13 @@
-pub trait GraphicsBackend {}
+use celui_math::BlendMode;
+
+// --------------------------- GraphicsBackend ----------------------------- //
+
+pub trait GraphicsBackend {
+    /// Sets the blend mode used to combine subsequent draws with the framebuffer.
+    ///
+    /// Backends that only blend normally can keep the default, which ignores every other mode.
+    #[inline(always)]
+    fn set_blend_mode(&mut self, mode: BlendMode) {
+        let _ = mode;
+    }
+}
@@ -0,0 +1,189 @@
+//! The `HashMap` storage replaced by the SwissTable layout, kept as a baseline.
+//!
+//! Three parallel arrays of keys, values and `u64` hashes, with linear probing
+//! and tombstones. Only what the benchmarks use is kept.
+
+use core::{hash::BuildHasher, mem::MaybeUninit};
+
+use celui_collections::Vec;
+
+const EMPTY: u64 = 0; // Marker for empty slots
+const TOMBSTONE: u64 = 1; // Marker for deleted slots
+
+pub struct LinearMap<K, V, S> {
+    keys: Vec<MaybeUninit<K>>,
+    values: Vec<MaybeUninit<V>>,
+    hashes: Vec<u64>,
+    mask: usize,
+    elements: usize,
+    tombstone_count: usize,
+    hash_builder: S,
+}
+
+impl<K: core::hash::Hash + Eq, V, S: BuildHasher + Clone> LinearMap<K, V, S> {
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(8, hash_builder)
+    }
+
+    fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let capacity = capacity.next_power_of_two();
+
+        let mut keys = Vec::with_capacity(capacity);
+        let mut values = Vec::with_capacity(capacity);
+        let mut hashes = Vec::with_capacity(capacity);
+
+        // SAFETY: Keys and values are `MaybeUninit`, and `hashes` is filled right away
+        unsafe {
+            keys.set_len(capacity);
+            values.set_len(capacity);
+            hashes.set_len(capacity);
+        }
+
+        hashes.fill(EMPTY);
+
+        Self {
+            keys,
+            values,
+            hashes,
+            mask: capacity - 1,
+            elements: 0,
+            tombstone_count: 0,
+            hash_builder,
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let i = self.find(key)?;
+
+        // SAFETY: Slot is occupied (hash > TOMBSTONE), so value is initialized
+        Some(unsafe { &*self.values[i].as_ptr() })
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.elements + self.tombstone_count >= (self.hashes.len() * 3) / 4 {
+            self.rehash(self.hashes.len() * 2);
+        }
+
+        let hash = self.hash_key(&key);
+        let i = self.find_slot(hash, &key);
+
+        if self.hashes[i] > TOMBSTONE {
+            // SAFETY: Slot is occupied (hash > TOMBSTONE), so value is initialized
+            return Some(unsafe {
+                core::mem::replace(&mut self.values[i], MaybeUninit::new(value)).assume_init()
+            });
+        }
+
+        if self.hashes[i] == TOMBSTONE {
+            self.tombstone_count -= 1;
+        }
+
+        self.elements += 1;
+        self.hashes[i] = hash;
+        self.keys[i].write(key);
+        self.values[i].write(value);
+
+        None
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let i = self.find(key)?;
+
+        self.elements -= 1;
+        self.tombstone_count += 1;
+        self.hashes[i] = TOMBSTONE;
+
+        // SAFETY: Slot was occupied, and is now a tombstone so it won't be read again
+        unsafe {
+            self.keys[i].assume_init_drop();
+
+            Some(self.values[i].assume_init_read())
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        (0..self.hashes.len())
+            .filter(|&i| self.hashes[i] > TOMBSTONE)
+            // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
+            .map(|i| unsafe { (&*self.keys[i].as_ptr(), &*self.values[i].as_ptr()) })
+    }
+
+    fn hash_key(&self, key: &K) -> u64 {
+        self.hash_builder.hash_one(key).max(TOMBSTONE + 1)
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        if self.elements == 0 {
+            return None;
+        }
+
+        let i = self.find_slot(self.hash_key(key), key);
+
+        (self.hashes[i] > TOMBSTONE).then_some(i)
+    }
+
+    /// Returns the slot holding `key`, or the first free slot of its probe sequence.
+    fn find_slot(&self, hash: u64, key: &K) -> usize {
+        let mut i = (hash as usize) & self.mask;
+        let mut first_tombstone = None;
+
+        loop {
+            let current_hash = self.hashes[i];
+
+            if current_hash == EMPTY {
+                return first_tombstone.unwrap_or(i);
+            }
+
+            if current_hash == TOMBSTONE {
+                first_tombstone.get_or_insert(i);
+            } else if current_hash == hash {
+                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key is initialized
+                if unsafe { &*self.keys[i].as_ptr() } == key {
+                    return i;
+                }
+            }
+
+            i = (i + 1) & self.mask;
+        }
+    }
+
+    fn rehash(&mut self, capacity: usize) {
+        let mut new_map = Self::with_capacity_and_hasher(capacity, self.hash_builder.clone());
+
+        for i in 0..self.hashes.len() {
+            let hash = self.hashes[i];
+
+            if hash > TOMBSTONE {
+                self.hashes[i] = EMPTY;
+
+                // SAFETY: Slot was occupied, and is now marked empty so it won't be read again
+                unsafe {
+                    let key = self.keys[i].assume_init_read();
+                    let value = self.values[i].assume_init_read();
+                    let j = new_map.find_slot(hash, &key);
+
+                    new_map.elements += 1;
+                    new_map.hashes[j] = hash;
+                    new_map.keys[j].write(key);
+                    new_map.values[j].write(value);
+                }
+            }
+        }
+
+        *self = new_map;
+    }
+}
+
+impl<K, V, S> Drop for LinearMap<K, V, S> {
+    fn drop(&mut self) {
+        for i in 0..self.hashes.len() {
+            if self.hashes[i] > TOMBSTONE {
+                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
+                unsafe {
+                    self.keys[i].assume_init_drop();
+                    self.values[i].assume_init_drop();
+                }
+            }
+        }
+    }
+}
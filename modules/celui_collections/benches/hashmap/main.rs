@@ -0,0 +1,203 @@
+//! Benchmarks for `HashMap`, against the linear probing layout it replaced and `std`.
+//!
+//! Run with `cargo bench -p celui_collections --features std`. Every workload is
+//! timed a few times and the fastest run is reported, in nanoseconds per operation.
+
+mod linear_probing;
+
+use std::{
+    hash::BuildHasher,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use celui_collections::{
+    hash::{BuildQuickHasher, RandomState},
+    HashMap,
+};
+use linear_probing::LinearMap;
+
+const SIZES: [usize; 3] = [100, 10_000, 1_000_000];
+const RUNS: usize = 5;
+
+/// The operations every benchmarked map provides.
+trait Map<K> {
+    fn create() -> Self;
+    fn insert(&mut self, key: K, value: u64);
+    fn get(&self, key: &K) -> Option<&u64>;
+    fn remove(&mut self, key: &K) -> Option<u64>;
+    fn sum(&self) -> u64;
+}
+
+impl<K: std::hash::Hash + Eq, S: BuildHasher + Default> Map<K> for HashMap<K, u64, S> {
+    fn create() -> Self {
+        Self::with_hasher(S::default())
+    }
+
+    fn insert(&mut self, key: K, value: u64) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: &K) -> Option<&u64> {
+        HashMap::get(self, key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<u64> {
+        HashMap::remove(self, key)
+    }
+
+    fn sum(&self) -> u64 {
+        self.values().sum()
+    }
+}
+
+impl<K: std::hash::Hash + Eq, S: BuildHasher + Clone + Default> Map<K> for LinearMap<K, u64, S> {
+    fn create() -> Self {
+        Self::with_hasher(S::default())
+    }
+
+    fn insert(&mut self, key: K, value: u64) {
+        LinearMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: &K) -> Option<&u64> {
+        LinearMap::get(self, key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<u64> {
+        LinearMap::remove(self, key)
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(|(_, value)| value).sum()
+    }
+}
+
+impl<K: std::hash::Hash + Eq, S: BuildHasher + Default> Map<K>
+    for std::collections::HashMap<K, u64, S>
+{
+    fn create() -> Self {
+        Self::with_hasher(S::default())
+    }
+
+    fn insert(&mut self, key: K, value: u64) {
+        std::collections::HashMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: &K) -> Option<&u64> {
+        std::collections::HashMap::get(self, key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<u64> {
+        std::collections::HashMap::remove(self, key)
+    }
+
+    fn sum(&self) -> u64 {
+        self.values().sum()
+    }
+}
+
+/// Runs `f` `RUNS` times and returns the fastest run, in nanoseconds per operation.
+fn time<F: FnMut() -> usize>(mut f: F) -> f64 {
+    let mut best = Duration::MAX;
+    let mut operations = 1;
+
+    for _ in 0..RUNS {
+        let start = Instant::now();
+
+        operations = black_box(f());
+        best = best.min(start.elapsed());
+    }
+
+    best.as_nanos() as f64 / operations as f64
+}
+
+/// Times every workload on a map of type `M` with `keys`, and prints a row per workload.
+fn bench<K: Clone, M: Map<K>>(name: &str, keys: &[K], missing: &[K]) {
+    let filled = || {
+        let mut map = M::create();
+
+        for (i, key) in keys.iter().enumerate() {
+            map.insert(key.clone(), i as u64);
+        }
+
+        map
+    };
+
+    let insert = time(|| {
+        black_box(filled());
+
+        keys.len()
+    });
+
+    let map = filled();
+
+    let hit = time(|| {
+        for key in keys {
+            black_box(map.get(key));
+        }
+
+        keys.len()
+    });
+
+    let miss = time(|| {
+        for key in missing {
+            black_box(map.get(key));
+        }
+
+        missing.len()
+    });
+
+    let iterate = time(|| {
+        black_box(map.sum());
+
+        keys.len()
+    });
+
+    let mut map = map;
+
+    // Removes and reinserts half of the keys, which leaves tombstones in the old layout
+    let churn = time(|| {
+        for (i, key) in keys.iter().enumerate().step_by(2) {
+            black_box(map.remove(key));
+            map.insert(key.clone(), i as u64);
+        }
+
+        keys.len()
+    });
+
+    println!("{name:<28} {insert:>9.1} {hit:>9.1} {miss:>9.1} {iterate:>9.1} {churn:>9.1}");
+}
+
+/// Benchmarks every map with the given keys.
+fn bench_all<K: std::hash::Hash + Eq + Clone>(title: &str, keys: &[K], missing: &[K]) {
+    println!("\n{title}, {} keys", keys.len());
+    println!(
+        "{:<28} {:>9} {:>9} {:>9} {:>9} {:>9}",
+        "ns/op", "insert", "hit", "miss", "iterate", "churn"
+    );
+
+    bench::<K, HashMap<K, u64, BuildQuickHasher>>("swiss table, quick", keys, missing);
+    bench::<K, LinearMap<K, u64, BuildQuickHasher>>("linear probing, quick", keys, missing);
+    bench::<K, HashMap<K, u64, RandomState>>("swiss table, siphash", keys, missing);
+    bench::<K, LinearMap<K, u64, RandomState>>("linear probing, siphash", keys, missing);
+    bench::<K, std::collections::HashMap<K, u64>>("std", keys, missing);
+}
+
+fn main() {
+    for size in SIZES {
+        // Sequential ids, like generated widget ids
+        let ids: Vec<u64> = (0..size as u64).collect();
+        let missing_ids: Vec<u64> = (size as u64..2 * size as u64).collect();
+
+        bench_all("u64 ids", &ids, &missing_ids);
+
+        // Short strings, like widget names
+        if size <= 10_000 {
+            let names: Vec<String> = (0..size).map(|i| format!("widget_{i}")).collect();
+            let missing_names: Vec<String> = (0..size).map(|i| format!("missing_{i}")).collect();
+
+            bench_all("string names", &names, &missing_names);
+        }
+    }
+}
@@ -0,0 +1,1213 @@
+use core::mem::MaybeUninit;
+
+use crate::slots::Slots;
+use crate::string::{byte_to_char_index, char_to_byte_index, floor_grapheme_boundary, Graphemes};
+use crate::vec::merge_sort_with_buffer;
+
+pub use crate::slots::Drain;
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `ArrayVec` and returns its elements.
+pub struct IntoIter<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    current: usize, // Next index to yield from the front
+    end: usize,     // One past the next index to yield from the back
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+
+        // SAFETY: Elements in `current..end` are initialized and not yielded yet
+        let item = unsafe { self.buffer[self.current].assume_init_read() };
+
+        self.current += 1;
+
+        Some(item)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: Elements in `current..end` are initialized and not yielded yet
+        Some(unsafe { self.buffer[self.end].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> core::iter::FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: Elements in `current..end` were not yielded and are still initialized
+        unsafe {
+            let remaining = &mut self.buffer[self.current..self.end];
+
+            core::ptr::drop_in_place(remaining as *mut [MaybeUninit<T>] as *mut [T]);
+        }
+    }
+}
+
+// ----------------------------- CapacityError ----------------------------- //
+
+/// The error returned when an `ArrayVec` or `ArrayString` is too full for an element, which
+/// gives the element back.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Creates an error holding the element that didn't fit.
+    #[inline(always)]
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    /// Returns a reference to the element that didn't fit.
+    #[inline(always)]
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Returns the element that didn't fit.
+    #[inline(always)]
+    pub fn into_element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> core::fmt::Display for CapacityError<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
+
+/// Panics when an infallible method runs out of room.
+#[cold]
+#[inline(never)]
+fn capacity_overflow() -> ! {
+    panic!("Capacity overflow")
+}
+
+// ------------------------------- ArrayVec -------------------------------- //
+
+/// A vector with a fixed capacity of `N` elements, stored inline. It never allocates.
+///
+/// Has the same API as `Vec`. Growing past `N` elements panics with "Capacity overflow",
+/// while the `try_*` methods return a `CapacityError` holding the element instead.
+///
+/// # Example
+/// ```
+/// use celui_collections::arrayvec::{ArrayVec, CapacityError};
+///
+/// let mut children = ArrayVec::<u32, 4>::new();
+///
+/// children.extend([3, 1, 2]);
+/// children.sort();
+/// assert!(children == [1, 2, 3]);
+///
+/// children.push(4);
+/// assert!(children.is_full());
+/// assert!(children.try_push(5) == Err(CapacityError::new(5)));
+/// ```
+pub struct ArrayVec<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Creates a new, empty vector.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            buffer: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity of the vector, which is always `N`.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements that can still be added.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the vector holds `N` elements.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns a reference to the element at the given index, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at the given index, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_slice_mut().get_mut(index)
+    }
+
+    /// Returns a slice containing all elements of the vector.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `self.len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    /// Returns a mutable slice containing all elements of the vector.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        // SAFETY: The first `self.len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Returns a raw pointer to the vector's buffer.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const T {
+        self.buffer.as_ptr() as *const T
+    }
+
+    /// Returns a raw mutable pointer to the vector's buffer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.buffer.as_mut_ptr() as *mut T
+    }
+
+    /// Returns a raw mutable pointer to the buffer of the vector at `this`, without creating a
+    /// reference to the vector, so references to its other elements stay valid.
+    ///
+    /// # SAFETY
+    /// `this` must point to a live vector.
+    #[inline(always)]
+    pub(crate) const unsafe fn buffer_ptr(this: *mut Self) -> *mut T {
+        // SAFETY: The caller guarantees `this` is valid
+        unsafe { core::ptr::addr_of_mut!((*this).buffer) as *mut T }
+    }
+
+    /// Returns an iterator over the slice.
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the slice.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_slice_mut().iter_mut()
+    }
+
+    /// Adds an element to the end of the vector.
+    ///
+    /// # Panics
+    /// Panics if the vector is full.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            capacity_overflow();
+        }
+    }
+
+    /// Adds an element to the end of the vector, or returns an error if it is full.
+    ///
+    /// On failure, the vector is left unchanged and the error gives `value` back.
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        if self.len == N {
+            return Err(CapacityError::new(value));
+        }
+
+        self.buffer[self.len].write(value);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the last element from the vector and returns it, or `None` if empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: The element was initialized, and is no longer counted by the length
+        Some(unsafe { self.buffer[self.len].assume_init_read() })
+    }
+
+    /// Inserts an element at the given index, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or the vector is full.
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        if self.try_insert(index, element).is_err() {
+            capacity_overflow();
+        }
+    }
+
+    /// Inserts an element at the given index, or returns an error if the vector is full.
+    ///
+    /// On failure, the vector is left unchanged and the error gives `element` back.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        assert!(index <= self.len, "Index out of bounds");
+
+        if self.len == N {
+            return Err(CapacityError::new(element));
+        }
+
+        // SAFETY: There is room for one more element
+        unsafe { self.slots().insert(index, element) };
+
+        Ok(())
+    }
+
+    /// Removes and returns the element at the given index, shifting subsequent elements left.
+    #[inline(always)]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.slots().remove(index)
+    }
+
+    /// Removes and returns the element at the given index, replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    #[inline(always)]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.slots().swap_remove(index)
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`, preserving their order.
+    #[inline(always)]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        self.slots().retain(predicate);
+    }
+
+    /// Removes consecutive repeated elements.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same_bucket(current, previous)` returns `true`.
+    #[inline(always)]
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
+        self.slots().dedup_by(same_bucket);
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// The elements are removed even if the iterator is not fully consumed.
+    #[inline(always)]
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        self.slots().drain(range)
+    }
+
+    /// Splits the vector in two at `at`, returning the elements from `at` onwards.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "Index out of bounds");
+
+        let count = self.len - at;
+        let mut other = Self::new();
+
+        // SAFETY: `at..self.len` is initialized and `other` has room for all `N` elements
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), count);
+
+            self.len = at;
+            other.len = count;
+        }
+
+        other
+    }
+
+    /// Moves all elements of `other` to the end of the vector, leaving `other` empty.
+    ///
+    /// # Panics
+    /// Panics if the elements don't fit, in which case both vectors are left unchanged.
+    pub fn append<const M: usize>(&mut self, other: &mut ArrayVec<T, M>) {
+        let count = other.len;
+
+        self.reserve(count);
+
+        // SAFETY: There is room for `count` elements, and `other` gives up ownership of its
+        // elements
+        unsafe {
+            core::ptr::copy_nonoverlapping(other.as_ptr(), self.as_mut_ptr().add(self.len), count);
+
+            other.len = 0;
+            self.len += count;
+        }
+    }
+
+    /// Resizes the vector to `new_len`, filling new slots with clones of `value`.
+    ///
+    /// # Panics
+    /// Panics if `new_len` is more than `N`.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+
+        while self.len + 1 < new_len {
+            self.push(value.clone());
+        }
+
+        self.push(value);
+    }
+
+    /// Appends clones of all elements of `slice` to the vector.
+    ///
+    /// # Panics
+    /// Panics if the elements don't fit, in which case the vector is left unchanged.
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(slice.len());
+
+        for item in slice {
+            self.push(item.clone());
+        }
+    }
+
+    /// Sorts the vector, preserving the order of equal elements.
+    ///
+    /// Uses a merge sort with a scratch buffer of `N` elements on the stack.
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.merge_sort(&mut |a: &T, b: &T| a.lt(b));
+    }
+
+    /// Sorts the vector with a comparator, preserving the order of equal elements.
+    #[inline]
+    pub fn sort_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, mut compare: F) {
+        self.merge_sort(&mut |a: &T, b: &T| compare(a, b) == core::cmp::Ordering::Less);
+    }
+
+    /// Sorts the vector by a key, preserving the order of equal elements.
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.merge_sort(&mut |a: &T, b: &T| key(a).lt(&key(b)));
+    }
+
+    /// Sorts the vector with `is_less`, using scratch space on the stack.
+    #[inline]
+    fn merge_sort<F: FnMut(&T, &T) -> bool>(&mut self, is_less: &mut F) {
+        let mut buffer = MaybeUninit::<[T; N]>::uninit();
+
+        // SAFETY: `buffer` has room for all `N` elements, and is separate from the vector
+        unsafe { merge_sort_with_buffer(self.as_slice_mut(), buffer.as_mut_ptr().cast(), is_less) };
+    }
+
+    /// Fills the vector with elements by cloning `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.as_slice_mut().fill(value);
+    }
+
+    /// Removes all elements from the vector.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Sets the length of the vector.
+    ///
+    /// # SAFETY
+    /// The caller must ensure:
+    /// - `new_len` does not exceed `N`
+    /// - All elements at `old_len..new_len` are initialized
+    /// - The elements at `new_len..old_len` will be leaked if the length is being truncated,
+    ///   resources must be properly cleaned up if necessary
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= N);
+
+        self.len = new_len;
+    }
+
+    /// Extends the vector with the contents of an iterator.
+    ///
+    /// # Panics
+    /// Panics once the iterator yields more elements than fit.
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+
+    /// Truncates the vector to a new length, removing excess elements.
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.slots().truncate(new_len);
+    }
+
+    /// Ensures that the vector has room for at least `additional` more elements.
+    ///
+    /// # Panics
+    /// Panics if they don't fit.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        if self.try_reserve(additional).is_err() {
+            capacity_overflow();
+        }
+    }
+
+    /// Ensures that the vector has room for at least `additional` more elements, or returns
+    /// an error if they don't fit.
+    #[inline]
+    pub const fn try_reserve(&self, additional: usize) -> Result<(), CapacityError> {
+        if additional > N - self.len {
+            return Err(CapacityError::new(()));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a view of the vector's elements for the algorithms shared with `Vec`.
+    #[inline(always)]
+    fn slots(&mut self) -> Slots<'_, T> {
+        let ptr = self.as_mut_ptr();
+
+        // SAFETY: The buffer holds `self.len` initialized elements
+        unsafe { Slots::new(ptr, &mut self.len) }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    /// Clones every element. If a clone panics, the elements cloned so far are dropped.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut new_vec = Self::new();
+
+        for item in self {
+            // SAFETY: `new_vec` has as much room as `self`
+            unsafe { new_vec.try_push(item.clone()).unwrap_unchecked() };
+        }
+
+        new_vec
+    }
+
+    /// Clones `source` into `self`, reusing its existing elements.
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+
+        let (prefix, rest) = source.split_at(self.len);
+
+        self.as_slice_mut().clone_from_slice(prefix);
+        self.extend_from_slice(rest);
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // Prevent the vector from dropping the elements
+        let vec = core::mem::ManuallyDrop::new(self);
+
+        IntoIter {
+            // SAFETY: `vec` is never used or dropped again, so the buffer is moved out once
+            buffer: unsafe { core::ptr::read(&vec.buffer) },
+            current: 0,
+            end: vec.len,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for ArrayVec<T, N> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, const N: usize, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for ArrayVec<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_slice_mut()[index]
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for ArrayVec<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_slice_mut()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, N> {
+    /// Collects the iterator's elements.
+    ///
+    /// # Panics
+    /// Panics if it yields more than `N` elements.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+
+        vec.extend(iter);
+
+        vec
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        ArrayVec::extend(self, iter);
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayVec<T, N> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        let array = core::mem::ManuallyDrop::new(array);
+        let mut vec = Self::new();
+
+        // SAFETY: `vec` has room for `N` elements, and the array gives up ownership of them
+        unsafe {
+            core::ptr::copy_nonoverlapping(array.as_ptr(), vec.as_mut_ptr(), N);
+
+            vec.len = N;
+        }
+
+        vec
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<ArrayVec<U, M>>
+    for ArrayVec<T, N>
+{
+    #[inline(always)]
+    fn eq(&self, other: &ArrayVec<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for ArrayVec<T, N> {
+    #[inline(always)]
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<&[U]> for ArrayVec<T, N> {
+    #[inline(always)]
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for ArrayVec<T, N> {
+    #[inline(always)]
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for ArrayVec<T, N> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+// ----------------------------- FromUtf8Error ----------------------------- //
+
+/// The error returned by `ArrayString::from_utf8`, which gives the bytes back.
+pub struct FromUtf8Error<const N: usize> {
+    bytes: ArrayVec<u8, N>,
+    error: core::str::Utf8Error,
+}
+
+impl<const N: usize> FromUtf8Error<N> {
+    /// Returns the bytes that were not valid UTF-8.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns the bytes that were not valid UTF-8.
+    #[inline(always)]
+    pub fn into_bytes(self) -> ArrayVec<u8, N> {
+        self.bytes
+    }
+
+    /// Returns where and why the bytes are not valid UTF-8.
+    #[inline(always)]
+    pub const fn utf8_error(&self) -> core::str::Utf8Error {
+        self.error
+    }
+}
+
+// ------------------------------ ArrayString ------------------------------ //
+
+/// A UTF-8 string with a fixed capacity of `N` bytes, stored inline. It never allocates.
+///
+/// Has the same API as `String`. Growing past `N` bytes panics with "Capacity overflow",
+/// while the `try_*` methods return a `CapacityError` holding the text that didn't fit and
+/// leave the string unchanged.
+///
+/// # Example
+/// ```
+/// use celui_collections::ArrayString;
+/// use core::fmt::Write;
+///
+/// let mut label = ArrayString::<16>::new();
+///
+/// write!(label, "{} items", 42).unwrap();
+/// assert!(label == "42 items");
+///
+/// // Doesn't fit, so nothing is written and the text is handed back
+/// let error = label.try_push_str(", and then some").unwrap_err();
+/// assert_eq!(error.into_element(), ", and then some");
+/// assert!(label == "42 items");
+/// ```
+#[derive(Clone, Default)]
+pub struct ArrayString<const N: usize> {
+    bytes: ArrayVec<u8, N>, // Always valid UTF-8
+}
+
+impl<const N: usize> ArrayString<N> {
+    /// Creates a new, empty string.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            bytes: ArrayVec::new(),
+        }
+    }
+
+    /// Converts `bytes` to a string, or returns an error holding them if they are not valid
+    /// UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_collections::{ArrayString, ArrayVec};
+    ///
+    /// let bytes = ArrayVec::<u8, 3>::from([b'o', b'k', 0xff]);
+    /// let error = ArrayString::from_utf8(bytes).unwrap_err();
+    ///
+    /// assert_eq!(error.utf8_error().valid_up_to(), 2);
+    /// assert_eq!(error.as_bytes(), b"ok\xff");
+    /// ```
+    #[inline]
+    pub fn from_utf8(bytes: ArrayVec<u8, N>) -> Result<Self, FromUtf8Error<N>> {
+        match core::str::from_utf8(&bytes) {
+            Ok(_) => Ok(Self { bytes }),
+            Err(error) => Err(FromUtf8Error { bytes, error }),
+        }
+    }
+
+    /// Converts `bytes` to a string without checking that they are valid UTF-8.
+    ///
+    /// # SAFETY
+    /// `bytes` must be valid UTF-8.
+    #[inline(always)]
+    pub const unsafe fn from_utf8_unchecked(bytes: ArrayVec<u8, N>) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the length of the string, in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the capacity of the string in bytes, which is always `N`.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of bytes that can still be added.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.bytes.remaining_capacity()
+    }
+
+    /// Returns `true` if the string is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns `true` if the string holds `N` bytes.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.bytes.is_full()
+    }
+
+    /// Returns the string's bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns the string as a string slice.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The bytes are always valid UTF-8
+        unsafe { core::str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Returns the string as a mutable string slice.
+    #[inline(always)]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: The bytes are always valid UTF-8
+        unsafe { core::str::from_utf8_unchecked_mut(self.bytes.as_slice_mut()) }
+    }
+
+    /// Converts the string into its bytes.
+    #[inline(always)]
+    pub fn into_bytes(self) -> ArrayVec<u8, N> {
+        self.bytes
+    }
+
+    /// Appends a `char` to the end of the string.
+    ///
+    /// # Panics
+    /// Panics if it doesn't fit.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends a `char` to the end of the string, or returns an error holding it if it
+    /// doesn't fit.
+    #[inline]
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+            .map_err(|_| CapacityError::new(c))
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// # Panics
+    /// Panics if it doesn't fit, in which case the string is left unchanged.
+    #[inline]
+    pub fn push_str(&mut self, text: &str) {
+        if self.try_push_str(text).is_err() {
+            capacity_overflow();
+        }
+    }
+
+    /// Appends a string slice to the end of the string, or returns an error holding it if it
+    /// doesn't fit, leaving the string unchanged.
+    #[inline]
+    pub fn try_push_str<'a>(&mut self, text: &'a str) -> Result<(), CapacityError<&'a str>> {
+        if self.bytes.try_reserve(text.len()).is_err() {
+            return Err(CapacityError::new(text));
+        }
+
+        self.bytes.extend_from_slice(text.as_bytes());
+
+        Ok(())
+    }
+
+    /// Inserts a `char` at byte `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or not on a `char` boundary, or if the `char`
+    /// doesn't fit.
+    #[inline]
+    pub fn insert(&mut self, index: usize, c: char) {
+        self.insert_str(index, c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts a string slice at byte `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or not on a `char` boundary, or if `text` doesn't
+    /// fit.
+    pub fn insert_str(&mut self, index: usize, text: &str) {
+        assert!(self.is_char_boundary(index), "Index not on a char boundary");
+
+        self.push_str(text);
+        self.bytes[index..].rotate_right(text.len());
+    }
+
+    /// Shortens the string to `new_len` bytes. Does nothing if it is already shorter.
+    ///
+    /// # Panics
+    /// Panics if `new_len` is not on a `char` boundary.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            assert!(
+                self.is_char_boundary(new_len),
+                "Index not on a char boundary"
+            );
+
+            self.bytes.truncate(new_len);
+        }
+    }
+
+    /// Removes the last `char` of the string, and returns it, or `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.chars().next_back()?;
+
+        self.bytes.truncate(self.len() - c.len_utf8());
+
+        Some(c)
+    }
+
+    /// Removes the `char` starting at byte `index`, and returns it.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or not on a `char` boundary.
+    pub fn remove(&mut self, index: usize) -> char {
+        let Some(c) = self[index..].chars().next() else {
+            panic!("Index out of bounds");
+        };
+
+        // The `char` is moved to the end whole, then cut off
+        self.bytes[index..].rotate_left(c.len_utf8());
+        self.bytes.truncate(self.len() - c.len_utf8());
+
+        c
+    }
+
+    /// Removes all text from the string.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Returns the byte index of the `char` at position `char_index`, or the length if
+    /// `char_index` is the number of `char`s. Returns `None` if it is past that.
+    ///
+    /// Takes time linear in `char_index`.
+    #[inline(always)]
+    pub fn char_to_byte_index(&self, char_index: usize) -> Option<usize> {
+        char_to_byte_index(self, char_index)
+    }
+
+    /// Returns the position of the `char` starting at byte `byte_index`, or the number of
+    /// `char`s if `byte_index` is the length. Returns `None` if `byte_index` is out of bounds
+    /// or not on a `char` boundary.
+    ///
+    /// Takes time linear in `byte_index`.
+    #[inline(always)]
+    pub fn byte_to_char_index(&self, byte_index: usize) -> Option<usize> {
+        byte_to_char_index(self, byte_index)
+    }
+
+    /// Returns an iterator over the string's grapheme clusters; see `Graphemes`.
+    #[inline(always)]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::new(self.as_str())
+    }
+
+    /// Returns the largest grapheme cluster boundary at or before byte `index`, or the length
+    /// if `index` is past it.
+    #[inline(always)]
+    pub fn floor_grapheme_boundary(&self, index: usize) -> usize {
+        floor_grapheme_boundary(self, index)
+    }
+
+    /// Shortens the string to its first `count` grapheme clusters. Does nothing if it has
+    /// fewer.
+    #[inline]
+    pub fn truncate_graphemes(&mut self, count: usize) {
+        let len = self.graphemes().take(count).map(str::len).sum();
+
+        // Cluster boundaries are char boundaries
+        self.bytes.truncate(len);
+    }
+}
+
+impl<const N: usize> core::ops::Deref for ArrayString<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> core::ops::DerefMut for ArrayString<N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ArrayString<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> core::borrow::Borrow<str> for ArrayString<N> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ArrayString<M>> for ArrayString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &ArrayString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for ArrayString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ArrayString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<ArrayString<N>> for str {
+    #[inline(always)]
+    fn eq(&self, other: &ArrayString<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<ArrayString<N>> for &str {
+    #[inline(always)]
+    fn eq(&self, other: &ArrayString<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> PartialOrd for ArrayString<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for ArrayString<N> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> core::hash::Hash for ArrayString<N> {
+    /// Hashes like the `str` it holds, so `str` keys can look it up in a map.
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> core::fmt::Display for ArrayString<N> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> core::fmt::Write for ArrayString<N> {
+    /// Appends `text`, or returns an error if it doesn't fit.
+    #[inline]
+    fn write_str(&mut self, text: &str) -> core::fmt::Result {
+        self.try_push_str(text).map_err(|_| core::fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.try_push(c).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl<const N: usize> Extend<char> for ArrayString<N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.push(c);
+        }
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a char> for ArrayString<N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a str> for ArrayString<N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for text in iter {
+            self.push_str(text);
+        }
+    }
+}
+
+impl<const N: usize> FromIterator<char> for ArrayString<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut string = Self::new();
+
+        string.extend(iter);
+
+        string
+    }
+}
+
+impl<'a, const N: usize> FromIterator<&'a str> for ArrayString<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut string = Self::new();
+
+        string.extend(iter);
+
+        string
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for ArrayString<N> {
+    type Error = CapacityError<&'a str>;
+
+    /// Copies `text` into a new string, or returns an error holding it if it doesn't fit.
+    #[inline]
+    fn try_from(text: &'a str) -> Result<Self, Self::Error> {
+        let mut string = Self::new();
+
+        string.try_push_str(text)?;
+
+        Ok(string)
+    }
+}
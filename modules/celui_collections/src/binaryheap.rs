@@ -0,0 +1,442 @@
+use celui_sys::alloc::{AllocError, Allocator, Global};
+
+use crate::vec::{Drain, Hole, IntoIter, Vec};
+
+// ------------------------------- PeekMut --------------------------------- //
+
+/// A mutable reference to the greatest element of a `BinaryHeap`.
+///
+/// The element is moved back into place when the reference is dropped.
+pub struct PeekMut<'a, T: Ord, A: Allocator = Global> {
+    heap: &'a mut BinaryHeap<T, A>,
+}
+
+impl<T: Ord, A: Allocator> PeekMut<'_, T, A> {
+    /// Removes the element from the heap and returns it.
+    #[inline]
+    pub fn pop(this: Self) -> T {
+        // Nothing to move back into place
+        let mut this = core::mem::ManuallyDrop::new(this);
+
+        // SAFETY: A `PeekMut` is only created for a non-empty heap
+        unsafe { this.heap.pop().unwrap_unchecked() }
+    }
+}
+
+impl<T: Ord, A: Allocator> core::ops::Deref for PeekMut<'_, T, A> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.heap.data[0]
+    }
+}
+
+impl<T: Ord, A: Allocator> core::ops::DerefMut for PeekMut<'_, T, A> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.data[0]
+    }
+}
+
+impl<T: Ord, A: Allocator> Drop for PeekMut<'_, T, A> {
+    #[inline]
+    fn drop(&mut self) {
+        let len = self.heap.len();
+
+        // SAFETY: The heap is not empty
+        unsafe { self.heap.sift_down_range(0, len) };
+    }
+}
+
+// ------------------------------ BinaryHeap ------------------------------- //
+
+/// A priority queue, stored as a binary max-heap in a `Vec`.
+///
+/// `pop` returns the greatest element first. Wrap elements in `core::cmp::Reverse` to get
+/// the smallest first instead.
+///
+/// # Example
+/// ```
+/// use celui_collections::BinaryHeap;
+/// use core::cmp::Reverse;
+///
+/// // Timers, soonest deadline first
+/// let mut timers = BinaryHeap::new();
+///
+/// timers.push(Reverse((250, "blink caret")));
+/// timers.push(Reverse((16, "next frame")));
+/// timers.push(Reverse((500, "show tooltip")));
+///
+/// assert_eq!(timers.pop(), Some(Reverse((16, "next frame"))));
+/// assert_eq!(timers.peek(), Some(&Reverse((250, "blink caret"))));
+/// ```
+pub struct BinaryHeap<T, A: Allocator = Global> {
+    data: Vec<T, A>, // Every element is greater than or equal to its children
+}
+
+impl<T> BinaryHeap<T> {
+    /// Creates a new, empty heap with zero capacity.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new heap with a specified initial capacity.
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new heap with a specified initial capacity, or an error if allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> BinaryHeap<T, A> {
+    /// Creates a new, empty heap with zero capacity that will allocate from `allocator`.
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            data: Vec::new_in(allocator),
+        }
+    }
+
+    /// Creates a new heap with a specified initial capacity, allocated from `allocator`.
+    #[inline(always)]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self {
+            data: Vec::with_capacity_in(capacity, allocator),
+        }
+    }
+
+    /// Creates a new heap with a specified initial capacity, allocated from `allocator`, or
+    /// an error if allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        Ok(Self {
+            data: Vec::try_with_capacity_in(capacity, allocator)?,
+        })
+    }
+
+    /// Returns a reference to the heap's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Returns the number of elements in the heap.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the capacity of the heap.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns `true` if the heap contains no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the greatest element, or `None` if the heap is empty.
+    #[inline(always)]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the elements in heap order, which is arbitrary apart from the greatest one
+    /// being first.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Returns an iterator over the elements in heap order.
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.data.as_slice().iter()
+    }
+
+    /// Removes all elements in heap order and returns them as an iterator.
+    #[inline(always)]
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        self.data.drain(..)
+    }
+
+    /// Removes all elements from the heap, keeping its capacity.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Consumes the heap and returns its elements in heap order.
+    #[inline(always)]
+    pub fn into_vec(self) -> Vec<T, A> {
+        self.data
+    }
+
+    /// Ensures that the heap has at least `additional` capacity.
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    /// Ensures that the heap has at least `additional` capacity, or returns an error if
+    /// growing fails, leaving the heap unchanged.
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.data.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity of the heap to its length.
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+}
+
+impl<T: Ord, A: Allocator> BinaryHeap<T, A> {
+    /// Returns the greatest element mutably, or `None` if the heap is empty. The heap is
+    /// fixed up once the returned `PeekMut` is dropped.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, A>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(PeekMut { heap: self })
+    }
+
+    /// Adds an element to the heap.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+
+        // SAFETY: The element was just pushed
+        unsafe { self.sift_up(self.len() - 1) };
+    }
+
+    /// Adds an element to the heap, or returns an error if growing fails.
+    ///
+    /// On failure, `value` is dropped and the heap is left unchanged.
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), AllocError> {
+        self.data.try_push(value)?;
+
+        // SAFETY: The element was just pushed
+        unsafe { self.sift_up(self.len() - 1) };
+
+        Ok(())
+    }
+
+    /// Removes the greatest element and returns it, or `None` if the heap is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let mut item = self.data.pop()?;
+
+        if !self.is_empty() {
+            core::mem::swap(&mut item, &mut self.data[0]);
+
+            let len = self.len();
+
+            // SAFETY: The heap is not empty
+            unsafe { self.sift_down_range(0, len) };
+        }
+
+        Some(item)
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        let len = self.len();
+
+        self.data.retain(predicate);
+
+        if self.len() < len {
+            self.rebuild();
+        }
+    }
+
+    /// Moves all elements of `other` into the heap, leaving `other` empty.
+    pub fn append<B: Allocator>(&mut self, other: &mut BinaryHeap<T, B>) {
+        self.data.append(&mut other.data);
+        self.rebuild();
+    }
+
+    /// Consumes the heap and returns its elements in ascending order.
+    pub fn into_sorted_vec(mut self) -> Vec<T, A> {
+        for end in (1..self.len()).rev() {
+            self.data.swap(0, end);
+
+            // SAFETY: `end` is within bounds and not 0
+            unsafe { self.sift_down_range(0, end) };
+        }
+
+        self.data
+    }
+
+    /// Restores the heap order of all elements.
+    fn rebuild(&mut self) {
+        let len = self.len();
+
+        for index in (0..len / 2).rev() {
+            // SAFETY: `index` is within bounds
+            unsafe { self.sift_down_range(index, len) };
+        }
+    }
+
+    /// Moves the element at `index` up until its parent is not less than it.
+    ///
+    /// # SAFETY
+    /// `index` must be within bounds.
+    unsafe fn sift_up(&mut self, mut index: usize) {
+        let ptr = self.data.as_mut_ptr();
+
+        // SAFETY: `index` and its parents are within bounds. The element is held in `hole`,
+        // which writes it back into the single vacant slot on drop, even on panic.
+        unsafe {
+            let mut hole = Hole {
+                value: core::mem::ManuallyDrop::new(core::ptr::read(ptr.add(index))),
+                dest: ptr.add(index),
+            };
+
+            while index > 0 {
+                let parent = (index - 1) / 2;
+
+                if *hole.value <= *ptr.add(parent) {
+                    break;
+                }
+
+                core::ptr::copy_nonoverlapping(ptr.add(parent), ptr.add(index), 1);
+
+                index = parent;
+                hole.dest = ptr.add(index);
+            }
+        }
+    }
+
+    /// Moves the element at `index` down until its children in `..end` are not greater.
+    ///
+    /// # SAFETY
+    /// `index` must be less than `end`, which must be at most the length.
+    unsafe fn sift_down_range(&mut self, mut index: usize, end: usize) {
+        let ptr = self.data.as_mut_ptr();
+
+        // SAFETY: `index` and its children below `end` are within bounds. The element is held
+        // in `hole`, which writes it back into the single vacant slot on drop, even on panic.
+        unsafe {
+            let mut hole = Hole {
+                value: core::mem::ManuallyDrop::new(core::ptr::read(ptr.add(index))),
+                dest: ptr.add(index),
+            };
+
+            let mut child = 2 * index + 1;
+
+            while child < end {
+                // Follow the greater child
+                if child + 1 < end && *ptr.add(child) <= *ptr.add(child + 1) {
+                    child += 1;
+                }
+
+                if *hole.value >= *ptr.add(child) {
+                    break;
+                }
+
+                core::ptr::copy_nonoverlapping(ptr.add(child), ptr.add(index), 1);
+
+                index = child;
+                hole.dest = ptr.add(index);
+                child = 2 * index + 1;
+            }
+        }
+    }
+}
+
+impl<T: Ord, A: Allocator + Default> Default for BinaryHeap<T, A> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for BinaryHeap<T, A> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for BinaryHeap<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    /// Consumes the heap, returning its elements in heap order.
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a BinaryHeap<T, A> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BinaryHeap<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: Ord, A: Allocator> Extend<T> for BinaryHeap<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        self.reserve(iter.size_hint().0);
+
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Ord, A: Allocator> From<Vec<T, A>> for BinaryHeap<T, A> {
+    /// Turns `vec` into a heap in linear time.
+    #[inline]
+    fn from(vec: Vec<T, A>) -> Self {
+        let mut heap = Self { data: vec };
+
+        heap.rebuild();
+
+        heap
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for BinaryHeap<T> {
+    #[inline(always)]
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T, A: Allocator> From<BinaryHeap<T, A>> for Vec<T, A> {
+    #[inline(always)]
+    fn from(heap: BinaryHeap<T, A>) -> Self {
+        heap.data
+    }
+}
@@ -0,0 +1,1405 @@
+use core::{borrow::Borrow, ops::Bound};
+
+use celui_sys::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+use crate::{arrayvec::ArrayVec, vec::Vec};
+
+/// Marks a missing node: the root of an empty tree, the parent of the root and the end of the
+/// free list.
+const NONE: u32 = u32::MAX;
+
+/// The branching factor of the tree.
+const B: usize = 6;
+
+/// The maximum number of pairs in a node.
+const CAPACITY: usize = 2 * B - 1;
+
+/// The minimum number of pairs in a node other than the root.
+const MIN_LEN: usize = B - 1;
+
+// --------------------------------- Node ---------------------------------- //
+
+/// A node of a `BTreeMap`, which is a leaf if it has no edges.
+///
+/// Nodes have room for one pair over `CAPACITY`, which an insert may add before the node is
+/// split in two.
+#[derive(Clone)]
+struct Node<K, V> {
+    keys: ArrayVec<K, { CAPACITY + 1 }>,
+    values: ArrayVec<V, { CAPACITY + 1 }>,
+    edges: ArrayVec<u32, { CAPACITY + 2 }>, // Children, empty for leaves
+    parent: u32,                            // Parent node, or the next free node if freed
+    parent_index: u32,                      // Index of this node in its parent's edges
+}
+
+impl<K, V> Node<K, V> {
+    /// Creates a new, empty leaf.
+    #[inline(always)]
+    const fn new() -> Self {
+        Self {
+            keys: ArrayVec::new(),
+            values: ArrayVec::new(),
+            edges: ArrayVec::new(),
+            parent: NONE,
+            parent_index: 0,
+        }
+    }
+
+    /// Returns `true` if the node has no children.
+    #[inline(always)]
+    fn is_leaf(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+// -------------------------------- Handle --------------------------------- //
+
+/// A position in a node: either a key-value pair, or the edge before the pair at `index`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Handle {
+    node: u32,
+    index: u32,
+}
+
+impl Handle {
+    /// The edge of an empty tree.
+    const NONE: Self = Self {
+        node: NONE,
+        index: 0,
+    };
+
+    #[inline(always)]
+    const fn new(node: u32, index: usize) -> Self {
+        Self {
+            node,
+            index: index as u32,
+        }
+    }
+}
+
+// The functions below walk the tree through raw pointers and only ever borrow the fields they
+// read, so that `IterMut` can hand out references to values while it keeps walking.
+
+/// Returns the number of pairs in `node`.
+///
+/// # SAFETY
+/// `nodes` must point to the node pool and `node` must be a live node.
+#[inline(always)]
+unsafe fn node_len<K, V>(nodes: *const Node<K, V>, node: u32) -> usize {
+    // SAFETY: The caller guarantees `node` is in the pool
+    unsafe { (*nodes.add(node as usize)).keys.len() }
+}
+
+/// Returns the child at `index` of `node`, or `None` if `node` is a leaf.
+///
+/// # SAFETY
+/// `nodes` must point to the node pool and `node` must be a live node.
+#[inline(always)]
+unsafe fn child<K, V>(nodes: *const Node<K, V>, node: u32, index: usize) -> Option<u32> {
+    // SAFETY: The caller guarantees `node` is in the pool
+    unsafe { (*nodes.add(node as usize)).edges.get(index).copied() }
+}
+
+/// Returns the first leaf edge under `node`.
+///
+/// # SAFETY
+/// `nodes` must point to the node pool and `node` must be a live node.
+unsafe fn first_leaf_edge<K, V>(nodes: *const Node<K, V>, mut node: u32) -> Handle {
+    // SAFETY: Children of live nodes are live
+    unsafe {
+        while let Some(first) = child(nodes, node, 0) {
+            node = first;
+        }
+    }
+
+    Handle::new(node, 0)
+}
+
+/// Returns the last leaf edge under `node`.
+///
+/// # SAFETY
+/// `nodes` must point to the node pool and `node` must be a live node.
+unsafe fn last_leaf_edge<K, V>(nodes: *const Node<K, V>, mut node: u32) -> Handle {
+    // SAFETY: Children of live nodes are live
+    unsafe {
+        while let Some(last) = child(nodes, node, node_len(nodes, node)) {
+            node = last;
+        }
+
+        Handle::new(node, node_len(nodes, node))
+    }
+}
+
+/// Returns the pair right after the leaf edge `edge`, and moves `edge` past it.
+///
+/// # SAFETY
+/// `nodes` must point to the node pool, and `edge` must be a leaf edge with a pair after it.
+unsafe fn next_kv<K, V>(nodes: *const Node<K, V>, edge: &mut Handle) -> Handle {
+    let mut handle = *edge;
+
+    // SAFETY: A pair follows `edge`, so we find it before climbing past the root
+    unsafe {
+        while handle.index as usize == node_len(nodes, handle.node) {
+            let node = nodes.add(handle.node as usize);
+
+            handle = Handle {
+                node: (*node).parent,
+                index: (*node).parent_index,
+            };
+        }
+
+        *edge = match child(nodes, handle.node, handle.index as usize + 1) {
+            Some(next) => first_leaf_edge(nodes, next),
+            None => Handle::new(handle.node, handle.index as usize + 1),
+        };
+    }
+
+    handle
+}
+
+/// Returns the pair right before the leaf edge `edge`, and moves `edge` before it.
+///
+/// # SAFETY
+/// `nodes` must point to the node pool, and `edge` must be a leaf edge with a pair before it.
+unsafe fn next_back_kv<K, V>(nodes: *const Node<K, V>, edge: &mut Handle) -> Handle {
+    let mut handle = *edge;
+
+    // SAFETY: A pair precedes `edge`, so we find it before climbing past the root
+    unsafe {
+        while handle.index == 0 {
+            let node = nodes.add(handle.node as usize);
+
+            handle = Handle {
+                node: (*node).parent,
+                index: (*node).parent_index,
+            };
+        }
+
+        handle.index -= 1;
+
+        *edge = match child(nodes, handle.node, handle.index as usize) {
+            Some(previous) => last_leaf_edge(nodes, previous),
+            None => handle,
+        };
+    }
+
+    handle
+}
+
+// -------------------------------- Range ---------------------------------- //
+
+/// An iterator over a range of key-value pairs of a `BTreeMap`, in key order.
+pub struct Range<'a, K, V> {
+    nodes: *const Node<K, V>,
+    front: Handle, // Leaf edge before the next pair to yield from the front
+    back: Handle,  // Leaf edge after the next pair to yield from the back
+
+    _marker: core::marker::PhantomData<&'a [Node<K, V>]>,
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // SAFETY: The edges differ, so a pair sits between them
+        unsafe {
+            let handle = next_kv(self.nodes, &mut self.front);
+            let node = &*self.nodes.add(handle.node as usize);
+
+            Some((
+                &node.keys[handle.index as usize],
+                &node.values[handle.index as usize],
+            ))
+        }
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Range<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // SAFETY: The edges differ, so a pair sits between them
+        unsafe {
+            let handle = next_back_kv(self.nodes, &mut self.back);
+            let node = &*self.nodes.add(handle.node as usize);
+
+            Some((
+                &node.keys[handle.index as usize],
+                &node.values[handle.index as usize],
+            ))
+        }
+    }
+}
+
+impl<K, V> core::iter::FusedIterator for Range<'_, K, V> {}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes,
+            front: self.front,
+            back: self.back,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+// ------------------------------- RangeMut -------------------------------- //
+
+/// A mutable iterator over a range of key-value pairs of a `BTreeMap`, in key order.
+pub struct RangeMut<'a, K, V> {
+    nodes: *mut Node<K, V>,
+    front: Handle, // Leaf edge before the next pair to yield from the front
+    back: Handle,  // Leaf edge after the next pair to yield from the back
+
+    _marker: core::marker::PhantomData<&'a mut [Node<K, V>]>,
+}
+
+impl<'a, K, V> RangeMut<'a, K, V> {
+    /// Returns the pair at `handle`.
+    ///
+    /// # SAFETY
+    /// `handle` must be a pair of the range which was not yielded before.
+    #[inline(always)]
+    unsafe fn pair(&self, handle: Handle) -> (&'a K, &'a mut V) {
+        // SAFETY: Each pair is yielded once, so the value is not aliased. Only the value is
+        // borrowed mutably, so values yielded before stay valid.
+        unsafe {
+            let node = self.nodes.add(handle.node as usize);
+            let keys = &(*node).keys;
+            let values = core::ptr::addr_of_mut!((*node).values);
+
+            (
+                &keys[handle.index as usize],
+                &mut *ArrayVec::buffer_ptr(values).add(handle.index as usize),
+            )
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for RangeMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // SAFETY: The edges differ, so a pair sits between them
+        unsafe {
+            let handle = next_kv(self.nodes, &mut self.front);
+
+            Some(self.pair(handle))
+        }
+    }
+}
+
+impl<K, V> DoubleEndedIterator for RangeMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // SAFETY: The edges differ, so a pair sits between them
+        unsafe {
+            let handle = next_back_kv(self.nodes, &mut self.back);
+
+            Some(self.pair(handle))
+        }
+    }
+}
+
+impl<K, V> core::iter::FusedIterator for RangeMut<'_, K, V> {}
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the key-value pairs of a `BTreeMap`, in key order.
+pub struct Iter<'a, K, V> {
+    range: Range<'a, K, V>,
+    len: usize, // Number of pairs not yet yielded
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pair = self.range.next()?;
+
+        self.len -= 1;
+
+        Some(pair)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let pair = self.range.next_back()?;
+
+        self.len -= 1;
+
+        Some(pair)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> core::iter::FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            range: self.range.clone(),
+            len: self.len,
+        }
+    }
+}
+
+// ------------------------------- IterMut --------------------------------- //
+
+/// A mutable iterator over the key-value pairs of a `BTreeMap`, in key order.
+pub struct IterMut<'a, K, V> {
+    range: RangeMut<'a, K, V>,
+    len: usize, // Number of pairs not yet yielded
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pair = self.range.next()?;
+
+        self.len -= 1;
+
+        Some(pair)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let pair = self.range.next_back()?;
+
+        self.len -= 1;
+
+        Some(pair)
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> core::iter::FusedIterator for IterMut<'_, K, V> {}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `BTreeMap` and returns its key-value pairs, in key
+/// order.
+pub struct IntoIter<K, V, A: Allocator = Global> {
+    map: BTreeMap<K, V, A>,
+}
+
+impl<K, V, A: Allocator> Iterator for IntoIter<K, V, A> {
+    type Item = (K, V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.map.pop_first()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.map.len, Some(self.map.len))
+    }
+}
+
+impl<K, V, A: Allocator> DoubleEndedIterator for IntoIter<K, V, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.map.pop_last()
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for IntoIter<K, V, A> {}
+
+impl<K, V, A: Allocator> core::iter::FusedIterator for IntoIter<K, V, A> {}
+
+// ---------------------- Keys / Values / ValuesMut ------------------------ //
+
+/// An iterator over the keys of a `BTreeMap`, in order.
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+/// An iterator over the values of a `BTreeMap`, in key order.
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+/// A mutable iterator over the values of a `BTreeMap`, in key order.
+pub struct ValuesMut<'a, K, V> {
+    iter: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ValuesMut<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+// ------------------------------- BTreeMap -------------------------------- //
+
+/// An ordered map based on a B-tree.
+///
+/// Nodes hold up to 11 pairs and live in a single pool allocated from `A`, linked by index.
+/// Nodes freed by removals are reused by later inserts, and the pool is only released when the
+/// map is dropped.
+///
+/// # Example
+/// ```
+/// use celui_collections::BTreeMap;
+///
+/// let mut keyframes = BTreeMap::new();
+/// keyframes.insert(0, "idle");
+/// keyframes.insert(250, "hover");
+/// keyframes.insert(500, "pressed");
+/// keyframes.insert(1000, "idle");
+///
+/// let active: Vec<_> = keyframes.range(200..=500).map(|(_, frame)| *frame).collect();
+/// assert_eq!(active, ["hover", "pressed"]);
+///
+/// assert_eq!(keyframes.range(..300).next_back(), Some((&250, &"hover")));
+/// assert_eq!(keyframes.first_key_value(), Some((&0, &"idle")));
+/// ```
+pub struct BTreeMap<K, V, A: Allocator = Global> {
+    nodes: Vec<Node<K, V>, A>, // Node pool, including freed nodes
+    root: u32,                 // Root node, or `NONE` if the map is empty
+    height: usize,             // Number of levels below the root
+    free_head: u32,            // First node of the free list
+    len: usize,                // Number of pairs
+}
+
+impl<K, V> BTreeMap<K, V> {
+    /// Creates a new, empty map.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<K, V, A: Allocator> BTreeMap<K, V, A> {
+    /// Creates a new, empty map which allocates from `allocator`.
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            nodes: Vec::new_in(allocator),
+            root: NONE,
+            height: 0,
+            free_head: NONE,
+            len: 0,
+        }
+    }
+
+    /// Returns a reference to the map's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.nodes.allocator()
+    }
+
+    /// Returns the number of key-value pairs in the map.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no key-value pairs.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the pair with the smallest key.
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// Returns the pair with the largest key.
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Removes and returns the pair with the smallest key.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.root == NONE {
+            return None;
+        }
+
+        // SAFETY: The root is a live node
+        let edge = unsafe { first_leaf_edge(self.nodes.as_ptr(), self.root) };
+
+        Some(self.remove_kv(edge))
+    }
+
+    /// Removes and returns the pair with the largest key.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        if self.root == NONE {
+            return None;
+        }
+
+        // SAFETY: The root is a live node
+        let mut edge = unsafe { last_leaf_edge(self.nodes.as_ptr(), self.root) };
+
+        edge.index -= 1;
+
+        Some(self.remove_kv(edge))
+    }
+
+    /// Removes every pair, keeping the node pool for reuse.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = NONE;
+        self.height = 0;
+        self.free_head = NONE;
+        self.len = 0;
+
+        self.nodes.clear();
+    }
+
+    /// Returns an iterator over the key-value pairs, in key order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let (front, back) = self.full_edges();
+
+        Iter {
+            range: Range {
+                nodes: self.nodes.as_ptr(),
+                front,
+                back,
+
+                _marker: core::marker::PhantomData,
+            },
+            len: self.len,
+        }
+    }
+
+    /// Returns a mutable iterator over the key-value pairs, in key order.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let (front, back) = self.full_edges();
+
+        IterMut {
+            range: RangeMut {
+                nodes: self.nodes.as_mut_ptr(),
+                front,
+                back,
+
+                _marker: core::marker::PhantomData,
+            },
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over the keys, in order.
+    #[inline(always)]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the values, in key order.
+    #[inline(always)]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    /// Returns a mutable iterator over the values, in key order.
+    #[inline(always)]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+
+    /// Returns the first and last leaf edges of the tree.
+    fn full_edges(&self) -> (Handle, Handle) {
+        if self.root == NONE {
+            return (Handle::NONE, Handle::NONE);
+        }
+
+        let nodes = self.nodes.as_ptr();
+
+        // SAFETY: The root is a live node
+        unsafe {
+            (
+                first_leaf_edge(nodes, self.root),
+                last_leaf_edge(nodes, self.root),
+            )
+        }
+    }
+
+    /// Returns the node at `index`.
+    #[inline(always)]
+    fn node(&mut self, index: u32) -> &mut Node<K, V> {
+        &mut self.nodes[index as usize]
+    }
+
+    /// Takes a node from the free list, or pushes a new one.
+    ///
+    /// Room for the node must have been reserved, so that this can't fail halfway through an
+    /// insert.
+    fn alloc_node(&mut self) -> u32 {
+        if self.free_head != NONE {
+            let index = self.free_head;
+
+            self.free_head = self.node(index).parent;
+            *self.node(index) = Node::new();
+
+            return index;
+        }
+
+        self.nodes.push(Node::new());
+
+        (self.nodes.len() - 1) as u32
+    }
+
+    /// Puts an empty node on the free list.
+    fn free_node(&mut self, index: u32) {
+        let free_head = self.free_head;
+
+        self.node(index).parent = free_head;
+        self.free_head = index;
+    }
+
+    /// Points the children of `node` from `start` onwards back at `node`.
+    fn adopt_children(&mut self, node: u32, start: usize) {
+        for index in start..self.node(node).edges.len() {
+            let child = self.node(node).edges[index];
+            let child = self.node(child);
+
+            child.parent = node;
+            child.parent_index = index as u32;
+        }
+    }
+
+    /// Inserts a pair at the leaf edge `edge`, splitting nodes that overflow on the way up.
+    ///
+    /// The pool must have room for `self.height + 2` more nodes.
+    fn insert_at(&mut self, edge: Handle, key: K, value: V) {
+        self.len += 1;
+
+        if self.root == NONE {
+            let root = self.alloc_node();
+
+            self.node(root).keys.push(key);
+            self.node(root).values.push(value);
+            self.root = root;
+
+            return;
+        }
+
+        let leaf = self.node(edge.node);
+
+        leaf.keys.insert(edge.index as usize, key);
+        leaf.values.insert(edge.index as usize, value);
+
+        let mut node = edge.node;
+
+        while self.node(node).keys.len() > CAPACITY {
+            let right = self.alloc_node();
+
+            let left = self.node(node);
+            let keys = left.keys.split_off(B + 1);
+            let values = left.values.split_off(B + 1);
+            let edges = if left.is_leaf() {
+                ArrayVec::new()
+            } else {
+                left.edges.split_off(B + 1)
+            };
+
+            let median_key = left.keys.pop().unwrap();
+            let median_value = left.values.pop().unwrap();
+            let parent = left.parent;
+            let parent_index = left.parent_index as usize;
+
+            let right_node = self.node(right);
+
+            right_node.keys = keys;
+            right_node.values = values;
+            right_node.edges = edges;
+
+            self.adopt_children(right, 0);
+
+            if parent == NONE {
+                let root = self.alloc_node();
+                let root_node = self.node(root);
+
+                root_node.keys.push(median_key);
+                root_node.values.push(median_value);
+                root_node.edges.push(node);
+                root_node.edges.push(right);
+
+                self.adopt_children(root, 0);
+                self.root = root;
+                self.height += 1;
+
+                return;
+            }
+
+            let parent_node = self.node(parent);
+
+            parent_node.keys.insert(parent_index, median_key);
+            parent_node.values.insert(parent_index, median_value);
+            parent_node.edges.insert(parent_index + 1, right);
+
+            self.adopt_children(parent, parent_index + 1);
+
+            node = parent;
+        }
+    }
+
+    /// Removes the pair at `handle`, merging or refilling nodes that underflow on the way up.
+    fn remove_kv(&mut self, handle: Handle) -> (K, V) {
+        self.len -= 1;
+
+        let index = handle.index as usize;
+
+        if self.node(handle.node).is_leaf() {
+            let leaf = self.node(handle.node);
+            let pair = (leaf.keys.remove(index), leaf.values.remove(index));
+
+            self.rebalance(handle.node);
+
+            return pair;
+        }
+
+        // Replace the pair with its predecessor, which is always in a leaf
+        let left = self.node(handle.node).edges[index];
+
+        // SAFETY: Children of live nodes are live
+        let leaf = unsafe { last_leaf_edge(self.nodes.as_ptr(), left) }.node;
+
+        let leaf_node = self.node(leaf);
+        let key = leaf_node.keys.pop().unwrap();
+        let value = leaf_node.values.pop().unwrap();
+
+        let node = self.node(handle.node);
+        let pair = (
+            core::mem::replace(&mut node.keys[index], key),
+            core::mem::replace(&mut node.values[index], value),
+        );
+
+        self.rebalance(leaf);
+
+        pair
+    }
+
+    /// Restores the minimum length of `node` and its ancestors after a removal.
+    fn rebalance(&mut self, mut node: u32) {
+        loop {
+            let current = self.node(node);
+
+            if current.parent == NONE {
+                if current.keys.is_empty() {
+                    self.root = current.edges.pop().unwrap_or(NONE);
+                    self.free_node(node);
+
+                    if self.root != NONE {
+                        self.node(self.root).parent = NONE;
+                        self.height -= 1;
+                    }
+                }
+
+                return;
+            }
+
+            if current.keys.len() >= MIN_LEN {
+                return;
+            }
+
+            let parent = current.parent;
+            let index = current.parent_index as usize;
+            let parent_node = self.node(parent);
+            let edges = parent_node.edges.len();
+
+            if index > 0 {
+                let left = parent_node.edges[index - 1];
+
+                if self.node(left).keys.len() > MIN_LEN {
+                    self.rotate_right(parent, index - 1);
+
+                    return;
+                }
+            }
+
+            if index + 1 < edges {
+                let right = self.node(parent).edges[index + 1];
+
+                if self.node(right).keys.len() > MIN_LEN {
+                    self.rotate_left(parent, index);
+
+                    return;
+                }
+            }
+
+            self.merge(parent, index.saturating_sub(1));
+
+            node = parent;
+        }
+    }
+
+    /// Moves the last pair of the child at `index` of `parent` up, and the separating pair down
+    /// to the front of the next child.
+    fn rotate_right(&mut self, parent: u32, index: usize) {
+        let left = self.node(parent).edges[index];
+        let right = self.node(parent).edges[index + 1];
+
+        let left_node = self.node(left);
+        let key = left_node.keys.pop().unwrap();
+        let value = left_node.values.pop().unwrap();
+        let edge = left_node.edges.pop();
+
+        let parent_node = self.node(parent);
+        let key = core::mem::replace(&mut parent_node.keys[index], key);
+        let value = core::mem::replace(&mut parent_node.values[index], value);
+
+        let right_node = self.node(right);
+
+        right_node.keys.insert(0, key);
+        right_node.values.insert(0, value);
+
+        if let Some(edge) = edge {
+            right_node.edges.insert(0, edge);
+
+            self.adopt_children(right, 0);
+        }
+    }
+
+    /// Moves the first pair of the child after `index` of `parent` up, and the separating pair
+    /// down to the end of the child at `index`.
+    fn rotate_left(&mut self, parent: u32, index: usize) {
+        let left = self.node(parent).edges[index];
+        let right = self.node(parent).edges[index + 1];
+
+        let right_node = self.node(right);
+        let key = right_node.keys.remove(0);
+        let value = right_node.values.remove(0);
+        let edge = (!right_node.is_leaf()).then(|| right_node.edges.remove(0));
+
+        if edge.is_some() {
+            self.adopt_children(right, 0);
+        }
+
+        let parent_node = self.node(parent);
+        let key = core::mem::replace(&mut parent_node.keys[index], key);
+        let value = core::mem::replace(&mut parent_node.values[index], value);
+
+        let left_node = self.node(left);
+
+        left_node.keys.push(key);
+        left_node.values.push(value);
+
+        if let Some(edge) = edge {
+            left_node.edges.push(edge);
+
+            let last = left_node.edges.len() - 1;
+
+            self.adopt_children(left, last);
+        }
+    }
+
+    /// Merges the child after `index` of `parent` and their separating pair into the child at
+    /// `index`.
+    fn merge(&mut self, parent: u32, index: usize) {
+        let parent_node = self.node(parent);
+        let key = parent_node.keys.remove(index);
+        let value = parent_node.values.remove(index);
+        let left = parent_node.edges[index];
+        let right = parent_node.edges.remove(index + 1);
+
+        self.adopt_children(parent, index + 1);
+
+        let right_node = self.node(right);
+        let mut keys = core::mem::take(&mut right_node.keys);
+        let mut values = core::mem::take(&mut right_node.values);
+        let mut edges = core::mem::take(&mut right_node.edges);
+
+        self.free_node(right);
+
+        let left_node = self.node(left);
+        let start = left_node.edges.len();
+
+        left_node.keys.push(key);
+        left_node.values.push(value);
+        left_node.keys.append(&mut keys);
+        left_node.values.append(&mut values);
+        left_node.edges.append(&mut edges);
+
+        self.adopt_children(left, start);
+    }
+}
+
+impl<K: Ord, V, A: Allocator> BTreeMap<K, V, A> {
+    /// Returns `true` if the map contains `key`.
+    #[inline(always)]
+    pub fn contains_key<Q: Ord + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Returns a reference to the value of `key`.
+    #[inline]
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns the stored key and the value of `key`.
+    #[inline]
+    pub fn get_key_value<Q: Ord + ?Sized>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = self.search(key).ok()?;
+        let node = &self.nodes[handle.node as usize];
+
+        Some((
+            &node.keys[handle.index as usize],
+            &node.values[handle.index as usize],
+        ))
+    }
+
+    /// Returns a mutable reference to the value of `key`.
+    #[inline]
+    pub fn get_mut<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = self.search(key).ok()?;
+
+        Some(&mut self.node(handle.node).values[handle.index as usize])
+    }
+
+    /// Inserts a key-value pair, returning the old value if the key was present.
+    ///
+    /// The stored key is kept when the key was present.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.try_insert(key, value)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Inserts a key-value pair, returning the old value if the key was present, or an error if
+    /// allocation fails.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, AllocError> {
+        let edge = match self.search(&key) {
+            Ok(handle) => {
+                let old = &mut self.node(handle.node).values[handle.index as usize];
+
+                return Ok(Some(core::mem::replace(old, value)));
+            }
+            Err(edge) => edge,
+        };
+
+        // Every level may split, and the root may grow a new level
+        self.nodes.try_reserve(self.height + 2)?;
+
+        self.insert_at(edge, key, value);
+
+        Ok(None)
+    }
+
+    /// Removes `key` and returns its value.
+    #[inline]
+    pub fn remove<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` and returns the stored key and its value.
+    #[inline]
+    pub fn remove_entry<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = self.search(key).ok()?;
+
+        Some(self.remove_kv(handle))
+    }
+
+    /// Returns an iterator over the pairs whose keys are in `range`, in key order.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends.
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+        R: core::ops::RangeBounds<Q>,
+    {
+        let (front, back) = self.range_edges(&range);
+
+        Range {
+            nodes: self.nodes.as_ptr(),
+            front,
+            back,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns a mutable iterator over the pairs whose keys are in `range`, in key order.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends.
+    #[inline]
+    pub fn range_mut<Q, R>(&mut self, range: R) -> RangeMut<'_, K, V>
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+        R: core::ops::RangeBounds<Q>,
+    {
+        let (front, back) = self.range_edges(&range);
+
+        RangeMut {
+            nodes: self.nodes.as_mut_ptr(),
+            front,
+            back,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Keeps only the pairs for which `predicate` returns `true`.
+    ///
+    /// The kept pairs are moved into a new tree, which allocates a new node pool.
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut predicate: F)
+    where
+        A: Clone,
+    {
+        let allocator = self.allocator().clone();
+        let mut old = core::mem::replace(self, Self::new_in(allocator));
+
+        while let Some((key, mut value)) = old.pop_first() {
+            if predicate(&key, &mut value) {
+                let (_, edge) = self.full_edges();
+
+                self.nodes.reserve(self.height + 2);
+                self.insert_at(edge, key, value);
+            }
+        }
+    }
+
+    /// Finds the pair of `key`, or the leaf edge where it would be inserted.
+    fn search<Q: Ord + ?Sized>(&self, key: &Q) -> Result<Handle, Handle>
+    where
+        K: Borrow<Q>,
+    {
+        if self.root == NONE {
+            return Err(Handle::NONE);
+        }
+
+        let mut node = self.root;
+
+        loop {
+            let current = &self.nodes[node as usize];
+
+            match current
+                .keys
+                .binary_search_by(|probe| probe.borrow().cmp(key))
+            {
+                Ok(index) => return Ok(Handle::new(node, index)),
+                Err(index) if current.is_leaf() => return Err(Handle::new(node, index)),
+                Err(index) => node = current.edges[index],
+            }
+        }
+    }
+
+    /// Finds the leaf edges around the pairs whose keys are in `range`.
+    fn range_edges<Q, R>(&self, range: &R) -> (Handle, Handle)
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+        R: core::ops::RangeBounds<Q>,
+    {
+        let (start, end) = (range.start_bound(), range.end_bound());
+
+        match (start, end) {
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Included(end))
+                if start == end =>
+            {
+                return (Handle::NONE, Handle::NONE);
+            }
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) if start > end => panic!("Range start is greater than range end"),
+            _ => {}
+        }
+
+        (self.bound_edge(start, false), self.bound_edge(end, true))
+    }
+
+    /// Finds the leaf edge before the first key above `bound` if `upper` is `false`, or after
+    /// the last key below it if `upper` is `true`.
+    fn bound_edge<Q>(&self, bound: Bound<&Q>, upper: bool) -> Handle
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+    {
+        if self.root == NONE {
+            return Handle::NONE;
+        }
+
+        let mut node = self.root;
+
+        loop {
+            let current = &self.nodes[node as usize];
+            let keys = current.keys.as_slice();
+
+            let index = match bound {
+                Bound::Unbounded if upper => keys.len(),
+                Bound::Unbounded => 0,
+                Bound::Included(bound) if upper => {
+                    keys.partition_point(|key| key.borrow() <= bound)
+                }
+                Bound::Included(bound) => keys.partition_point(|key| key.borrow() < bound),
+                Bound::Excluded(bound) if upper => keys.partition_point(|key| key.borrow() < bound),
+                Bound::Excluded(bound) => keys.partition_point(|key| key.borrow() <= bound),
+            };
+
+            if current.is_leaf() {
+                return Handle::new(node, index);
+            }
+
+            node = current.edges[index];
+        }
+    }
+}
+
+impl<K, V, A: Allocator + Default> Default for BTreeMap<K, V, A> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<K: Clone, V: Clone, A: Allocator + Clone> Clone for BTreeMap<K, V, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            root: self.root,
+            height: self.height,
+            free_head: self.free_head,
+            len: self.len,
+        }
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, A: Allocator, B: Allocator> PartialEq<BTreeMap<K, V, B>>
+    for BTreeMap<K, V, A>
+{
+    #[inline]
+    fn eq(&self, other: &BTreeMap<K, V, B>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq, A: Allocator> Eq for BTreeMap<K, V, A> {}
+
+impl<K: core::hash::Hash, V: core::hash::Hash, A: Allocator> core::hash::Hash
+    for BTreeMap<K, V, A>
+{
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        for pair in self.iter() {
+            pair.hash(state);
+        }
+    }
+}
+
+impl<K, V, A, Q> core::ops::Index<&Q> for BTreeMap<K, V, A>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+    A: Allocator,
+{
+    type Output = V;
+
+    /// # Panics
+    /// Panics if the key is not present.
+    #[inline]
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("Key not found")
+    }
+}
+
+impl<K, V, A: Allocator> IntoIterator for BTreeMap<K, V, A> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { map: self }
+    }
+}
+
+impl<'a, K, V, A: Allocator> IntoIterator for &'a BTreeMap<K, V, A> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, A: Allocator> IntoIterator for &'a mut BTreeMap<K, V, A> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Ord, V, A: Allocator> Extend<(K, V)> for BTreeMap<K, V, A> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: Ord + Copy, V: Copy, A: Allocator> Extend<(&'a K, &'a V)> for BTreeMap<K, V, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BTreeMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<K: Ord, V, const N: usize> From<[(K, V); N]> for BTreeMap<K, V> {
+    #[inline]
+    fn from(array: [(K, V); N]) -> Self {
+        Self::from_iter(array)
+    }
+}
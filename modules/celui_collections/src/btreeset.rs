@@ -0,0 +1,370 @@
+use core::borrow::Borrow;
+
+use celui_sys::alloc::{AllocError, Allocator, Global};
+
+use crate::{btreemap, btreemap::BTreeMap};
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the values of a `BTreeSet`, in order.
+pub struct Iter<'a, T> {
+    iter: btreemap::Keys<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+// -------------------------------- Range ---------------------------------- //
+
+/// An iterator over a range of values of a `BTreeSet`, in order.
+pub struct Range<'a, T> {
+    range: btreemap::Range<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Range<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|(value, _)| value)
+    }
+}
+
+impl<T> DoubleEndedIterator for Range<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|(value, _)| value)
+    }
+}
+
+impl<T> Clone for Range<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            range: self.range.clone(),
+        }
+    }
+}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `BTreeSet` and returns its values, in order.
+pub struct IntoIter<T, A: Allocator = Global> {
+    iter: btreemap::IntoIter<T, (), A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(value, _)| value)
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+// ------------------------------- BTreeSet -------------------------------- //
+
+/// An ordered set, stored as a `BTreeMap` with `()` values.
+///
+/// # Example
+/// ```
+/// use celui_collections::BTreeSet;
+///
+/// let mut z_indices = BTreeSet::from([10, -1, 3]);
+/// assert!(z_indices.insert(0));
+/// assert!(!z_indices.insert(3));
+///
+/// let above: Vec<_> = z_indices.range(0..).copied().collect();
+/// assert_eq!(above, [0, 3, 10]);
+/// assert_eq!(z_indices.first(), Some(&-1));
+/// ```
+pub struct BTreeSet<T, A: Allocator = Global> {
+    map: BTreeMap<T, (), A>,
+}
+
+impl<T> BTreeSet<T> {
+    /// Creates a new, empty set.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> BTreeSet<T, A> {
+    /// Creates a new, empty set which allocates from `allocator`.
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            map: BTreeMap::new_in(allocator),
+        }
+    }
+
+    /// Returns a reference to the set's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.map.allocator()
+    }
+
+    /// Returns the number of values in the set.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no values.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the smallest value.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first_key_value().map(|(value, _)| value)
+    }
+
+    /// Returns the largest value.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last_key_value().map(|(value, _)| value)
+    }
+
+    /// Removes and returns the smallest value.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.map.pop_first().map(|(value, _)| value)
+    }
+
+    /// Removes and returns the largest value.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.map.pop_last().map(|(value, _)| value)
+    }
+
+    /// Removes every value, keeping the node pool for reuse.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the values, in order.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.map.keys(),
+        }
+    }
+}
+
+impl<T: Ord, A: Allocator> BTreeSet<T, A> {
+    /// Returns `true` if the set contains the given value.
+    #[inline(always)]
+    pub fn contains<Q: Ord + ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the value in the set equal to the given one, or `None`.
+    #[inline]
+    pub fn get<Q: Ord + ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+    {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// Returns `true` if the value was inserted, or `false` if it was already present, in
+    /// which case the value in the set is kept and `value` is dropped.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Adds a value to the set, or returns an error if allocation fails.
+    #[inline]
+    pub fn try_insert(&mut self, value: T) -> Result<bool, AllocError> {
+        Ok(self.map.try_insert(value, ())?.is_none())
+    }
+
+    /// Removes a value from the set, and returns `true` if it was present.
+    #[inline]
+    pub fn remove<Q: Ord + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set equal to the given one, or `None`.
+    #[inline]
+    pub fn take<Q: Ord + ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+
+    /// Returns an iterator over the values in `range`, in order.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends.
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, T>
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q>,
+        R: core::ops::RangeBounds<Q>,
+    {
+        Range {
+            range: self.map.range(range),
+        }
+    }
+
+    /// Keeps only the values for which `f` returns `true`, removing the others.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F)
+    where
+        A: Clone,
+    {
+        self.map.retain(|value, _| f(value));
+    }
+}
+
+impl<T, A: Allocator + Default> Default for BTreeSet<T, A> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::default(),
+        }
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for BTreeSet<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, A: Allocator, B: Allocator> PartialEq<BTreeSet<T, B>> for BTreeSet<T, A> {
+    #[inline]
+    fn eq(&self, other: &BTreeSet<T, B>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for BTreeSet<T, A> {}
+
+impl<T: core::hash::Hash, A: Allocator> core::hash::Hash for BTreeSet<T, A> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for BTreeSet<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a BTreeSet<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Ord, A: Allocator> Extend<T> for BTreeSet<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+impl<'a, T: Ord + Copy, A: Allocator> Extend<&'a T> for BTreeSet<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BTreeSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for BTreeSet<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter(array)
+    }
+}
@@ -0,0 +1,291 @@
+//! Hashers for `HashMap`.
+//!
+//! The default, `RandomState`, builds `SipHasher13`s with random keys, so the
+//! layout of a map can't be predicted from its keys. This keeps maps fed with
+//! user input (text, file names, network data) safe from collision attacks.
+//!
+//! With the `std` feature, keys are drawn from the standard library's random
+//! source. Without it, they are derived from a global seed, which should be set
+//! once at startup from whatever entropy the platform has, with `set_hash_seed`.
+//!
+//! `QuickHasher` is much faster on short keys but offers no such protection. It
+//! can be opted into with `BuildQuickHasher` for maps whose keys are trusted.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+// 32-bit atomics with plain loads and stores, which every target has; many MCUs lack 64-bit ones
+static HASH_SEED_LOW: AtomicU32 = AtomicU32::new(0xAA6C_78A5); // Set with `set_hash_seed`
+static HASH_SEED_HIGH: AtomicU32 = AtomicU32::new(0x2D35_8DCC);
+static KEY_COUNTER: AtomicU32 = AtomicU32::new(0); // Gives every `RandomState` different keys
+
+/// Sets the global seed mixed into the keys of every new `RandomState`.
+///
+/// Without the `std` feature, this is the only source of randomness, so it should be called at
+/// startup with a value that is hard to guess. Maps created before the call keep their keys.
+#[inline]
+pub fn set_hash_seed(seed: u64) {
+    HASH_SEED_LOW.store(seed as u32, Ordering::Relaxed);
+    HASH_SEED_HIGH.store((seed >> 32) as u32, Ordering::Relaxed);
+}
+
+/// Returns a different count on every call.
+#[inline(always)]
+fn next_count() -> u32 {
+    #[cfg(target_has_atomic = "32")]
+    {
+        KEY_COUNTER.fetch_add(1, Ordering::Relaxed)
+    }
+
+    // Without compare-and-swap, a race can only give two maps the same keys
+    #[cfg(not(target_has_atomic = "32"))]
+    {
+        let count = KEY_COUNTER.load(Ordering::Relaxed);
+
+        KEY_COUNTER.store(count.wrapping_add(1), Ordering::Relaxed);
+
+        count
+    }
+}
+
+/// Returns a fresh pair of SipHash keys.
+fn random_keys() -> (u64, u64) {
+    let count = u64::from(next_count());
+    let seed = u64::from(HASH_SEED_HIGH.load(Ordering::Relaxed)) << 32
+        | u64::from(HASH_SEED_LOW.load(Ordering::Relaxed));
+
+    let k0 = mix(seed ^ count.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    let k1 = mix(k0 ^ seed.rotate_left(32));
+
+    #[cfg(feature = "std")]
+    {
+        let (std_k0, std_k1) = std_keys();
+
+        (k0 ^ std_k0, k1 ^ std_k1)
+    }
+
+    #[cfg(not(feature = "std"))]
+    (k0, k1)
+}
+
+/// Returns a pair of random keys from the standard library's `RandomState`.
+#[cfg(feature = "std")]
+fn std_keys() -> (u64, u64) {
+    use core::hash::BuildHasher;
+
+    let state = std::collections::hash_map::RandomState::new();
+
+    (state.hash_one(0u64), state.hash_one(1u64))
+}
+
+/// The SplitMix64 finalizer, which spreads every input bit over the output.
+#[inline(always)]
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    x ^ (x >> 31)
+}
+
+// ----------------------------- RandomState ------------------------------- //
+
+/// The default `BuildHasher` of `HashMap`, which builds `SipHasher13`s with random keys.
+///
+/// Every `RandomState` gets its own keys, and every hasher it builds uses them.
+#[derive(Clone)]
+pub struct RandomState {
+    k0: u64,
+    k1: u64,
+}
+
+impl RandomState {
+    /// Creates a `RandomState` with fresh random keys.
+    #[inline]
+    pub fn new() -> Self {
+        let (k0, k1) = random_keys();
+
+        Self { k0, k1 }
+    }
+}
+
+impl Default for RandomState {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::hash::BuildHasher for RandomState {
+    type Hasher = SipHasher13;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.k0, self.k1)
+    }
+}
+
+// ----------------------------- SipHasher13 ------------------------------- //
+
+/// An implementation of SipHash 1-3, a keyed hash function that resists collision attacks.
+///
+/// This is the same function the standard library's `HashMap` uses by default.
+#[derive(Clone)]
+pub struct SipHasher13 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    tail: u64,     // Bytes not yet compressed, in little-endian order
+    tail_len: u32, // Number of bytes in `tail`
+    length: u64,   // Number of bytes written so far
+}
+
+impl SipHasher13 {
+    /// Creates a hasher with both keys set to `0`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_with_keys(0, 0)
+    }
+
+    /// Creates a hasher with the given keys.
+    #[inline(always)]
+    pub const fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            v0: k0 ^ 0x736F_6D65_7073_6575,
+            v1: k1 ^ 0x646F_7261_6E64_6F6D,
+            v2: k0 ^ 0x6C79_6765_6E65_7261,
+            v3: k1 ^ 0x7465_6462_7974_6573,
+            tail: 0,
+            tail_len: 0,
+            length: 0,
+        }
+    }
+
+    /// Mixes the four state words together.
+    #[inline(always)]
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13) ^ self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16) ^ self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21) ^ self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17) ^ self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    /// Compresses one 8-byte word of input, with a single round.
+    #[inline(always)]
+    fn compress(&mut self, word: u64) {
+        self.v3 ^= word;
+        self.round();
+        self.v0 ^= word;
+    }
+}
+
+impl Default for SipHasher13 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::hash::Hasher for SipHasher13 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        // Top up the tail left over from the previous write first
+        if self.tail_len != 0 {
+            let taken = bytes.len().min(8 - self.tail_len as usize);
+
+            for (i, &byte) in bytes[..taken].iter().enumerate() {
+                self.tail |= (byte as u64) << (8 * (self.tail_len as usize + i));
+            }
+
+            self.tail_len += taken as u32;
+            bytes = &bytes[taken..];
+
+            if self.tail_len < 8 {
+                return;
+            }
+
+            self.compress(self.tail);
+            self.tail = 0;
+            self.tail_len = 0;
+        }
+
+        let mut words = bytes.chunks_exact(8);
+
+        for word in &mut words {
+            self.compress(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+
+        for (i, &byte) in words.remainder().iter().enumerate() {
+            self.tail |= (byte as u64) << (8 * i);
+        }
+
+        self.tail_len = words.remainder().len() as u32;
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let last = ((self.length & 0xFF) << 56) | self.tail;
+
+        state.compress(last);
+        state.v2 ^= 0xFF;
+
+        for _ in 0..3 {
+            state.round();
+        }
+
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
+
+// ----------------------------- QuickHasher ------------------------------- //
+
+/// A `BuildHasher` for `QuickHasher`, to opt a `HashMap` into it.
+///
+/// # Example
+/// ```
+/// use celui_collections::{hash::BuildQuickHasher, HashMap};
+///
+/// // Widget ids are generated by us, so there's no need for a keyed hash
+/// let mut map = HashMap::with_hasher(BuildQuickHasher::default());
+/// map.insert(42u32, "button");
+///
+/// assert_eq!(map[&42], "button");
+/// ```
+pub type BuildQuickHasher = core::hash::BuildHasherDefault<QuickHasher>;
+
+/// A simple, fast hasher for trusted keys.
+///
+/// Uses a multiplication-based hashing strategy with wrapping arithmetic. It is not keyed, so
+/// colliding keys are easy to find; only use it for keys that don't come from user input.
+#[derive(Default)]
+pub struct QuickHasher {
+    value: u64,
+}
+
+impl QuickHasher {
+    /// Creates a new `QuickHasher` with an initial value of `0`.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { value: 0 }
+    }
+}
+
+impl core::hash::Hasher for QuickHasher {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.value
+    }
+
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.value = self.value.wrapping_mul(31).wrapping_add(byte as u64);
+        }
+    }
+}
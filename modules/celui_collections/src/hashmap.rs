@@ -1,113 +1,952 @@
-use crate::vec::Vec;
+use celui_sys::alloc::{handle_alloc_error, AllocError, Allocator, Global};
 
-const EMPTY: u64 = 0; // Marker for empty slots
-const TOMBSTONE: u64 = 1; // Marker for deleted slots
-const DEFAULT_CAPACITY: usize = 8; // Minimum non-zero capacity, like Vec's 4
+use crate::{hash::RandomState, vec::Vec};
+
+const GROUP_WIDTH: usize = 8; // Control bytes probed at once
+const MIN_BUCKETS: usize = GROUP_WIDTH; // Smallest table, so a group never wraps onto itself
+const DEFAULT_CAPACITY: usize = 8; // Pairs a new map has room for
+
+const EMPTY: u8 = 0b1111_1111; // Control byte of an empty slot
+const DELETED: u8 = 0b1000_0000; // Control byte of a removed slot; full slots are `0b0xxx_xxxx`
 
 // --------------------------------- Iter ---------------------------------- //
 
 /// An iterator over the key-value pairs of a `HashMap`.
-pub struct Iter<'a, K, V> {
-    hash_map: &'a HashMap<K, V>,
-    index: usize,
+pub struct Iter<'a, K, V, A: Allocator = Global> {
+    table: &'a RawTable<K, V, A>,
+    cursor: FullSlots,
+    remaining: usize, // Number of pairs not yet yielded
 }
 
-impl<'a, K, V> Iterator for Iter<'a, K, V> {
+impl<'a, K, V, A: Allocator> Iterator for Iter<'a, K, V, A> {
     type Item = (&'a K, &'a V);
 
     #[inline(always)]
-    fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.hash_map.hashes.len() {
-            let i = self.index;
-
-            self.index += 1;
-
-            if self.hash_map.hashes[i] > TOMBSTONE {
-                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
-                unsafe {
-                    return Some((
-                        &*self.hash_map.keys[i].as_ptr(),
-                        &*self.hash_map.values[i].as_ptr(),
-                    ));
-                }
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let i = self.cursor.next(self.table)?;
+
+        self.remaining -= 1;
+
+        // SAFETY: Slot is full, so the pair is initialized
+        let (key, value) = unsafe { &*self.table.slot(i) };
+
+        Some((key, value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for Iter<'_, K, V, A> {}
+
+impl<K, V, A: Allocator> Clone for Iter<'_, K, V, A> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table,
+            cursor: self.cursor,
+            remaining: self.remaining,
+        }
+    }
+}
+
+// ------------------------------- IterMut --------------------------------- //
+
+/// A mutable iterator over the key-value pairs of a `HashMap`.
+pub struct IterMut<'a, K, V, A: Allocator = Global> {
+    table: &'a mut RawTable<K, V, A>,
+    cursor: FullSlots,
+    remaining: usize, // Number of pairs not yet yielded
+}
+
+impl<'a, K, V, A: Allocator> Iterator for IterMut<'a, K, V, A> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let i = self.cursor.next(self.table)?;
+
+        self.remaining -= 1;
+
+        // SAFETY: Slot is full, so the pair is initialized, and each slot is only yielded once
+        let (key, value) = unsafe { &mut *self.table.slot_mut(i) };
+
+        Some((key, value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for IterMut<'_, K, V, A> {}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `HashMap` and returns its key-value pairs.
+pub struct IntoIter<K, V, A: Allocator = Global> {
+    table: RawTable<K, V, A>,
+    cursor: FullSlots,
+}
+
+impl<K, V, A: Allocator> Iterator for IntoIter<K, V, A> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.table.take_next(&mut self.cursor)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.table.elements, Some(self.table.elements))
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for IntoIter<K, V, A> {}
+
+// -------------------------------- Drain ---------------------------------- //
+
+/// A draining iterator that removes every key-value pair from a `HashMap`.
+///
+/// Pairs not yielded are dropped, and the map is left empty when the iterator is dropped.
+pub struct Drain<'a, K, V, A: Allocator = Global> {
+    table: &'a mut RawTable<K, V, A>,
+    cursor: FullSlots,
+}
+
+impl<K, V, A: Allocator> Iterator for Drain<'_, K, V, A> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.table.take_next(&mut self.cursor)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.table.elements, Some(self.table.elements))
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for Drain<'_, K, V, A> {}
+
+impl<K, V, A: Allocator> Drop for Drain<'_, K, V, A> {
+    fn drop(&mut self) {
+        self.by_ref().for_each(drop);
+
+        // Yielded slots were marked empty, so only the deleted ones are left
+        self.table.clear_ctrl();
+    }
+}
+
+// ---------------------- Keys / Values / ValuesMut ------------------------ //
+
+/// An iterator over the keys of a `HashMap`.
+pub struct Keys<'a, K, V, A: Allocator = Global> {
+    iter: Iter<'a, K, V, A>,
+}
+
+impl<'a, K, V, A: Allocator> Iterator for Keys<'a, K, V, A> {
+    type Item = &'a K;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for Keys<'_, K, V, A> {}
+
+/// An iterator over the values of a `HashMap`.
+pub struct Values<'a, K, V, A: Allocator = Global> {
+    iter: Iter<'a, K, V, A>,
+}
+
+impl<'a, K, V, A: Allocator> Iterator for Values<'a, K, V, A> {
+    type Item = &'a V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for Values<'_, K, V, A> {}
+
+/// A mutable iterator over the values of a `HashMap`.
+pub struct ValuesMut<'a, K, V, A: Allocator = Global> {
+    iter: IterMut<'a, K, V, A>,
+}
+
+impl<'a, K, V, A: Allocator> Iterator for ValuesMut<'a, K, V, A> {
+    type Item = &'a mut V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V, A: Allocator> ExactSizeIterator for ValuesMut<'_, K, V, A> {}
+
+// -------------------------------- Entry ---------------------------------- //
+
+/// A view into a single slot of a `HashMap`, which is either occupied or vacant.
+///
+/// Returned by `HashMap::entry`.
+pub enum Entry<'a, K, V, A: Allocator = Global> {
+    Occupied(OccupiedEntry<'a, K, V, A>),
+    Vacant(VacantEntry<'a, K, V, A>),
+}
+
+impl<'a, K, V, A: Allocator> Entry<'a, K, V, A> {
+    /// Returns a reference to the entry's key.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable reference
+    /// to the value.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the result of `default`, called with the key, if the entry is vacant, and
+    /// returns a mutable reference to the value.
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Inserts `V::default()` if the entry is vacant, and returns a mutable reference to the
+    /// value.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value if the entry is occupied, and returns the entry.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// A view into an occupied slot of a `HashMap`.
+pub struct OccupiedEntry<'a, K, V, A: Allocator = Global> {
+    table: &'a mut RawTable<K, V, A>,
+    index: usize, // Full slot
+}
+
+impl<'a, K, V, A: Allocator> OccupiedEntry<'a, K, V, A> {
+    /// Returns a reference to the key in the map.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        // SAFETY: The slot is full, so the pair is initialized
+        unsafe { &(*self.table.slot(self.index)).0 }
+    }
+
+    /// Returns a reference to the value.
+    #[inline(always)]
+    pub fn get(&self) -> &V {
+        // SAFETY: The slot is full, so the pair is initialized
+        unsafe { &(*self.table.slot(self.index)).1 }
+    }
+
+    /// Returns a mutable reference to the value.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut V {
+        // SAFETY: The slot is full, so the pair is initialized
+        unsafe { &mut (*self.table.slot_mut(self.index)).1 }
+    }
+
+    /// Converts the entry into a mutable reference to the value, tied to the map's borrow.
+    #[inline(always)]
+    pub fn into_mut(self) -> &'a mut V {
+        // SAFETY: The slot is full, so the pair is initialized
+        unsafe { &mut (*self.table.slot_mut(self.index)).1 }
+    }
+
+    /// Replaces the value, and returns the old one.
+    #[inline(always)]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the pair from the map, and returns the value.
+    #[inline(always)]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the pair from the map, and returns it.
+    #[inline(always)]
+    pub fn remove_entry(self) -> (K, V) {
+        // SAFETY: The slot is full
+        unsafe { self.table.remove_at(self.index) }
+    }
+}
+
+/// A view into a vacant slot of a `HashMap`.
+pub struct VacantEntry<'a, K, V, A: Allocator = Global> {
+    table: &'a mut RawTable<K, V, A>,
+    hash: u64,
+    key: K,
+    index: usize, // Empty or deleted slot where the pair will be inserted
+}
+
+impl<'a, K, V, A: Allocator> VacantEntry<'a, K, V, A> {
+    /// Returns a reference to the key that would be inserted.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes back ownership of the key.
+    #[inline(always)]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts the pair, and returns a mutable reference to the value.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let table = self.table;
+
+        // SAFETY: The slot is free, and room was made for one more pair when the entry was made
+        unsafe {
+            table.insert_at(self.index, self.hash, (self.key, value));
+
+            &mut (*table.slot_mut(self.index)).1
+        }
+    }
+}
+
+// -------------------------------- Group ---------------------------------- //
+
+/// Returns a word with every byte set to `byte`.
+#[inline(always)]
+const fn repeat(byte: u8) -> u64 {
+    u64::from_ne_bytes([byte; GROUP_WIDTH])
+}
+
+/// `GROUP_WIDTH` control bytes loaded into a word, to be matched all at once.
+///
+/// Byte `i` of the group lives in bits `8 * i..8 * i + 8`, whatever the target's endianness.
+#[derive(Clone, Copy)]
+struct Group(u64);
+
+impl Group {
+    /// Returns the slots whose control byte may be `byte`.
+    ///
+    /// This can have false positives, but only on full slots right after a true match, which
+    /// are ruled out by comparing keys anyway.
+    #[inline(always)]
+    const fn match_byte(self, byte: u8) -> BitMask {
+        let cmp = self.0 ^ repeat(byte);
+
+        BitMask(cmp.wrapping_sub(repeat(0x01)) & !cmp & repeat(0x80))
+    }
+
+    /// Returns the empty slots: the only control bytes with both high bits set.
+    #[inline(always)]
+    const fn match_empty(self) -> BitMask {
+        BitMask(self.0 & (self.0 << 1) & repeat(0x80))
+    }
+
+    /// Returns the empty and deleted slots: the control bytes with the high bit set.
+    #[inline(always)]
+    const fn match_empty_or_deleted(self) -> BitMask {
+        BitMask(self.0 & repeat(0x80))
+    }
+
+    /// Returns the full slots: the control bytes with the high bit clear.
+    #[inline(always)]
+    const fn match_full(self) -> BitMask {
+        BitMask(!self.0 & repeat(0x80))
+    }
+}
+
+/// A set of slots within a group, as the high bit of each matching byte.
+#[derive(Clone, Copy)]
+struct BitMask(u64);
+
+impl BitMask {
+    /// Returns the lowest slot in the set.
+    #[inline(always)]
+    const fn lowest(self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.0.trailing_zeros() as usize / 8)
+        }
+    }
+
+    /// Returns the number of slots before the first slot in the set, or `GROUP_WIDTH`.
+    #[inline(always)]
+    const fn leading_slots(self) -> usize {
+        self.0.trailing_zeros() as usize / 8
+    }
+
+    /// Returns the number of slots after the last slot in the set, or `GROUP_WIDTH`.
+    #[inline(always)]
+    const fn trailing_slots(self) -> usize {
+        self.0.leading_zeros() as usize / 8
+    }
+}
+
+impl Iterator for BitMask {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<usize> {
+        let slot = self.lowest()?;
+
+        self.0 &= self.0 - 1;
+
+        Some(slot)
+    }
+}
+
+/// A cursor over the full slots of a table, one group at a time.
+#[derive(Clone, Copy)]
+struct FullSlots {
+    group: usize,  // First slot of the current group
+    bits: BitMask, // Full slots of the current group not yet visited
+}
+
+impl FullSlots {
+    /// Starts at the first group of `table`.
+    #[inline(always)]
+    fn new<K, V, A: Allocator>(table: &RawTable<K, V, A>) -> Self {
+        Self {
+            group: 0,
+            bits: table.group(0).match_full(),
+        }
+    }
+
+    /// Returns the next full slot of `table`, or `None` past the last group.
+    #[inline(always)]
+    fn next<K, V, A: Allocator>(&mut self, table: &RawTable<K, V, A>) -> Option<usize> {
+        loop {
+            if let Some(slot) = self.bits.next() {
+                return Some(self.group + slot);
+            }
+
+            self.group += GROUP_WIDTH;
+
+            if self.group >= table.buckets() {
+                return None;
+            }
+
+            self.bits = table.group(self.group).match_full();
+        }
+    }
+}
+
+// ------------------------------- RawTable -------------------------------- //
+
+/// The slots of a `HashMap`, without its hasher. Also the index table of an `IndexMap`.
+///
+/// Laid out like a SwissTable: every slot has a control byte, which is either `EMPTY`,
+/// `DELETED`, or the top 7 bits of the hash of the key in it (its "h2"). Lookups start at the
+/// slot picked by the low bits of the hash, and compare the h2 of a whole group of slots at
+/// once, so keys are only compared on a likely match. Groups are visited with triangular
+/// probing, which reaches every group of a power-of-two table, until one has an empty slot.
+///
+/// The first group of control bytes is mirrored after the last slot, so a group starting at
+/// any slot can be loaded without wrapping around.
+pub(crate) struct RawTable<K, V, A: Allocator> {
+    ctrl: Vec<u8, A>, // Control bytes, then the mirrored first group
+    slots: Vec<core::mem::MaybeUninit<(K, V)>, A>, // Key-value pairs, initialized in full slots
+    mask: usize,      // Bitmask for indexing (buckets - 1)
+    elements: usize,  // Number of full slots
+    growth_left: usize, // Empty slots that can be filled before growing
+}
+
+/// Returns the h2 of `hash`, the control byte of a slot holding a key with that hash.
+///
+/// The hash is multiplied first so every bit reaches the top 7, since weak hashers like
+/// `QuickHasher` only vary in their low bits.
+#[inline(always)]
+const fn h2(hash: u64) -> u8 {
+    (hash.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 57) as u8
+}
+
+/// Returns the number of pairs a table of `buckets` slots holds, keeping 1/8 of it empty.
+#[inline(always)]
+const fn bucket_capacity(buckets: usize) -> usize {
+    buckets / 8 * 7
+}
+
+/// Returns the number of slots needed to hold `capacity` pairs.
+#[inline]
+fn capacity_to_buckets(capacity: usize) -> Result<usize, AllocError> {
+    if capacity < MIN_BUCKETS {
+        return Ok(MIN_BUCKETS);
+    }
+
+    capacity
+        .checked_mul(8)
+        .map(|capacity| capacity / 7)
+        .and_then(usize::checked_next_power_of_two)
+        .ok_or(AllocError::CapacityOverflow)
+}
+
+impl<K, V, A: Allocator + Clone> RawTable<K, V, A> {
+    /// Creates an empty table with room for at least `capacity` pairs, or an error if
+    /// allocation fails.
+    pub(crate) fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        Self::try_with_buckets_in(capacity_to_buckets(capacity)?, allocator)
+    }
+
+    /// Creates an empty table of `buckets` slots, a power of two of at least `MIN_BUCKETS`.
+    fn try_with_buckets_in(buckets: usize, allocator: A) -> Result<Self, AllocError> {
+        debug_assert!(buckets.is_power_of_two() && buckets >= MIN_BUCKETS);
+
+        let mut ctrl = Vec::try_with_capacity_in(buckets + GROUP_WIDTH, allocator.clone())?;
+        let mut slots = Vec::try_with_capacity_in(buckets, allocator)?;
+
+        ctrl.resize(buckets + GROUP_WIDTH, EMPTY);
+
+        // SAFETY: Slots are `MaybeUninit`, and only read once their control byte is full
+        unsafe { slots.set_len(buckets) };
+
+        Ok(Self {
+            ctrl,
+            slots,
+            mask: buckets - 1,
+            elements: 0,
+            growth_left: bucket_capacity(buckets),
+        })
+    }
+
+    /// Makes room for `additional` more pairs, rehashing keys with `hasher`.
+    ///
+    /// If deleted slots are what's in the way, the table is rebuilt at the same size instead of
+    /// growing. On failure the table is left unchanged.
+    #[inline]
+    pub(crate) fn try_reserve<H: Fn(&K) -> u64>(
+        &mut self,
+        additional: usize,
+        hasher: H,
+    ) -> Result<(), AllocError> {
+        if additional <= self.growth_left {
+            return Ok(());
+        }
+
+        self.try_reserve_rehash(additional, hasher)
+    }
+
+    #[cold]
+    fn try_reserve_rehash<H: Fn(&K) -> u64>(
+        &mut self,
+        additional: usize,
+        hasher: H,
+    ) -> Result<(), AllocError> {
+        let needed = self
+            .elements
+            .checked_add(additional)
+            .ok_or(AllocError::CapacityOverflow)?;
+
+        let full_capacity = bucket_capacity(self.buckets());
+
+        let buckets = if needed <= full_capacity / 2 {
+            self.buckets()
+        } else {
+            capacity_to_buckets(needed.max(full_capacity + 1))?
+        };
+
+        self.try_rehash(buckets, hasher)
+    }
+
+    /// Shrinks the table to the fewest slots that hold its pairs, and clears out deleted slots,
+    /// rehashing keys with `hasher`. On failure the table is left unchanged.
+    pub(crate) fn try_shrink_to_fit<H: Fn(&K) -> u64>(
+        &mut self,
+        hasher: H,
+    ) -> Result<(), AllocError> {
+        let buckets = capacity_to_buckets(self.elements).unwrap_or(self.buckets());
+        let has_deleted = self.capacity() < bucket_capacity(self.buckets());
+
+        if buckets < self.buckets() || has_deleted {
+            return self.try_rehash(buckets, hasher);
+        }
+
+        Ok(())
+    }
+
+    /// Moves all pairs into a new table of `buckets` slots, rehashing keys with `hasher`.
+    ///
+    /// The new table is allocated first, so the table is left unchanged on failure. If `hasher`
+    /// panics, the pairs not moved yet are dropped.
+    fn try_rehash<H: Fn(&K) -> u64>(
+        &mut self,
+        buckets: usize,
+        hasher: H,
+    ) -> Result<(), AllocError> {
+        debug_assert!(bucket_capacity(buckets) >= self.elements);
+
+        let new_table = Self::try_with_buckets_in(buckets, self.ctrl.allocator().clone())?;
+        let mut old_table = core::mem::replace(self, new_table);
+        let mut cursor = FullSlots::new(&old_table);
+
+        while let Some(i) = cursor.next(&old_table) {
+            // SAFETY: Slot is full, so the pair is initialized
+            let hash = hasher(unsafe { &(*old_table.slot(i)).0 });
+
+            // Mark the slot empty so the old table doesn't drop the moved pair
+            old_table.set_ctrl(i, EMPTY);
+            old_table.elements -= 1;
+
+            // SAFETY: The pair was moved out of the old table. The new table has room for every
+            // pair and no equal keys.
+            unsafe {
+                let pair = old_table.slots[i].assume_init_read();
+
+                self.insert_unchecked(hash, pair);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<K, V, A: Allocator> RawTable<K, V, A> {
+    /// Returns the number of pairs in the table.
+    #[inline(always)]
+    pub(crate) const fn len(&self) -> usize {
+        self.elements
+    }
+
+    /// Returns the number of pairs the table can hold without growing.
+    #[inline(always)]
+    pub(crate) const fn capacity(&self) -> usize {
+        self.elements + self.growth_left
+    }
+
+    /// Returns a reference to the table's allocator.
+    #[inline(always)]
+    pub(crate) const fn allocator(&self) -> &A {
+        self.ctrl.allocator()
+    }
+
+    /// Returns the number of slots.
+    #[inline(always)]
+    const fn buckets(&self) -> usize {
+        self.mask + 1
+    }
+
+    /// Loads the group of control bytes starting at slot `i`.
+    #[inline(always)]
+    fn group(&self, i: usize) -> Group {
+        debug_assert!(i + GROUP_WIDTH <= self.ctrl.len());
+
+        // SAFETY: `i` is a slot, and there are `GROUP_WIDTH` mirrored control bytes after the last
+        let word = unsafe { self.ctrl.as_ptr().add(i).cast::<u64>().read_unaligned() };
+
+        Group(u64::from_le(word))
+    }
+
+    /// Sets the control byte of slot `i`, and its mirror if it is in the first group.
+    #[inline(always)]
+    fn set_ctrl(&mut self, i: usize, ctrl: u8) {
+        let mirror = (i.wrapping_sub(GROUP_WIDTH) & self.mask) + GROUP_WIDTH;
+
+        self.ctrl[i] = ctrl;
+        self.ctrl[mirror] = ctrl;
+    }
+
+    /// Marks every slot empty, without dropping anything.
+    #[inline]
+    pub(crate) fn clear_ctrl(&mut self) {
+        self.ctrl.fill(EMPTY);
+        self.elements = 0;
+        self.growth_left = bucket_capacity(self.buckets());
+    }
+
+    /// Returns a pointer to the pair in slot `i`.
+    #[inline(always)]
+    pub(crate) fn slot(&self, i: usize) -> *const (K, V) {
+        debug_assert!(i < self.buckets());
+
+        // SAFETY: `i` is within the slots
+        unsafe { self.slots.as_ptr().add(i).cast() }
+    }
+
+    /// Returns a mutable pointer to the pair in slot `i`.
+    #[inline(always)]
+    pub(crate) fn slot_mut(&mut self, i: usize) -> *mut (K, V) {
+        debug_assert!(i < self.buckets());
+
+        // SAFETY: `i` is within the slots
+        unsafe { self.slots.as_mut_ptr().add(i).cast() }
+    }
+
+    /// Returns the full slot whose key has `hash` and satisfies `eq`, or `None`.
+    #[inline(always)]
+    pub(crate) fn find<F: FnMut(&K) -> bool>(&self, hash: u64, mut eq: F) -> Option<usize> {
+        let h2 = h2(hash);
+        let mut probe = ProbeSeq::new(hash, self.mask);
+
+        loop {
+            let group = self.group(probe.position);
+
+            for slot in group.match_byte(h2) {
+                let i = (probe.position + slot) & self.mask;
+
+                // SAFETY: Only full slots match an h2, so the pair is initialized
+                if eq(unsafe { &(*self.slot(i)).0 }) {
+                    return Some(i);
+                }
+            }
+
+            // An empty slot ends every probe sequence that could have gone further
+            if group.match_empty().lowest().is_some() {
+                return None;
+            }
+
+            probe.move_next(self.mask);
+        }
+    }
+
+    /// Returns the first empty or deleted slot in the probe sequence of `hash`.
+    ///
+    /// The table must have at least one empty slot, which `growth_left` guarantees.
+    #[inline(always)]
+    pub(crate) fn find_insert_slot(&self, hash: u64) -> usize {
+        let mut probe = ProbeSeq::new(hash, self.mask);
+
+        loop {
+            if let Some(slot) = self.group(probe.position).match_empty_or_deleted().lowest() {
+                return (probe.position + slot) & self.mask;
             }
-        }
 
-        None
+            probe.move_next(self.mask);
+        }
     }
-}
 
-// ------------------------------- IterMut --------------------------------- //
+    /// Moves `pair` into the free slot `i`.
+    ///
+    /// # SAFETY
+    /// Slot `i` must be empty or deleted, with `growth_left` > 0 if it is empty, and `pair`'s
+    /// key must have `hash` and not be in the table.
+    #[inline(always)]
+    unsafe fn insert_at(&mut self, i: usize, hash: u64, pair: (K, V)) {
+        debug_assert!(self.ctrl[i] & 0x80 != 0);
 
-/// A mutable iterator over the key-value pairs of a `HashMap`.
-pub struct IterMut<'a, K, V> {
-    hash_map: &'a mut HashMap<K, V>,
-    index: usize,
-}
+        self.growth_left -= (self.ctrl[i] == EMPTY) as usize;
+        self.elements += 1;
+        self.set_ctrl(i, h2(hash));
 
-impl<'a, K, V> Iterator for IterMut<'a, K, V> {
-    type Item = (&'a K, &'a mut V);
+        // SAFETY: Slot `i` is within the slots, and free
+        unsafe { self.slot_mut(i).write(pair) };
+    }
 
+    /// Moves `pair` into the first free slot of its probe sequence, and returns the slot.
+    ///
+    /// # SAFETY
+    /// The table must have `growth_left` > 0, and `pair`'s key must have `hash` and not be in
+    /// the table.
     #[inline(always)]
-    fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.hash_map.hashes.len() {
-            let i = self.index;
-
-            self.index += 1;
-
-            if self.hash_map.hashes[i] > TOMBSTONE {
-                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
-                unsafe {
-                    return Some((
-                        &*self.hash_map.keys[i].as_ptr(),
-                        &mut *self.hash_map.values[i].as_mut_ptr(),
-                    ));
-                }
-            }
+    pub(crate) unsafe fn insert_unchecked(&mut self, hash: u64, pair: (K, V)) -> usize {
+        let i = self.find_insert_slot(hash);
+
+        // SAFETY: `i` is free, and the caller upholds the rest
+        unsafe { self.insert_at(i, hash, pair) };
+
+        i
+    }
+
+    /// Frees the full slot `i`, and moves its pair out.
+    ///
+    /// The slot is marked `DELETED` if a probe sequence may have gone past it while its group
+    /// was full, and `EMPTY` (reusable for free) otherwise.
+    ///
+    /// # SAFETY
+    /// Slot `i` must be full.
+    #[inline]
+    pub(crate) unsafe fn remove_at(&mut self, i: usize) -> (K, V) {
+        debug_assert!(self.ctrl[i] & 0x80 == 0);
+
+        let empty_before = self
+            .group(i.wrapping_sub(GROUP_WIDTH) & self.mask)
+            .match_empty();
+        let empty_after = self.group(i).match_empty();
+
+        // If the full run through `i` is shorter than a group, no probe ever went past it
+        let ctrl = if empty_before.trailing_slots() + empty_after.leading_slots() >= GROUP_WIDTH {
+            DELETED
+        } else {
+            self.growth_left += 1;
+
+            EMPTY
+        };
+
+        self.set_ctrl(i, ctrl);
+        self.elements -= 1;
+
+        // SAFETY: Slot was full, and is now marked free so it won't be read again
+        unsafe { self.slot(i).read() }
+    }
+
+    /// Moves the next pair of `cursor` out of the table, marking its slot empty.
+    ///
+    /// Only for draining: the probe sequences are broken until every pair is taken out.
+    #[inline]
+    fn take_next(&mut self, cursor: &mut FullSlots) -> Option<(K, V)> {
+        if self.elements == 0 {
+            return None;
         }
 
-        None
+        let i = cursor.next(self)?;
+
+        self.set_ctrl(i, EMPTY);
+        self.elements -= 1;
+
+        // SAFETY: Slot was full, and is now marked empty so it won't be read again
+        Some(unsafe { self.slot(i).read() })
     }
 }
 
-// ----------------------------- QuickHasher ------------------------------- //
+impl<K, V, A: Allocator> Drop for RawTable<K, V, A> {
+    fn drop(&mut self) {
+        if !core::mem::needs_drop::<(K, V)>() || self.elements == 0 {
+            return;
+        }
 
-/// A simple, fast hasher for internal use in `HashMap`.
-///
-/// Uses a multiplication-based hashing strategy with wrapping arithmetic.
-#[derive(Default)]
-pub struct QuickHasher {
-    value: u64,
+        let mut cursor = FullSlots::new(self);
+
+        while let Some(i) = cursor.next(self) {
+            // SAFETY: Slot is full, so the pair is initialized
+            unsafe { core::ptr::drop_in_place(self.slot_mut(i)) };
+        }
+    }
 }
 
-impl QuickHasher {
-    /// Creates a new `QuickHasher` with an initial value of `0`.
-    #[inline(always)]
-    pub const fn new() -> Self {
-        Self { value: 0 }
+impl<K: Clone, V: Clone, A: Allocator + Clone> Clone for RawTable<K, V, A> {
+    /// Clones the table slot for slot, so no key is rehashed.
+    fn clone(&self) -> Self {
+        let mut table = Self::try_with_buckets_in(self.buckets(), self.allocator().clone())
+            .unwrap_or_else(|error| handle_alloc_error(error));
+
+        // Slots are only marked full once their pair is cloned, so a panicking `clone` drops
+        // exactly the pairs cloned so far
+        let mut cursor = FullSlots::new(self);
+
+        while let Some(i) = cursor.next(self) {
+            // SAFETY: Slot is full, so the pair is initialized
+            let (key, value) = unsafe { &*self.slot(i) };
+            let pair = (key.clone(), value.clone());
+
+            // SAFETY: Slot `i` is within the new table, and still empty
+            unsafe { table.slot_mut(i).write(pair) };
+
+            table.set_ctrl(i, self.ctrl[i]);
+            table.elements += 1;
+        }
+
+        // Deleted slots are copied too, so every probe sequence stays the same
+        table.ctrl.copy_from_slice(&self.ctrl);
+        table.growth_left = self.growth_left;
+
+        table
     }
 }
 
-impl core::hash::Hasher for QuickHasher {
+/// The sequence of groups probed for a hash.
+struct ProbeSeq {
+    position: usize, // First slot of the current group
+    stride: usize,   // Distance to the next group, growing by a group each step
+}
+
+impl ProbeSeq {
     #[inline(always)]
-    fn finish(&self) -> u64 {
-        self.value
+    const fn new(hash: u64, mask: usize) -> Self {
+        Self {
+            position: hash as usize & mask,
+            stride: 0,
+        }
     }
 
     #[inline(always)]
-    fn write(&mut self, bytes: &[u8]) {
-        for &byte in bytes {
-            self.value = self.value.wrapping_mul(31).wrapping_add(byte as u64);
-        }
+    fn move_next(&mut self, mask: usize) {
+        self.stride += GROUP_WIDTH;
+        self.position = (self.position + self.stride) & mask;
     }
 }
 
 // ------------------------------- HashMap --------------------------------- //
 
-/// A hash map implementation using open addressing with linear probing.
+/// A hash map implementation using open addressing with SwissTable-style group probing.
 ///
-/// Stores key-value pairs in a dynamically resizing array, using tombstones
-/// to handle deletions. Provides efficient insertion, lookup, and removal.
+/// Stores key-value pairs in a single array of slots, with a control byte per
+/// slot that lets lookups check a whole group of slots at once. The table
+/// grows once 7/8 of it is used, and removals rarely leave tombstones behind.
+///
+/// Keys are hashed with `S`, which defaults to `RandomState`; see the `hash`
+/// module for the alternatives.
 ///
 /// # Example
 /// ```
@@ -117,13 +956,9 @@ impl core::hash::Hasher for QuickHasher {
 /// map.insert("key", 69);
 /// assert_eq!(map.get(&"key"), Some(&69));
 /// ```
-pub struct HashMap<K, V> {
-    keys: Vec<core::mem::MaybeUninit<K>>, // Uninitialized storage for keys
-    values: Vec<core::mem::MaybeUninit<V>>, // Uninitialized storage for values
-    hashes: Vec<u64>,                     // Hash values
-    mask: usize,                          // Bitmask for indexing (capacity - 1)
-    elements: usize,                      // Number of active key-value pairs
-    tombstone_count: usize,               // Number of tombstone slots
+pub struct HashMap<K, V, S = RandomState, A: Allocator = Global> {
+    table: RawTable<K, V, A>, // Slots holding the pairs
+    hash_builder: S,          // Builds a hasher for every key
 }
 
 impl<K: core::hash::Hash + Eq, V> HashMap<K, V> {
@@ -136,47 +971,108 @@ impl<K: core::hash::Hash + Eq, V> HashMap<K, V> {
     /// Creates a new hash map with a specified initial capacity.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        let capacity = capacity.next_power_of_two();
+        Self::with_capacity_in(capacity, Global)
+    }
 
-        let mut keys = Vec::with_capacity(capacity);
-        let mut values = Vec::with_capacity(capacity);
-        let mut hashes = Vec::with_capacity(capacity);
+    /// Creates a new hash map with a specified initial capacity, or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
 
-        // SAFETY: Setting length to capacity is safe since we don't access uninitialized memory
-        // until it's written to, and `hashes` is initialized with `EMPTY`.
-        unsafe {
-            keys.set_len(capacity);
-            values.set_len(capacity);
-            hashes.set_len(capacity);
-            hashes.fill(EMPTY);
-        }
+impl<K: core::hash::Hash + Eq, V, S: core::hash::BuildHasher> HashMap<K, V, S> {
+    /// Creates a new, empty hash map that hashes keys with `hash_builder`.
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(DEFAULT_CAPACITY, hash_builder)
+    }
 
-        Self {
-            keys,
-            values,
-            hashes,
-            mask: capacity - 1,
-            elements: 0,
-            tombstone_count: 0,
-        }
+    /// Creates a new hash map with a specified initial capacity, hashing keys with
+    /// `hash_builder`.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, hash_builder, Global)
+    }
+}
+
+impl<K: core::hash::Hash + Eq, V, A: Allocator + Clone> HashMap<K, V, RandomState, A> {
+    /// Creates a new, empty hash map that will allocate from `allocator`.
+    #[inline]
+    pub fn new_in(allocator: A) -> Self {
+        Self::with_capacity_in(DEFAULT_CAPACITY, allocator)
+    }
+
+    /// Creates a new hash map with a specified initial capacity, allocated from `allocator`.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, RandomState::new(), allocator)
+    }
+
+    /// Creates a new hash map with a specified initial capacity, allocated from `allocator`, or
+    /// an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        Self::try_with_capacity_and_hasher_in(capacity, RandomState::new(), allocator)
+    }
+}
+
+impl<K, V, S, A> HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Creates a new hash map with a specified initial capacity, hashing keys with
+    /// `hash_builder` and allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_and_hasher_in(capacity: usize, hash_builder: S, allocator: A) -> Self {
+        Self::try_with_capacity_and_hasher_in(capacity, hash_builder, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new hash map with a specified initial capacity, hashing keys with
+    /// `hash_builder` and allocating from `allocator`, or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_and_hasher_in(
+        capacity: usize,
+        hash_builder: S,
+        allocator: A,
+    ) -> Result<Self, AllocError> {
+        Ok(Self {
+            table: RawTable::try_with_capacity_in(capacity, allocator)?,
+            hash_builder,
+        })
+    }
+
+    /// Returns a reference to the map's hasher builder.
+    #[inline(always)]
+    pub const fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns a reference to the map's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.table.allocator()
     }
 
     /// Returns the number of key-value pairs in the map.
     #[inline(always)]
     pub const fn len(&self) -> usize {
-        self.elements
+        self.table.len()
     }
 
-    /// Returns the current capacity of the map.
+    /// Returns the number of pairs the map can hold without growing.
     #[inline(always)]
     pub const fn capacity(&self) -> usize {
-        self.hashes.len()
+        self.table.capacity()
     }
 
     /// Returns `true` if the map contains no key-value pairs.
     #[inline(always)]
     pub const fn is_empty(&self) -> bool {
-        self.elements == 0
+        self.table.elements == 0
     }
 
     /// Returns `true` if the map contains the given key.
@@ -185,7 +1081,7 @@ impl<K: core::hash::Hash + Eq, V> HashMap<K, V> {
     where
         K: core::borrow::Borrow<Q>,
     {
-        self.get(key).is_some()
+        self.find(key).is_some()
     }
 
     /// Returns a reference to the value associated with the given key, or `None` if not found.
@@ -194,19 +1090,24 @@ impl<K: core::hash::Hash + Eq, V> HashMap<K, V> {
     where
         K: core::borrow::Borrow<Q>,
     {
-        if self.elements == 0 {
-            return None;
-        }
+        let i = self.find(key)?;
 
-        let hash = self.hash_key(key);
-        let i = self.find_slot(hash, key)?;
+        // SAFETY: Slot is full, so the pair is initialized
+        Some(unsafe { &(*self.table.slot(i)).1 })
+    }
 
-        if self.hashes[i] <= TOMBSTONE {
-            return None;
-        }
+    /// Returns the key-value pair stored for the given key, or `None` if not found.
+    #[inline]
+    pub fn get_key_value<Q: core::hash::Hash + Eq + ?Sized>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        let i = self.find(key)?;
+
+        // SAFETY: Slot is full, so the pair is initialized
+        let (key, value) = unsafe { &*self.table.slot(i) };
 
-        // SAFETY: Slot is occupied (hash > TOMBSTONE), so value is initialized
-        Some(unsafe { &*self.values[i].as_ptr() })
+        Some((key, value))
     }
 
     /// Returns a mutable reference to the value associated with the key, or `None` if not found.
@@ -215,73 +1116,139 @@ impl<K: core::hash::Hash + Eq, V> HashMap<K, V> {
     where
         K: core::borrow::Borrow<Q>,
     {
-        if self.elements == 0 {
-            return None;
-        }
-
-        let hash = self.hash_key(key);
-        let i = self.find_slot(hash, key)?;
+        let i = self.find(key)?;
 
-        if self.hashes[i] <= TOMBSTONE {
-            return None;
-        }
-
-        // SAFETY: Slot is occupied (hash > TOMBSTONE), so value is initialized
-        Some(unsafe { &mut *self.values[i].as_mut_ptr() })
+        // SAFETY: Slot is full, so the pair is initialized
+        Some(unsafe { &mut (*self.table.slot_mut(i)).1 })
     }
 
     /// Returns an iterator over the map's key-value pairs.
     #[inline(always)]
-    pub fn iter(&self) -> Iter<'_, K, V> {
+    pub fn iter(&self) -> Iter<'_, K, V, A> {
         Iter {
-            hash_map: self,
-            index: 0,
+            table: &self.table,
+            cursor: FullSlots::new(&self.table),
+            remaining: self.table.elements,
         }
     }
 
     /// Returns a mutable iterator over the map's key-value pairs.
     #[inline(always)]
-    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V, A> {
         IterMut {
-            hash_map: self,
-            index: 0,
+            cursor: FullSlots::new(&self.table),
+            remaining: self.table.elements,
+            table: &mut self.table,
         }
     }
 
-    /// Inserts a key-value pair, replacing the old value if the key already exists.
+    /// Returns an iterator over the map's keys.
+    #[inline(always)]
+    pub fn keys(&self) -> Keys<'_, K, V, A> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the map's values.
+    #[inline(always)]
+    pub fn values(&self) -> Values<'_, K, V, A> {
+        Values { iter: self.iter() }
+    }
+
+    /// Returns a mutable iterator over the map's values.
+    #[inline(always)]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V, A> {
+        ValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+
+    /// Removes every key-value pair from the map, and returns them as an iterator.
     ///
-    /// Returns the previous value if the key was already present, or `None` if it was inserted.
+    /// The capacity is kept. Pairs not consumed are dropped along with the iterator.
+    #[inline(always)]
+    pub fn drain(&mut self) -> Drain<'_, K, V, A> {
+        Drain {
+            cursor: FullSlots::new(&self.table),
+            table: &mut self.table,
+        }
+    }
+
+    /// Returns the entry for `key`, for in-place lookup and insertion.
+    ///
+    /// For a vacant entry, the map grows here if needed, so inserting never allocates.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_collections::HashMap;
+    ///
+    /// let mut clicks = HashMap::new();
+    ///
+    /// for id in ["ok", "cancel", "ok"] {
+    ///     *clicks.entry(id).or_insert(0) += 1;
+    /// }
+    ///
+    /// clicks.entry("ok").and_modify(|count| *count *= 10).or_insert(0);
+    ///
+    /// assert_eq!(clicks[&"ok"], 20);
+    /// assert_eq!(clicks[&"cancel"], 1);
+    /// ```
     #[inline]
-    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
-        if self.elements + self.tombstone_count >= (self.hashes.len() * 3) / 4 {
-            self.resize();
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, A> {
+        self.try_entry(key)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Returns the entry for `key`, or an error if growing the map fails.
+    ///
+    /// On failure, `key` is dropped and the map is left unchanged.
+    pub fn try_entry(&mut self, key: K) -> Result<Entry<'_, K, V, A>, AllocError> {
+        let hash = self.hash_builder.hash_one(&key);
+
+        if let Some(index) = self.table.find(hash, |other| *other == key) {
+            return Ok(Entry::Occupied(OccupiedEntry {
+                table: &mut self.table,
+                index,
+            }));
         }
 
-        let hash = self.hash_key(&key);
-        let i = self.find_slot(hash, &key).expect("HashMap is full");
+        let hash_builder = &self.hash_builder;
 
-        if self.hashes[i] > TOMBSTONE {
-            // SAFETY: Slot is occupied (hash > TOMBSTONE), so value is initialized
-            unsafe {
-                let old_value =
-                    core::mem::replace(&mut self.values[i], core::mem::MaybeUninit::new(value));
+        self.table
+            .try_reserve(1, |key| hash_builder.hash_one(key))?;
 
-                self.keys[i].write(key);
+        let index = self.table.find_insert_slot(hash);
 
-                return Some(old_value.assume_init());
-            }
-        }
+        Ok(Entry::Vacant(VacantEntry {
+            table: &mut self.table,
+            hash,
+            key,
+            index,
+        }))
+    }
 
-        if self.hashes[i] == TOMBSTONE {
-            self.tombstone_count -= 1;
-        }
+    /// Inserts a key-value pair, replacing the old value if the key already exists.
+    ///
+    /// Returns the previous value if the key was already present, or `None` if it was inserted.
+    /// The key already in the map is kept, and `key` is dropped.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.try_insert(key, value)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
 
-        self.elements += 1;
-        self.hashes[i] = hash;
-        self.keys[i].write(key);
-        self.values[i].write(value);
+    /// Inserts a key-value pair, or returns an error if growing the map fails.
+    ///
+    /// On failure, `key` and `value` are dropped and the map is left unchanged.
+    #[inline]
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, AllocError> {
+        match self.try_entry(key)? {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
 
-        None
+                Ok(None)
+            }
+        }
     }
 
     /// Removes and returns the value associated with the key, or `None` if not found.
@@ -290,213 +1257,249 @@ impl<K: core::hash::Hash + Eq, V> HashMap<K, V> {
     where
         K: core::borrow::Borrow<Q>,
     {
-        if self.elements == 0 {
-            return None;
-        }
+        self.remove_entry(key).map(|(_, value)| value)
+    }
 
-        let hash = self.hash_key(key);
-        let i = self.find_slot(hash, key)?;
+    /// Removes and returns the key-value pair stored for the key, or `None` if not found.
+    #[inline]
+    pub fn remove_entry<Q: core::hash::Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        let i = self.find(key)?;
 
-        if self.hashes[i] <= TOMBSTONE {
-            return None;
-        }
+        // SAFETY: Slot is full
+        Some(unsafe { self.table.remove_at(i) })
+    }
 
-        self.elements -= 1;
-        self.tombstone_count += 1;
-        self.hashes[i] = TOMBSTONE;
+    /// Keeps only the pairs for which `f` returns `true`, removing the others.
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        let table = &mut self.table;
+        let mut cursor = FullSlots::new(table);
 
-        // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
-        unsafe {
-            let _ = core::mem::replace(&mut self.keys[i], core::mem::MaybeUninit::uninit());
-            let value = core::mem::replace(&mut self.values[i], core::mem::MaybeUninit::uninit());
+        while let Some(i) = cursor.next(table) {
+            // SAFETY: Slot is full, so the pair is initialized
+            let (key, value) = unsafe { &mut *table.slot_mut(i) };
 
-            Some(value.assume_init())
+            if !f(key, value) {
+                // SAFETY: Slot is full, and the references above aren't used anymore
+                drop(unsafe { table.remove_at(i) });
+            }
         }
     }
 
     /// Removes all key-value pairs from the map, leaving it empty.
     #[inline]
     pub fn clear(&mut self) {
-        if self.elements == 0 {
-            return;
-        }
-
-        for i in 0..self.hashes.len() {
-            if self.hashes[i] > TOMBSTONE {
-                let _ = core::mem::replace(&mut self.keys[i], core::mem::MaybeUninit::uninit());
-                let _ = core::mem::replace(&mut self.values[i], core::mem::MaybeUninit::uninit());
-            }
-        }
-
-        self.hashes.fill(EMPTY);
-        self.elements = 0;
-        self.tombstone_count = 0;
+        self.drain();
     }
 
     /// Ensures the map has space for at least `additional` more key-value pairs.
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
-        let needed_capacity = self.len() + additional;
-
-        if needed_capacity > self.capacity() {
-            let new_capacity = needed_capacity.next_power_of_two();
-            let mut new_map = Self::with_capacity(new_capacity);
-
-            for i in 0..self.hashes.len() {
-                let hash = self.hashes[i];
-
-                if hash > TOMBSTONE {
-                    // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
-                    unsafe {
-                        let key =
-                            core::mem::replace(&mut self.keys[i], core::mem::MaybeUninit::uninit());
-                        let value = core::mem::replace(
-                            &mut self.values[i],
-                            core::mem::MaybeUninit::uninit(),
-                        );
-
-                        new_map.insert_unchecked(hash, key.assume_init(), value.assume_init());
-                    }
-                }
-            }
-
-            *self = new_map;
+        if let Err(error) = self.try_reserve(additional) {
+            handle_alloc_error(error);
         }
     }
 
-    /// Returns a 64-bit hash for a key, ensuring it doesn't overlap with special markers.
-    #[inline(always)]
-    fn hash_key<Q: core::hash::Hash + ?Sized>(&self, key: &Q) -> u64 {
-        let mut hasher = QuickHasher::new();
+    /// Ensures the map has space for at least `additional` more key-value pairs, or returns
+    /// an error if growing fails, leaving the map unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        let hash_builder = &self.hash_builder;
 
-        key.hash(&mut hasher);
+        self.table
+            .try_reserve(additional, |key| hash_builder.hash_one(key))
+    }
 
-        let hash = core::hash::Hasher::finish(&hasher);
+    /// Shrinks the capacity as much as possible, and clears out deleted slots.
+    pub fn shrink_to_fit(&mut self) {
+        let hash_builder = &self.hash_builder;
 
-        // Ensure hash is greater than TOMBSTONE to avoid conflicts with markers
-        if hash <= TOMBSTONE {
-            hash + 2
-        } else {
-            hash
+        if let Err(error) = self
+            .table
+            .try_shrink_to_fit(|key| hash_builder.hash_one(key))
+        {
+            handle_alloc_error(error);
         }
     }
 
-    /// Finds the slot for a key based on its hash, returning the index if found or a free slot.
+    /// Returns the full slot holding `key`, or `None` if it isn't in the map.
     #[inline]
-    fn find_slot<Q: Eq + ?Sized>(&self, hash: u64, key: &Q) -> Option<usize>
+    fn find<Q: core::hash::Hash + Eq + ?Sized>(&self, key: &Q) -> Option<usize>
     where
         K: core::borrow::Borrow<Q>,
     {
-        debug_assert!(!self.hashes.is_empty());
-        debug_assert!(hash > TOMBSTONE);
-
-        let mut i = (hash as usize) & self.mask;
-        let start_i = i;
-        let mut first_tombstone = None;
-        let mut visited = 0;
-
-        while visited < self.hashes.len() {
-            let current_hash = self.hashes[i];
-
-            if current_hash == EMPTY {
-                return first_tombstone.or(Some(i));
-            }
+        if self.table.elements == 0 {
+            return None;
+        }
 
-            if current_hash == TOMBSTONE && first_tombstone.is_none() {
-                first_tombstone = Some(i);
-            } else if current_hash == hash {
-                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key is initialized
-                let current_key = unsafe { &*self.keys[i].as_ptr() };
+        let hash = self.hash_builder.hash_one(key);
 
-                if key == current_key.borrow() {
-                    return Some(i);
-                }
-            }
+        self.table.find(hash, |other| other.borrow() == key)
+    }
+}
 
-            i = (i + 1) & self.mask;
-            visited += 1;
+impl<K: core::hash::Hash + Eq, V, S: core::hash::BuildHasher + Default> Default
+    for HashMap<K, V, S>
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
 
-            if i == start_i {
-                return first_tombstone;
-            }
+impl<K, V, S, A> Clone for HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq + Clone,
+    V: Clone,
+    S: core::hash::BuildHasher + Clone,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+            hash_builder: self.hash_builder.clone(),
         }
+    }
+}
 
-        // If we've probed all slots and found no place, return the first tombstone if any
-        first_tombstone
+impl<K, V, S, A, T, B> PartialEq<HashMap<K, V, T, B>> for HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    V: PartialEq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+    T: core::hash::BuildHasher,
+    B: Allocator + Clone,
+{
+    fn eq(&self, other: &HashMap<K, V, T, B>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|other| value == other))
     }
+}
 
-    /// Resizes the hash map to at least double its current capacity, rehashing all entries.
-    #[inline]
-    fn resize(&mut self) {
-        let new_capacity = if self.hashes.is_empty() {
-            DEFAULT_CAPACITY
-        } else {
-            self.hashes.len() * 2
-        };
-        let mut new_map = Self::with_capacity(new_capacity);
+impl<K, V, S, A> Eq for HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    V: Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+}
 
-        for i in 0..self.hashes.len() {
-            let hash = self.hashes[i];
+impl<K, V, S, A, Q> core::ops::Index<&Q> for HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq + core::borrow::Borrow<Q>,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+    Q: core::hash::Hash + Eq + ?Sized,
+{
+    type Output = V;
 
-            if hash > TOMBSTONE {
-                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
-                unsafe {
-                    let key =
-                        core::mem::replace(&mut self.keys[i], core::mem::MaybeUninit::uninit());
-                    let value =
-                        core::mem::replace(&mut self.values[i], core::mem::MaybeUninit::uninit());
+    /// Returns the value for `key`.
+    ///
+    /// # Panics
+    /// Panics if `key` is not in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("Key not found")
+    }
+}
 
-                    new_map.insert_unchecked(hash, key.assume_init(), value.assume_init());
-                }
-            }
-        }
+impl<K, V, S, A: Allocator> IntoIterator for HashMap<K, V, S, A> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, A>;
 
-        *self = new_map;
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            cursor: FullSlots::new(&self.table),
+            table: self.table,
+        }
     }
+}
 
-    /// Inserts a key-value pair without checking capacity, assuming a slot is available.
-    ///
-    /// # SAFETY
-    /// The caller must ensure:
-    /// - `hash` must be greater than `TOMBSTONE`.
-    /// - A slot must be available (caller must ensure via `find_slot`).
-    #[inline]
-    unsafe fn insert_unchecked(&mut self, hash: u64, key: K, value: V) {
-        debug_assert!(hash > TOMBSTONE);
+impl<'a, K, V, S, A> IntoIterator for &'a HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, A>;
 
-        let i = self.find_slot(hash, &key).expect("HashMap is full");
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
-        if self.hashes[i] <= TOMBSTONE {
-            self.elements += 1;
+impl<'a, K, V, S, A> IntoIterator for &'a mut HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V, A>;
 
-            if self.hashes[i] == TOMBSTONE {
-                self.tombstone_count -= 1;
-            }
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S, A> Extend<(K, V)> for HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
         }
+    }
+}
 
-        self.hashes[i] = hash;
-        self.keys[i].write(key);
-        self.values[i].write(value);
+impl<'a, K, V, S, A> Extend<(&'a K, &'a V)> for HashMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq + Copy,
+    V: Copy,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
     }
 }
 
-impl<K: core::hash::Hash + Eq, V> Default for HashMap<K, V> {
-    #[inline(always)]
-    fn default() -> Self {
-        Self::new()
+impl<K, V, S> FromIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+
+        map.extend(iter);
+
+        map
     }
 }
 
-impl<K, V> Drop for HashMap<K, V> {
-    fn drop(&mut self) {
-        for i in 0..self.hashes.len() {
-            if self.hashes[i] > TOMBSTONE {
-                // SAFETY: Slot is occupied (hash > TOMBSTONE), so key and value are initialized
-                unsafe {
-                    core::ptr::drop_in_place(self.keys[i].as_mut_ptr());
-                    core::ptr::drop_in_place(self.values[i].as_mut_ptr());
-                }
-            }
-        }
+impl<K: core::hash::Hash + Eq, V, const N: usize> From<[(K, V); N]> for HashMap<K, V> {
+    #[inline]
+    fn from(array: [(K, V); N]) -> Self {
+        let mut map = Self::with_capacity(N);
+
+        map.extend(array);
+
+        map
     }
 }
@@ -0,0 +1,598 @@
+use celui_sys::alloc::{AllocError, Allocator, Global};
+
+use crate::{hash::RandomState, hashmap, hashmap::HashMap};
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the values of a `HashSet`.
+pub struct Iter<'a, T, A: Allocator = Global> {
+    iter: hashmap::Iter<'a, T, (), A>,
+}
+
+impl<'a, T, A: Allocator> Iterator for Iter<'a, T, A> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Iter<'_, T, A> {}
+
+impl<T, A: Allocator> Clone for Iter<'_, T, A> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `HashSet` and returns its values.
+pub struct IntoIter<T, A: Allocator = Global> {
+    iter: hashmap::IntoIter<T, (), A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+// -------------------------------- Drain ---------------------------------- //
+
+/// A draining iterator that removes every value from a `HashSet`.
+///
+/// Values not yielded are dropped, and the set is left empty when the iterator is dropped.
+pub struct Drain<'a, T, A: Allocator = Global> {
+    iter: hashmap::Drain<'a, T, (), A>,
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+// ---------------------------- Set operations ----------------------------- //
+
+/// An iterator over the values in both of two `HashSet`s.
+pub struct Intersection<'a, T, S = RandomState, A: Allocator = Global> {
+    iter: Iter<'a, T, A>,
+    other: &'a HashSet<T, S, A>,
+}
+
+impl<'a, T, S, A> Iterator for Intersection<'a, T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+
+        self.iter.find(|value| other.contains(*value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the values in one `HashSet` but not in another.
+pub struct Difference<'a, T, S = RandomState, A: Allocator = Global> {
+    iter: Iter<'a, T, A>,
+    other: &'a HashSet<T, S, A>,
+}
+
+impl<'a, T, S, A> Iterator for Difference<'a, T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+
+        self.iter.find(|value| !other.contains(*value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the values in exactly one of two `HashSet`s.
+pub struct SymmetricDifference<'a, T, S = RandomState, A: Allocator = Global> {
+    iter: core::iter::Chain<Difference<'a, T, S, A>, Difference<'a, T, S, A>>,
+}
+
+impl<'a, T, S, A> Iterator for SymmetricDifference<'a, T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator over the values in either of two `HashSet`s, without duplicates.
+pub struct Union<'a, T, S = RandomState, A: Allocator = Global> {
+    iter: core::iter::Chain<Iter<'a, T, A>, Difference<'a, T, S, A>>,
+}
+
+impl<'a, T, S, A> Iterator for Union<'a, T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ------------------------------- HashSet --------------------------------- //
+
+/// A hash set, stored as a `HashMap` with `()` values.
+///
+/// Iteration order is unspecified and changes between runs with the default
+/// `RandomState`; use `IndexSet` when the order matters.
+///
+/// # Example
+/// ```
+/// use celui_collections::HashSet;
+///
+/// let mut hovered = HashSet::new();
+/// assert!(hovered.insert("button"));
+/// assert!(!hovered.insert("button"));
+///
+/// let focused = HashSet::from(["button", "input"]);
+/// assert_eq!(hovered.intersection(&focused).count(), 1);
+/// assert!(hovered.is_subset(&focused));
+/// ```
+pub struct HashSet<T, S = RandomState, A: Allocator = Global> {
+    map: HashMap<T, (), S, A>, // Values are the keys of the map
+}
+
+impl<T: core::hash::Hash + Eq> HashSet<T> {
+    /// Creates a new, empty hash set.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Creates a new hash set with a specified initial capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a new hash set with a specified initial capacity, or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Ok(Self {
+            map: HashMap::try_with_capacity(capacity)?,
+        })
+    }
+}
+
+impl<T: core::hash::Hash + Eq, S: core::hash::BuildHasher> HashSet<T, S> {
+    /// Creates a new, empty hash set which hashes values with `hash_builder`.
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hash_builder),
+        }
+    }
+
+    /// Creates a new hash set with a specified initial capacity, hashing values with
+    /// `hash_builder`.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hash_builder),
+        }
+    }
+}
+
+impl<T: core::hash::Hash + Eq, A: Allocator + Clone> HashSet<T, RandomState, A> {
+    /// Creates a new, empty hash set which allocates from `allocator`.
+    #[inline]
+    pub fn new_in(allocator: A) -> Self {
+        Self {
+            map: HashMap::new_in(allocator),
+        }
+    }
+
+    /// Creates a new hash set with a specified initial capacity, allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self {
+            map: HashMap::with_capacity_in(capacity, allocator),
+        }
+    }
+}
+
+impl<T, S, A> HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Creates a new hash set with a specified initial capacity, hashing values with
+    /// `hash_builder` and allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_and_hasher_in(capacity: usize, hash_builder: S, allocator: A) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher_in(capacity, hash_builder, allocator),
+        }
+    }
+
+    /// Returns a reference to the set's hasher builder.
+    #[inline(always)]
+    pub const fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// Returns a reference to the set's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.map.allocator()
+    }
+
+    /// Returns the number of values in the set.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the number of values the set can hold without growing.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns `true` if the set contains no values.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns `true` if the set contains the given value.
+    #[inline(always)]
+    pub fn contains<Q: core::hash::Hash + Eq + ?Sized>(&self, value: &Q) -> bool
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the value in the set equal to the given one, or `None`.
+    #[inline]
+    pub fn get<Q: core::hash::Hash + Eq + ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Returns an iterator over the set's values.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T, A> {
+        Iter {
+            iter: self.map.iter(),
+        }
+    }
+
+    /// Removes every value from the set, and returns them as an iterator.
+    ///
+    /// The capacity is kept. Values not consumed are dropped along with the iterator.
+    #[inline(always)]
+    pub fn drain(&mut self) -> Drain<'_, T, A> {
+        Drain {
+            iter: self.map.drain(),
+        }
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// Returns `true` if the value was inserted, or `false` if it was already present, in
+    /// which case the value in the set is kept and `value` is dropped.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Adds a value to the set, or returns an error if growing the set fails.
+    #[inline]
+    pub fn try_insert(&mut self, value: T) -> Result<bool, AllocError> {
+        Ok(self.map.try_insert(value, ())?.is_none())
+    }
+
+    /// Removes a value from the set, and returns `true` if it was present.
+    #[inline]
+    pub fn remove<Q: core::hash::Hash + Eq + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set equal to the given one, or `None`.
+    #[inline]
+    pub fn take<Q: core::hash::Hash + Eq + ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+
+    /// Keeps only the values for which `f` returns `true`, removing the others.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|value, _| f(value));
+    }
+
+    /// Removes all values from the set, leaving it empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Ensures the set has space for at least `additional` more values.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// Ensures the set has space for at least `additional` more values, or returns an error if
+    /// growing fails, leaving the set unchanged.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.map.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+    }
+
+    /// Returns an iterator over the values in `self` or `other`, without duplicates.
+    #[inline]
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, S, A> {
+        Union {
+            iter: self.iter().chain(other.difference(self)),
+        }
+    }
+
+    /// Returns an iterator over the values in both `self` and `other`.
+    #[inline]
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T, S, A> {
+        // Walk the smaller set, and look up into the larger one
+        let (smaller, larger) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        Intersection {
+            iter: smaller.iter(),
+            other: larger,
+        }
+    }
+
+    /// Returns an iterator over the values in `self` but not in `other`.
+    #[inline]
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, S, A> {
+        Difference {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Returns an iterator over the values in exactly one of `self` and `other`.
+    #[inline]
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T, S, A> {
+        SymmetricDifference {
+            iter: self.difference(other).chain(other.difference(self)),
+        }
+    }
+
+    /// Returns `true` if `self` and `other` have no values in common.
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    /// Returns `true` if every value of `self` is in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|value| other.contains(value))
+    }
+
+    /// Returns `true` if every value of `other` is in `self`.
+    #[inline(always)]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+impl<T: core::hash::Hash + Eq, S: core::hash::BuildHasher + Default> Default for HashSet<T, S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T, S, A> Clone for HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq + Clone,
+    S: core::hash::BuildHasher + Clone,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, S, A, U, B> PartialEq<HashSet<T, U, B>> for HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+    U: core::hash::BuildHasher,
+    B: Allocator + Clone,
+{
+    #[inline]
+    fn eq(&self, other: &HashSet<T, U, B>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T, S, A> Eq for HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+}
+
+impl<T, S, A: Allocator> IntoIterator for HashSet<T, S, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, S, A> IntoIterator for &'a HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, S, A> Extend<T> for HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+impl<'a, T, S, A> Extend<&'a T> for HashSet<T, S, A>
+where
+    T: core::hash::Hash + Eq + Copy,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, S> FromIterator<T> for HashSet<T, S>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::default();
+
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<T: core::hash::Hash + Eq, const N: usize> From<[T; N]> for HashSet<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        let mut set = Self::with_capacity(N);
+
+        set.extend(array);
+
+        set
+    }
+}
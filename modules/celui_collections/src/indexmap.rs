@@ -0,0 +1,1208 @@
+use celui_sys::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+use crate::{hash::RandomState, hashmap::RawTable, vec::Vec};
+
+// -------------------------------- Bucket --------------------------------- //
+
+/// A key-value pair of an `IndexMap`, with the hash of its key.
+#[derive(Clone)]
+struct Bucket<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the key-value pairs of an `IndexMap`, in order.
+pub struct Iter<'a, K, V> {
+    iter: core::slice::Iter<'a, Bucket<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|bucket| (&bucket.key, &bucket.value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|bucket| (&bucket.key, &bucket.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+// ------------------------------- IterMut --------------------------------- //
+
+/// A mutable iterator over the key-value pairs of an `IndexMap`, in order.
+pub struct IterMut<'a, K, V> {
+    iter: core::slice::IterMut<'a, Bucket<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `IndexMap` and returns its key-value pairs, in order.
+pub struct IntoIter<K, V, A: Allocator = Global> {
+    iter: crate::vec::IntoIter<Bucket<K, V>, A>,
+}
+
+impl<K, V, A: Allocator> Iterator for IntoIter<K, V, A> {
+    type Item = (K, V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|bucket| (bucket.key, bucket.value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ---------------------- Keys / Values / ValuesMut ------------------------ //
+
+/// An iterator over the keys of an `IndexMap`, in order.
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+/// An iterator over the values of an `IndexMap`, in order.
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+/// A mutable iterator over the values of an `IndexMap`, in order.
+pub struct ValuesMut<'a, K, V> {
+    iter: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ValuesMut<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+// -------------------------------- Entry ---------------------------------- //
+
+/// A view into a single entry of an `IndexMap`, which is either occupied or vacant.
+pub enum Entry<'a, K, V, A: Allocator = Global> {
+    Occupied(OccupiedEntry<'a, K, V, A>),
+    Vacant(VacantEntry<'a, K, V, A>),
+}
+
+impl<'a, K, V, A: Allocator> Entry<'a, K, V, A> {
+    /// Returns a reference to the entry's key.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Returns the position of the entry, or the one it would be inserted at if vacant.
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        match self {
+            Entry::Occupied(entry) => entry.index(),
+            Entry::Vacant(entry) => entry.index(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable reference
+    /// to the value.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts `V::default()` if the entry is vacant, and returns a mutable reference to the
+    /// value.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value if the entry is occupied, and returns the entry.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// A view into an occupied entry of an `IndexMap`.
+pub struct OccupiedEntry<'a, K, V, A: Allocator = Global> {
+    raw: &'a mut RawIndexMap<K, V, A>,
+    index: usize, // Position of the pair
+}
+
+impl<'a, K, V, A: Allocator> OccupiedEntry<'a, K, V, A> {
+    /// Returns a reference to the key in the map.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        &self.raw.entries[self.index].key
+    }
+
+    /// Returns the position of the pair in the map.
+    #[inline(always)]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the value.
+    #[inline(always)]
+    pub fn get(&self) -> &V {
+        &self.raw.entries[self.index].value
+    }
+
+    /// Returns a mutable reference to the value.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.raw.entries[self.index].value
+    }
+
+    /// Converts the entry into a mutable reference to the value, tied to the map's borrow.
+    #[inline(always)]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.raw.entries[self.index].value
+    }
+
+    /// Replaces the value, and returns the old one. The pair keeps its position.
+    #[inline(always)]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the pair by moving the last pair into its position, and returns it.
+    #[inline]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.raw.swap_remove_index(self.index)
+    }
+
+    /// Removes the pair by shifting the pairs after it down by one, and returns it.
+    #[inline]
+    pub fn shift_remove_entry(self) -> (K, V) {
+        self.raw.shift_remove_index(self.index)
+    }
+}
+
+/// A view into a vacant entry of an `IndexMap`.
+pub struct VacantEntry<'a, K, V, A: Allocator = Global> {
+    raw: &'a mut RawIndexMap<K, V, A>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K, V, A: Allocator> VacantEntry<'a, K, V, A> {
+    /// Returns a reference to the key that would be inserted.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the position the pair would be inserted at, the end of the map.
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.raw.entries.len()
+    }
+
+    /// Takes back ownership of the key.
+    #[inline(always)]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts the pair at the end of the map, and returns a mutable reference to the value.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        // SAFETY: Room was made for one more pair when the entry was made, and the key isn't
+        // in the map
+        let index = unsafe { self.raw.push(self.hash, self.key, value) };
+
+        &mut self.raw.entries[index].value
+    }
+}
+
+// ----------------------------- RawIndexMap ------------------------------- //
+
+/// The pairs of an `IndexMap`, without its hasher.
+///
+/// Pairs are kept in insertion order in `entries`, along with their hashes. The `indices`
+/// table maps the hash of every key to the position of its pair, so lookups hash the key
+/// once and compare keys in `entries`, and the stored hashes are all that's needed to grow.
+struct RawIndexMap<K, V, A: Allocator> {
+    entries: Vec<Bucket<K, V>, A>,   // Pairs in order
+    indices: RawTable<usize, (), A>, // Position of every pair, found by its hash
+}
+
+impl<K, V, A: Allocator + Clone> RawIndexMap<K, V, A> {
+    /// Creates an empty map with room for at least `capacity` pairs, or an error if
+    /// allocation fails.
+    fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        Ok(Self {
+            entries: Vec::try_with_capacity_in(capacity, allocator.clone())?,
+            indices: RawTable::try_with_capacity_in(capacity, allocator)?,
+        })
+    }
+
+    /// Makes room for `additional` more pairs. On failure the map is left unchanged.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        let entries = &self.entries;
+
+        self.indices
+            .try_reserve(additional, |&index| entries[index].hash)?;
+        self.entries.try_reserve(additional)
+    }
+
+    /// Shrinks both the pairs and the table as much as possible.
+    fn shrink_to_fit(&mut self) {
+        let entries = &self.entries;
+
+        if let Err(error) = self.indices.try_shrink_to_fit(|&index| entries[index].hash) {
+            handle_alloc_error(error);
+        }
+
+        self.entries.shrink_to_fit();
+    }
+}
+
+impl<K, V, A: Allocator> RawIndexMap<K, V, A> {
+    /// Returns the position of the pair whose key has `hash` and satisfies `eq`, or `None`.
+    #[inline]
+    fn find<F: FnMut(&K) -> bool>(&self, hash: u64, mut eq: F) -> Option<usize> {
+        let slot = self
+            .indices
+            .find(hash, |&index| eq(&self.entries[index].key))?;
+
+        // SAFETY: Slot is full, so the pair is initialized
+        Some(unsafe { (*self.indices.slot(slot)).0 })
+    }
+
+    /// Returns the slot of `indices` holding `index`.
+    #[inline]
+    fn find_slot(&self, index: usize) -> usize {
+        self.indices
+            .find(self.entries[index].hash, |&other| other == index)
+            .expect("Pair missing from the index table")
+    }
+
+    /// Changes the position stored for the pair at `old` to `new`.
+    #[inline]
+    fn set_index(&mut self, old: usize, new: usize) {
+        let slot = self.find_slot(old);
+
+        // SAFETY: Slot is full, so the pair is initialized
+        unsafe { (*self.indices.slot_mut(slot)).0 = new };
+    }
+
+    /// Appends a pair, and returns its position.
+    ///
+    /// # SAFETY
+    /// There must be room for one more pair, and `key` must have `hash` and not be in the map.
+    #[inline]
+    unsafe fn push(&mut self, hash: u64, key: K, value: V) -> usize {
+        let index = self.entries.len();
+
+        // SAFETY: The caller upholds the table's requirements
+        unsafe { self.indices.insert_unchecked(hash, (index, ())) };
+
+        self.entries.push(Bucket { hash, key, value });
+
+        index
+    }
+
+    /// Removes the pair at `index` by moving the last pair into its place, and returns it.
+    fn swap_remove_index(&mut self, index: usize) -> (K, V) {
+        let slot = self.find_slot(index);
+
+        // SAFETY: Slot is full
+        unsafe { self.indices.remove_at(slot) };
+
+        let last = self.entries.len() - 1;
+
+        if index != last {
+            self.set_index(last, index);
+        }
+
+        let bucket = self.entries.swap_remove(index);
+
+        (bucket.key, bucket.value)
+    }
+
+    /// Removes the pair at `index` by shifting the pairs after it down, and returns it.
+    fn shift_remove_index(&mut self, index: usize) -> (K, V) {
+        let slot = self.find_slot(index);
+
+        // SAFETY: Slot is full
+        unsafe { self.indices.remove_at(slot) };
+
+        for i in index + 1..self.entries.len() {
+            self.set_index(i, i - 1);
+        }
+
+        let bucket = self.entries.remove(index);
+
+        (bucket.key, bucket.value)
+    }
+
+    /// Moves the pair at `from` to `to`, shifting the pairs in between.
+    fn move_index(&mut self, from: usize, to: usize) {
+        let slot = self.find_slot(from);
+
+        // The moved pair is marked with an out of range position while the others shift, so
+        // it can't be mistaken for one of them
+        // SAFETY: Slot is full, so the pair is initialized
+        unsafe { (*self.indices.slot_mut(slot)).0 = usize::MAX };
+
+        if from < to {
+            for i in from + 1..=to {
+                self.set_index(i, i - 1);
+            }
+
+            self.entries[from..=to].rotate_left(1);
+        } else {
+            for i in (to..from).rev() {
+                self.set_index(i, i + 1);
+            }
+
+            self.entries[to..=from].rotate_right(1);
+        }
+
+        // SAFETY: Slot is still full, with the same key
+        unsafe { (*self.indices.slot_mut(slot)).0 = to };
+    }
+
+    /// Rebuilds `indices` from the positions of `entries`, after they were reordered.
+    fn rebuild(&mut self) {
+        self.indices.clear_ctrl();
+
+        for (index, bucket) in self.entries.iter().enumerate() {
+            // SAFETY: The table held every pair before, so it has room for all of them, and
+            // keys are unique
+            unsafe { self.indices.insert_unchecked(bucket.hash, (index, ())) };
+        }
+    }
+
+    /// Keeps only the pairs for which `f` returns `true`, in order.
+    fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        let len = self.entries.len();
+        let mut kept = 0;
+
+        // Kept pairs are swapped to the front, so they keep their order
+        for i in 0..len {
+            let bucket = &mut self.entries[i];
+
+            if f(&bucket.key, &mut bucket.value) {
+                self.entries.swap(kept, i);
+
+                kept += 1;
+            }
+        }
+
+        if kept < len {
+            self.entries.truncate(kept);
+            self.rebuild();
+        }
+    }
+
+    /// Removes every pair.
+    #[inline]
+    fn clear(&mut self) {
+        self.indices.clear_ctrl();
+        self.entries.clear();
+    }
+}
+
+impl<K: Clone, V: Clone, A: Allocator + Clone> Clone for RawIndexMap<K, V, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+// ------------------------------- IndexMap -------------------------------- //
+
+/// A hash map that keeps its pairs in insertion order.
+///
+/// Pairs are stored in a `Vec`, and looked up through a hash table of their positions, so
+/// iteration follows the order pairs were inserted in regardless of the hasher, and pairs can
+/// also be accessed by position. Replacing the value of a key keeps its position.
+///
+/// Removing is either a `swap_remove`, which moves the last pair into the hole in constant
+/// time, or a `shift_remove`, which keeps the order but shifts every pair after the removed
+/// one.
+///
+/// # Example
+/// ```
+/// use celui_collections::IndexMap;
+///
+/// let mut style = IndexMap::new();
+/// style.insert("color", "red");
+/// style.insert("padding", "4px");
+/// style.insert("margin", "2px");
+/// style.insert("color", "blue");
+///
+/// let keys: Vec<_> = style.keys().copied().collect();
+/// assert_eq!(keys, ["color", "padding", "margin"]);
+///
+/// assert_eq!(style.shift_remove(&"padding"), Some("4px"));
+/// assert_eq!(style.get_index(1), Some((&"margin", &"2px")));
+/// assert_eq!(style[0], "blue");
+/// ```
+pub struct IndexMap<K, V, S = RandomState, A: Allocator = Global> {
+    raw: RawIndexMap<K, V, A>, // Pairs in order, and their positions by hash
+    hash_builder: S,           // Builds a hasher for every key
+}
+
+impl<K: core::hash::Hash + Eq, V> IndexMap<K, V> {
+    /// Creates a new, empty index map.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new index map with a specified initial capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new index map with a specified initial capacity, or an error if allocation
+    /// fails.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<K: core::hash::Hash + Eq, V, S: core::hash::BuildHasher> IndexMap<K, V, S> {
+    /// Creates a new, empty index map which hashes keys with `hash_builder`.
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Creates a new index map with a specified initial capacity, hashing keys with
+    /// `hash_builder`.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, hash_builder, Global)
+    }
+}
+
+impl<K: core::hash::Hash + Eq, V, A: Allocator + Clone> IndexMap<K, V, RandomState, A> {
+    /// Creates a new, empty index map which allocates from `allocator`.
+    #[inline]
+    pub fn new_in(allocator: A) -> Self {
+        Self::with_capacity_in(0, allocator)
+    }
+
+    /// Creates a new index map with a specified initial capacity, allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, RandomState::new(), allocator)
+    }
+
+    /// Creates a new index map with a specified initial capacity, allocating from `allocator`,
+    /// or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        Self::try_with_capacity_and_hasher_in(capacity, RandomState::new(), allocator)
+    }
+}
+
+impl<K, V, S, A> IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Creates a new index map with a specified initial capacity, hashing keys with
+    /// `hash_builder` and allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_and_hasher_in(capacity: usize, hash_builder: S, allocator: A) -> Self {
+        Self::try_with_capacity_and_hasher_in(capacity, hash_builder, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new index map with a specified initial capacity, hashing keys with
+    /// `hash_builder` and allocating from `allocator`, or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_and_hasher_in(
+        capacity: usize,
+        hash_builder: S,
+        allocator: A,
+    ) -> Result<Self, AllocError> {
+        Ok(Self {
+            raw: RawIndexMap::try_with_capacity_in(capacity, allocator)?,
+            hash_builder,
+        })
+    }
+
+    /// Returns a reference to the map's hasher builder.
+    #[inline(always)]
+    pub const fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns a reference to the map's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.raw.indices.allocator()
+    }
+
+    /// Returns the number of key-value pairs in the map.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.raw.entries.len()
+    }
+
+    /// Returns the number of pairs the map can hold without growing.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.raw.entries.capacity().min(self.raw.indices.capacity())
+    }
+
+    /// Returns `true` if the map contains no key-value pairs.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.raw.entries.is_empty()
+    }
+
+    /// Returns `true` if the map contains the given key.
+    #[inline(always)]
+    pub fn contains_key<Q: core::hash::Hash + Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Returns a reference to the value associated with the given key, or `None` if not found.
+    #[inline]
+    pub fn get<Q: core::hash::Hash + Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        let index = self.get_index_of(key)?;
+
+        Some(&self.raw.entries[index].value)
+    }
+
+    /// Returns the key-value pair stored for the given key, or `None` if not found.
+    #[inline]
+    pub fn get_key_value<Q: core::hash::Hash + Eq + ?Sized>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        self.get_index(self.get_index_of(key)?)
+    }
+
+    /// Returns a mutable reference to the value associated with the key, or `None` if not found.
+    #[inline]
+    pub fn get_mut<Q: core::hash::Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        let index = self.get_index_of(key)?;
+
+        Some(&mut self.raw.entries[index].value)
+    }
+
+    /// Returns the position of the given key, or `None` if not found.
+    #[inline]
+    pub fn get_index_of<Q: core::hash::Hash + Eq + ?Sized>(&self, key: &Q) -> Option<usize>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let hash = self.hash_builder.hash_one(key);
+
+        self.raw.find(hash, |other| other.borrow() == key)
+    }
+
+    /// Returns the key-value pair at position `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let bucket = self.raw.entries.get(index)?;
+
+        Some((&bucket.key, &bucket.value))
+    }
+
+    /// Returns the key and a mutable reference to the value at position `index`, or `None` if
+    /// out of bounds.
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let bucket = self.raw.entries.get_mut(index)?;
+
+        Some((&bucket.key, &mut bucket.value))
+    }
+
+    /// Returns the first key-value pair, or `None` if the map is empty.
+    #[inline(always)]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Returns the last key-value pair, or `None` if the map is empty.
+    #[inline]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().checked_sub(1)?)
+    }
+
+    /// Returns an iterator over the map's key-value pairs, in order.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.raw.entries.as_slice().iter(),
+        }
+    }
+
+    /// Returns a mutable iterator over the map's key-value pairs, in order.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.raw.entries.as_slice_mut().iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the map's keys, in order.
+    #[inline(always)]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the map's values, in order.
+    #[inline(always)]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    /// Returns a mutable iterator over the map's values, in order.
+    #[inline(always)]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+
+    /// Returns the entry for `key`, for in-place lookup and insertion.
+    ///
+    /// For a vacant entry, the map grows here if needed, so inserting never allocates.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, A> {
+        self.try_entry(key)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Returns the entry for `key`, or an error if growing the map fails.
+    ///
+    /// On failure, `key` is dropped and the map is left unchanged.
+    pub fn try_entry(&mut self, key: K) -> Result<Entry<'_, K, V, A>, AllocError> {
+        let hash = self.hash_builder.hash_one(&key);
+
+        if let Some(index) = self.raw.find(hash, |other| *other == key) {
+            return Ok(Entry::Occupied(OccupiedEntry {
+                raw: &mut self.raw,
+                index,
+            }));
+        }
+
+        self.raw.try_reserve(1)?;
+
+        Ok(Entry::Vacant(VacantEntry {
+            raw: &mut self.raw,
+            hash,
+            key,
+        }))
+    }
+
+    /// Inserts a key-value pair, replacing the old value if the key already exists.
+    ///
+    /// New keys are added at the end, and replaced ones keep their position. Returns the
+    /// previous value if the key was already present, or `None` if it was inserted.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Inserts a key-value pair like `insert`, and also returns the position of the pair.
+    #[inline]
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        self.try_insert_full(key, value)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Inserts a key-value pair, or returns an error if growing the map fails.
+    ///
+    /// On failure, `key` and `value` are dropped and the map is left unchanged.
+    #[inline]
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, AllocError> {
+        Ok(self.try_insert_full(key, value)?.1)
+    }
+
+    /// Inserts a key-value pair like `insert_full`, or returns an error if growing the map
+    /// fails.
+    pub fn try_insert_full(&mut self, key: K, value: V) -> Result<(usize, Option<V>), AllocError> {
+        match self.try_entry(key)? {
+            Entry::Occupied(mut entry) => Ok((entry.index(), Some(entry.insert(value)))),
+            Entry::Vacant(entry) => {
+                let index = entry.index();
+
+                entry.insert(value);
+
+                Ok((index, None))
+            }
+        }
+    }
+
+    /// Removes the value for the key by moving the last pair into its position, and returns
+    /// it, or `None` if not found.
+    ///
+    /// Takes constant time, but changes the order of the last pair.
+    #[inline]
+    pub fn swap_remove<Q: core::hash::Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        let index = self.get_index_of(key)?;
+
+        Some(self.raw.swap_remove_index(index).1)
+    }
+
+    /// Removes the value for the key by shifting the pairs after it down, and returns it, or
+    /// `None` if not found.
+    ///
+    /// Keeps the order of the other pairs, but takes time linear in the number of pairs after
+    /// the removed one.
+    #[inline]
+    pub fn shift_remove<Q: core::hash::Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: core::borrow::Borrow<Q>,
+    {
+        let index = self.get_index_of(key)?;
+
+        Some(self.raw.shift_remove_index(index).1)
+    }
+
+    /// Removes the pair at position `index` like `swap_remove`, or returns `None` if out of
+    /// bounds.
+    #[inline]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        (index < self.len()).then(|| self.raw.swap_remove_index(index))
+    }
+
+    /// Removes the pair at position `index` like `shift_remove`, or returns `None` if out of
+    /// bounds.
+    #[inline]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        (index < self.len()).then(|| self.raw.shift_remove_index(index))
+    }
+
+    /// Removes and returns the last key-value pair, or `None` if the map is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let last = self.len().checked_sub(1)?;
+
+        Some(self.raw.swap_remove_index(last))
+    }
+
+    /// Moves the pair at position `from` to `to`, shifting the pairs in between.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        assert!(from < self.len() && to < self.len(), "Index out of bounds");
+
+        if from != to {
+            self.raw.move_index(from, to);
+        }
+    }
+
+    /// Keeps only the pairs for which `f` returns `true`, removing the others.
+    ///
+    /// The kept pairs keep their order.
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, f: F) {
+        self.raw.retain(f);
+    }
+
+    /// Sorts the pairs by key.
+    #[inline]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_by(|key, _, other, _| key.cmp(other));
+    }
+
+    /// Sorts the pairs with a comparison function, keeping the order of equal pairs.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> core::cmp::Ordering,
+    {
+        self.raw
+            .entries
+            .sort_by(|a, b| compare(&a.key, &a.value, &b.key, &b.value));
+        self.raw.rebuild();
+    }
+
+    /// Removes all key-value pairs from the map, leaving it empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.raw.clear();
+    }
+
+    /// Ensures the map has space for at least `additional` more key-value pairs.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Ensures the map has space for at least `additional` more key-value pairs, or returns
+    /// an error if growing fails.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.raw.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.raw.shrink_to_fit();
+    }
+}
+
+impl<K: core::hash::Hash + Eq, V, S: core::hash::BuildHasher + Default> Default
+    for IndexMap<K, V, S>
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S, A> Clone for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq + Clone,
+    V: Clone,
+    S: core::hash::BuildHasher + Clone,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            raw: self.raw.clone(),
+            hash_builder: self.hash_builder.clone(),
+        }
+    }
+}
+
+impl<K, V, S, A, T, B> PartialEq<IndexMap<K, V, T, B>> for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    V: PartialEq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+    T: core::hash::BuildHasher,
+    B: Allocator + Clone,
+{
+    /// Compares the pairs of both maps, regardless of their order.
+    fn eq(&self, other: &IndexMap<K, V, T, B>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|other| value == other))
+    }
+}
+
+impl<K, V, S, A> Eq for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    V: Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+}
+
+impl<K, V, S, A, Q> core::ops::Index<&Q> for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq + core::borrow::Borrow<Q>,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+    Q: core::hash::Hash + Eq + ?Sized,
+{
+    type Output = V;
+
+    /// Returns the value for `key`.
+    ///
+    /// # Panics
+    /// Panics if `key` is not in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("Key not found")
+    }
+}
+
+impl<K, V, S, A> core::ops::Index<usize> for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Output = V;
+
+    /// Returns the value at position `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &V {
+        self.get_index(index).expect("Index out of bounds").1
+    }
+}
+
+impl<K, V, S, A: Allocator> IntoIterator for IndexMap<K, V, S, A> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.raw.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S, A> IntoIterator for &'a IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S, A> IntoIterator for &'a mut IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S, A> Extend<(K, V)> for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K, V, S, A> Extend<(&'a K, &'a V)> for IndexMap<K, V, S, A>
+where
+    K: core::hash::Hash + Eq + Copy,
+    V: Copy,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for IndexMap<K, V, S>
+where
+    K: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<K: core::hash::Hash + Eq, V, const N: usize> From<[(K, V); N]> for IndexMap<K, V> {
+    #[inline]
+    fn from(array: [(K, V); N]) -> Self {
+        let mut map = Self::with_capacity(N);
+
+        map.extend(array);
+
+        map
+    }
+}
@@ -0,0 +1,522 @@
+use celui_sys::alloc::{AllocError, Allocator, Global};
+
+use crate::{hash::RandomState, indexmap, indexmap::IndexMap};
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the values of an `IndexSet`, in order.
+pub struct Iter<'a, T> {
+    iter: indexmap::Keys<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `IndexSet` and returns its values, in order.
+pub struct IntoIter<T, A: Allocator = Global> {
+    iter: indexmap::IntoIter<T, (), A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ------------------------------- IndexSet -------------------------------- //
+
+/// A hash set that keeps its values in insertion order, stored as an `IndexMap` with `()`
+/// values.
+///
+/// # Example
+/// ```
+/// use celui_collections::IndexSet;
+///
+/// let mut children = IndexSet::new();
+/// children.insert("header");
+/// children.insert("body");
+/// children.insert("footer");
+/// assert!(!children.insert("header"));
+///
+/// children.move_index(2, 0);
+///
+/// let order: Vec<_> = children.iter().copied().collect();
+/// assert_eq!(order, ["footer", "header", "body"]);
+/// assert_eq!(children.get_index_of("body"), Some(2));
+/// ```
+pub struct IndexSet<T, S = RandomState, A: Allocator = Global> {
+    map: IndexMap<T, (), S, A>, // Values are the keys of the map
+}
+
+impl<T: core::hash::Hash + Eq> IndexSet<T> {
+    /// Creates a new, empty index set.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            map: IndexMap::new(),
+        }
+    }
+
+    /// Creates a new index set with a specified initial capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: IndexMap::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a new index set with a specified initial capacity, or an error if allocation
+    /// fails.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Ok(Self {
+            map: IndexMap::try_with_capacity(capacity)?,
+        })
+    }
+}
+
+impl<T: core::hash::Hash + Eq, S: core::hash::BuildHasher> IndexSet<T, S> {
+    /// Creates a new, empty index set which hashes values with `hash_builder`.
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            map: IndexMap::with_hasher(hash_builder),
+        }
+    }
+
+    /// Creates a new index set with a specified initial capacity, hashing values with
+    /// `hash_builder`.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            map: IndexMap::with_capacity_and_hasher(capacity, hash_builder),
+        }
+    }
+}
+
+impl<T: core::hash::Hash + Eq, A: Allocator + Clone> IndexSet<T, RandomState, A> {
+    /// Creates a new, empty index set which allocates from `allocator`.
+    #[inline]
+    pub fn new_in(allocator: A) -> Self {
+        Self {
+            map: IndexMap::new_in(allocator),
+        }
+    }
+
+    /// Creates a new index set with a specified initial capacity, allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self {
+            map: IndexMap::with_capacity_in(capacity, allocator),
+        }
+    }
+}
+
+impl<T, S, A> IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Creates a new index set with a specified initial capacity, hashing values with
+    /// `hash_builder` and allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_and_hasher_in(capacity: usize, hash_builder: S, allocator: A) -> Self {
+        Self {
+            map: IndexMap::with_capacity_and_hasher_in(capacity, hash_builder, allocator),
+        }
+    }
+
+    /// Returns a reference to the set's hasher builder.
+    #[inline(always)]
+    pub const fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// Returns a reference to the set's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.map.allocator()
+    }
+
+    /// Returns the number of values in the set.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the number of values the set can hold without growing.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns `true` if the set contains no values.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns `true` if the set contains the given value.
+    #[inline(always)]
+    pub fn contains<Q: core::hash::Hash + Eq + ?Sized>(&self, value: &Q) -> bool
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the value in the set equal to the given one, or `None`.
+    #[inline]
+    pub fn get<Q: core::hash::Hash + Eq + ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Returns the position of the given value, or `None` if not found.
+    #[inline(always)]
+    pub fn get_index_of<Q: core::hash::Hash + Eq + ?Sized>(&self, value: &Q) -> Option<usize>
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.get_index_of(value)
+    }
+
+    /// Returns the value at position `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(value, _)| value)
+    }
+
+    /// Returns the first value, or `None` if the set is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(value, _)| value)
+    }
+
+    /// Returns the last value, or `None` if the set is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(value, _)| value)
+    }
+
+    /// Returns an iterator over the set's values, in order.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.map.keys(),
+        }
+    }
+
+    /// Adds a value at the end of the set.
+    ///
+    /// Returns `true` if the value was inserted, or `false` if it was already present, in
+    /// which case the value in the set keeps its position and `value` is dropped.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.insert_full(value).1
+    }
+
+    /// Adds a value like `insert`, and also returns the position of the value.
+    #[inline]
+    pub fn insert_full(&mut self, value: T) -> (usize, bool) {
+        let (index, old) = self.map.insert_full(value, ());
+
+        (index, old.is_none())
+    }
+
+    /// Adds a value at the end of the set, or returns an error if growing the set fails.
+    #[inline]
+    pub fn try_insert(&mut self, value: T) -> Result<bool, AllocError> {
+        Ok(self.map.try_insert(value, ())?.is_none())
+    }
+
+    /// Removes a value by moving the last value into its position, and returns `true` if it
+    /// was present.
+    #[inline]
+    pub fn swap_remove<Q: core::hash::Hash + Eq + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.swap_remove(value).is_some()
+    }
+
+    /// Removes a value by shifting the values after it down, and returns `true` if it was
+    /// present.
+    #[inline]
+    pub fn shift_remove<Q: core::hash::Hash + Eq + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: core::borrow::Borrow<Q>,
+    {
+        self.map.shift_remove(value).is_some()
+    }
+
+    /// Removes the value at position `index` like `swap_remove`, or returns `None` if out of
+    /// bounds.
+    #[inline]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.swap_remove_index(index).map(|(value, _)| value)
+    }
+
+    /// Removes the value at position `index` like `shift_remove`, or returns `None` if out of
+    /// bounds.
+    #[inline]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.shift_remove_index(index).map(|(value, _)| value)
+    }
+
+    /// Removes and returns the last value, or `None` if the set is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(value, _)| value)
+    }
+
+    /// Moves the value at position `from` to `to`, shifting the values in between.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` is out of bounds.
+    #[inline]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.map.move_index(from, to);
+    }
+
+    /// Keeps only the values for which `f` returns `true`, removing the others.
+    ///
+    /// The kept values keep their order.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|value, _| f(value));
+    }
+
+    /// Sorts the values.
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys();
+    }
+
+    /// Sorts the values with a comparison function, keeping the order of equal values.
+    #[inline]
+    pub fn sort_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, mut compare: F) {
+        self.map.sort_by(|value, _, other, _| compare(value, other));
+    }
+
+    /// Removes all values from the set, leaving it empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Ensures the set has space for at least `additional` more values.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// Ensures the set has space for at least `additional` more values, or returns an error if
+    /// growing fails.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.map.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+    }
+
+    /// Returns `true` if every value of `self` is in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|value| other.contains(value))
+    }
+
+    /// Returns `true` if every value of `other` is in `self`.
+    #[inline(always)]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+impl<T: core::hash::Hash + Eq, S: core::hash::BuildHasher + Default> Default for IndexSet<T, S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T, S, A> Clone for IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq + Clone,
+    S: core::hash::BuildHasher + Clone,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, S, A, U, B> PartialEq<IndexSet<T, U, B>> for IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+    U: core::hash::BuildHasher,
+    B: Allocator + Clone,
+{
+    /// Compares the values of both sets, regardless of their order.
+    #[inline]
+    fn eq(&self, other: &IndexSet<T, U, B>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T, S, A> Eq for IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+}
+
+impl<T, S, A> core::ops::Index<usize> for IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Output = T;
+
+    /// Returns the value at position `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get_index(index).expect("Index out of bounds")
+    }
+}
+
+impl<T, S, A: Allocator> IntoIterator for IndexSet<T, S, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, S, A> IntoIterator for &'a IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, S, A> Extend<T> for IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+impl<'a, T, S, A> Extend<&'a T> for IndexSet<T, S, A>
+where
+    T: core::hash::Hash + Eq + Copy,
+    S: core::hash::BuildHasher,
+    A: Allocator + Clone,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, S> FromIterator<T> for IndexSet<T, S>
+where
+    T: core::hash::Hash + Eq,
+    S: core::hash::BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::default();
+
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<T: core::hash::Hash + Eq, const N: usize> From<[T; N]> for IndexSet<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        let mut set = Self::with_capacity(N);
+
+        set.extend(array);
+
+        set
+    }
+}
@@ -1,7 +1,38 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-mod hashmap;
-mod vec;
+pub mod arrayvec;
+pub mod binaryheap;
+pub mod btreemap;
+pub mod btreeset;
+pub mod hash;
+pub mod hashmap;
+pub mod hashset;
+pub mod indexmap;
+pub mod indexset;
+pub mod ringbuffer;
+pub mod secondarymap;
+pub mod slotmap;
+mod slots;
+pub mod smallvec;
+pub mod string;
+pub mod vec;
+pub mod vecdeque;
 
+#[cfg(feature = "std")]
+mod std;
+
+pub use crate::arrayvec::{ArrayString, ArrayVec};
+pub use crate::binaryheap::BinaryHeap;
+pub use crate::btreemap::BTreeMap;
+pub use crate::btreeset::BTreeSet;
 pub use crate::hashmap::HashMap;
-pub use crate::vec::Vec;
+pub use crate::hashset::HashSet;
+pub use crate::indexmap::IndexMap;
+pub use crate::indexset::IndexSet;
+pub use crate::ringbuffer::RingBuffer;
+pub use crate::secondarymap::SecondaryMap;
+pub use crate::slotmap::{DefaultKey, Key, SlotMap};
+pub use crate::smallvec::SmallVec;
+pub use crate::string::String;
+pub use crate::vec::{IntoIter, Iter, IterMut, Vec};
+pub use crate::vecdeque::VecDeque;
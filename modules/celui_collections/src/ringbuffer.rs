@@ -0,0 +1,448 @@
+use core::mem::MaybeUninit;
+
+pub use crate::vecdeque::{Iter, IterMut};
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `RingBuffer` and returns its elements.
+pub struct IntoIter<T, const N: usize> {
+    ring: RingBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ring.pop_front()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.ring.len, Some(self.ring.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.ring.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> core::iter::FusedIterator for IntoIter<T, N> {}
+
+// ------------------------------ RingBuffer ------------------------------- //
+
+/// A double-ended queue with a fixed capacity of `N` elements, stored inline. It never
+/// allocates.
+///
+/// Pushing onto a full buffer evicts the element at the other end and returns it, so it
+/// keeps the latest `N` elements, like an undo history or a frame time graph.
+///
+/// # Example
+/// ```
+/// use celui_collections::RingBuffer;
+///
+/// let mut history = RingBuffer::<&str, 3>::new();
+///
+/// history.push_back("type a");
+/// history.push_back("type b");
+/// history.push_back("delete");
+///
+/// // The oldest edit is forgotten to make room
+/// assert_eq!(history.push_back("paste"), Some("type a"));
+///
+/// // Undo
+/// assert_eq!(history.pop_back(), Some("paste"));
+/// assert!(history == ["type b", "delete"]);
+/// ```
+pub struct RingBuffer<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    head: usize, // Index of the front element
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a new, empty ring buffer.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            buffer: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the buffer.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity of the buffer, which is always `N`.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the buffer holds `N` elements, so pushing evicts one.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns a reference to the element at the given index from the front, or `None` if
+    /// out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: `index` is within bounds, so its slot is initialized
+            return Some(unsafe { self.buffer[self.physical(index)].assume_init_ref() });
+        }
+
+        None
+    }
+
+    /// Returns a mutable reference to the element at the given index from the front, or
+    /// `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            let index = self.physical(index);
+
+            // SAFETY: `index` is within bounds, so its slot is initialized
+            return Some(unsafe { self.buffer[index].assume_init_mut() });
+        }
+
+        None
+    }
+
+    /// Returns the front element, or `None` if the buffer is empty.
+    #[inline(always)]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the front element mutably, or `None` if the buffer is empty.
+    #[inline(always)]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns the back element, or `None` if the buffer is empty.
+    #[inline(always)]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns the back element mutably, or `None` if the buffer is empty.
+    #[inline(always)]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns the elements as two slices, which hold them front to back when joined.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front_len, back_len) = self.slice_lens();
+        let ptr = self.buffer.as_ptr() as *const T;
+
+        // SAFETY: The elements are initialized from `head` to the end of the buffer, then
+        // wrap around to its start
+        unsafe {
+            (
+                core::slice::from_raw_parts(ptr.add(self.head), front_len),
+                core::slice::from_raw_parts(ptr, back_len),
+            )
+        }
+    }
+
+    /// Returns the elements as two mutable slices, which hold them front to back when joined.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front_len, back_len) = self.slice_lens();
+        let ptr = self.buffer.as_mut_ptr() as *mut T;
+
+        // SAFETY: The elements are initialized from `head` to the end of the buffer, then
+        // wrap around to its start, so the two slices never overlap
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(ptr.add(self.head), front_len),
+                core::slice::from_raw_parts_mut(ptr, back_len),
+            )
+        }
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+
+        Iter::new(front, back)
+    }
+
+    /// Returns a mutable iterator over the elements, front to back.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+
+        IterMut::new(front, back)
+    }
+
+    /// Adds an element to the back of the buffer. If it is full, the front element is evicted
+    /// and returned.
+    #[inline]
+    pub fn push_back(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value);
+        }
+
+        if self.len == N {
+            let head = self.head;
+
+            self.head = self.physical(1);
+
+            // SAFETY: The buffer is full, so the front slot is initialized, and it becomes the
+            // back slot as the head moves past it
+            return Some(unsafe {
+                core::mem::replace(&mut self.buffer[head], MaybeUninit::new(value)).assume_init()
+            });
+        }
+
+        let index = self.physical(self.len);
+
+        self.buffer[index].write(value);
+        self.len += 1;
+
+        None
+    }
+
+    /// Adds an element to the front of the buffer. If it is full, the back element is evicted
+    /// and returned.
+    #[inline]
+    pub fn push_front(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value);
+        }
+
+        // Right before the front slot, which is the back slot if the buffer is full
+        self.head = self.physical(N - 1);
+
+        if self.len == N {
+            let head = self.head;
+
+            // SAFETY: The buffer is full, so the back slot is initialized, and it becomes the
+            // front slot as the head moves onto it
+            return Some(unsafe {
+                core::mem::replace(&mut self.buffer[head], MaybeUninit::new(value)).assume_init()
+            });
+        }
+
+        self.buffer[self.head].write(value);
+        self.len += 1;
+
+        None
+    }
+
+    /// Removes the front element and returns it, or `None` if the buffer is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let head = self.head;
+
+        self.head = self.physical(1);
+        self.len -= 1;
+
+        // SAFETY: The front element was initialized, and is no longer counted by the length
+        Some(unsafe { self.buffer[head].assume_init_read() })
+    }
+
+    /// Removes the back element and returns it, or `None` if the buffer is empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: The back element was initialized, and is no longer counted by the length
+        Some(unsafe { self.buffer[self.physical(self.len)].assume_init_read() })
+    }
+
+    /// Truncates the buffer to its first `new_len` elements, dropping the ones at the back.
+    pub fn truncate(&mut self, new_len: usize) {
+        while self.len > new_len {
+            self.pop_back();
+        }
+    }
+
+    /// Removes all elements from the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+
+        self.head = 0;
+    }
+
+    /// Returns the physical index of the element at logical `index`, which is at most `N`.
+    #[inline(always)]
+    fn physical(&self, index: usize) -> usize {
+        let index = self.head + index;
+
+        if index >= N {
+            index - N
+        } else {
+            index
+        }
+    }
+
+    /// Returns the lengths of the two slices returned by `as_slices`.
+    #[inline(always)]
+    fn slice_lens(&self) -> (usize, usize) {
+        let front_len = self.len.min(N - self.head);
+
+        (front_len, self.len - front_len)
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for RingBuffer<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut ring = Self::new();
+
+        ring.extend(self.iter().cloned());
+
+        ring
+    }
+}
+
+impl<T, const N: usize> core::ops::Index<usize> for RingBuffer<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+
+impl<T, const N: usize> core::ops::IndexMut<usize> for RingBuffer<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("Index out of bounds")
+    }
+}
+
+impl<T, const N: usize> IntoIterator for RingBuffer<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { ring: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a RingBuffer<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut RingBuffer<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for RingBuffer<T, N> {
+    /// Collects the last `N` elements of the iterator.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring = Self::new();
+
+        ring.extend(iter);
+
+        ring
+    }
+}
+
+impl<T, const N: usize> Extend<T> for RingBuffer<T, N> {
+    /// Pushes every element to the back, evicting the front ones once full.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<RingBuffer<U, M>>
+    for RingBuffer<T, N>
+{
+    #[inline]
+    fn eq(&self, other: &RingBuffer<U, M>) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for RingBuffer<T, N> {
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        let (front, back) = self.as_slices();
+
+        self.len == other.len() && front == &other[..front.len()] && back == &other[front.len()..]
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for RingBuffer<T, N> {
+    #[inline(always)]
+    fn eq(&self, other: &[U; M]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for RingBuffer<T, N> {}
+
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for RingBuffer<T, N> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
@@ -0,0 +1,420 @@
+use celui_sys::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+use crate::{
+    slotmap::{Key, KeyData},
+    vec::Vec,
+};
+
+// -------------------------------- Entry ---------------------------------- //
+
+/// A value of a `SecondaryMap`, with the version of the key it was inserted with.
+#[derive(Clone)]
+struct Entry<V> {
+    version: u32,
+    value: V,
+}
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the key-value pairs of a `SecondaryMap`, in slot order.
+pub struct Iter<'a, K, V> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Option<Entry<V>>>>,
+    remaining: usize, // Number of pairs not yet yielded
+
+    _marker: core::marker::PhantomData<K>,
+}
+
+impl<'a, K: Key, V> Iterator for Iter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.slots.by_ref() {
+            if let Some(entry) = slot {
+                self.remaining -= 1;
+
+                return Some((secondary_key(index, entry.version), &entry.value));
+            }
+        }
+
+        None
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Key, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+// ------------------------------- IterMut --------------------------------- //
+
+/// A mutable iterator over the key-value pairs of a `SecondaryMap`, in slot order.
+pub struct IterMut<'a, K, V> {
+    slots: core::iter::Enumerate<core::slice::IterMut<'a, Option<Entry<V>>>>,
+    remaining: usize, // Number of pairs not yet yielded
+
+    _marker: core::marker::PhantomData<K>,
+}
+
+impl<'a, K: Key, V> Iterator for IterMut<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.slots.by_ref() {
+            if let Some(entry) = slot {
+                self.remaining -= 1;
+
+                return Some((secondary_key(index, entry.version), &mut entry.value));
+            }
+        }
+
+        None
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Key, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+// ----------------------------- SecondaryMap ------------------------------ //
+
+/// A map from the keys of a `SlotMap` to extra values, e.g. the layout of each widget.
+///
+/// It is indexed by slot rather than hashed, so lookups are as cheap as in the `SlotMap`
+/// itself. A value is only returned for the exact key it was inserted with; inserting with a
+/// newer key for the same slot replaces it, and inserting with an older one is ignored.
+///
+/// # Example
+/// ```
+/// use celui_collections::{new_key_type, SecondaryMap, SlotMap};
+///
+/// new_key_type! { struct WidgetId; }
+///
+/// let mut widgets: SlotMap<WidgetId, &str> = SlotMap::new();
+/// let mut heights = SecondaryMap::new();
+///
+/// let button = widgets.insert("button");
+///
+/// heights.insert(button, 24.0);
+/// widgets.remove(button);
+///
+/// let label = widgets.insert("label"); // Reuses the button's slot
+///
+/// assert_eq!(heights.get(label), None);
+/// assert_eq!(heights.insert(label, 16.0), None); // Drops the button's height
+/// assert_eq!(heights.insert(button, 32.0), None); // Stale keys are ignored
+/// assert_eq!(heights.get(button), None);
+/// assert_eq!(heights[label], 16.0);
+/// ```
+pub struct SecondaryMap<K: Key, V, A: Allocator = Global> {
+    slots: Vec<Option<Entry<V>>, A>, // Values by slot index of their key
+    len: usize,                      // Number of occupied slots
+
+    _marker: core::marker::PhantomData<K>,
+}
+
+impl<K: Key, V> SecondaryMap<K, V> {
+    /// Creates a new, empty secondary map.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new secondary map with room for keys of a specified number of slots.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<K: Key, V, A: Allocator> SecondaryMap<K, V, A> {
+    /// Creates a new, empty secondary map which allocates from `allocator`.
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            slots: Vec::new_in(allocator),
+            len: 0,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Creates a new secondary map with room for keys of a specified number of slots,
+    /// allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self {
+            slots: Vec::with_capacity_in(capacity, allocator),
+            len: 0,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns a reference to the map's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.slots.allocator()
+    }
+
+    /// Returns the number of values in the map.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map holds no values.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the map holds a value for `key`.
+    #[inline(always)]
+    pub fn contains_key(&self, key: K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the value of `key`.
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&V> {
+        let data = key.data();
+
+        match self.slots.get(data.index())? {
+            Some(entry) if entry.version == data.version() => Some(&entry.value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value of `key`.
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        let data = key.data();
+
+        match self.slots.get_mut(data.index())? {
+            Some(entry) if entry.version == data.version() => Some(&mut entry.value),
+            _ => None,
+        }
+    }
+
+    /// Inserts a value for `key`, returning the value previously stored for the same key.
+    ///
+    /// A value stored for an older key of the same slot is dropped. If `key` is null or older
+    /// than the stored key, `value` is dropped instead and `None` is returned.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.try_insert(key, value)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Inserts a value for `key`, returning the value previously stored for the same key, or an
+    /// error if allocation fails.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, AllocError> {
+        let data = key.data();
+
+        if data.is_null() || !data.is_occupied() {
+            return Ok(None);
+        }
+
+        let index = data.index();
+
+        if index >= self.slots.len() {
+            self.slots.try_reserve(index + 1 - self.slots.len())?;
+
+            while self.slots.len() <= index {
+                self.slots.push(None);
+            }
+        }
+
+        let slot = &mut self.slots[index];
+
+        let Some(entry) = slot else {
+            *slot = Some(Entry {
+                version: data.version(),
+                value,
+            });
+
+            self.len += 1;
+
+            return Ok(None);
+        };
+
+        if entry.version == data.version() {
+            return Ok(Some(core::mem::replace(&mut entry.value, value)));
+        }
+
+        // Versions only grow, so the difference tells which key is newer even after wrapping
+        if (data.version().wrapping_sub(entry.version) as i32) > 0 {
+            *entry = Entry {
+                version: data.version(),
+                value,
+            };
+        }
+
+        Ok(None)
+    }
+
+    /// Removes the value of `key` and returns it.
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let data = key.data();
+        let slot = self.slots.get_mut(data.index())?;
+
+        match slot {
+            Some(entry) if entry.version == data.version() => {
+                self.len -= 1;
+
+                slot.take().map(|entry| entry.value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Keeps only the values for which `predicate` returns `true`.
+    pub fn retain<F: FnMut(K, &mut V) -> bool>(&mut self, mut predicate: F) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if let Some(entry) = slot {
+                if !predicate(secondary_key(index, entry.version), &mut entry.value) {
+                    *slot = None;
+
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
+    /// Removes every value.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the key-value pairs, in slot order.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.slots.as_slice().iter().enumerate(),
+            remaining: self.len,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns a mutable iterator over the key-value pairs, in slot order.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            slots: self.slots.as_slice_mut().iter_mut().enumerate(),
+            remaining: self.len,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the keys, in slot order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = K> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values, in slot order.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns a mutable iterator over the values, in slot order.
+    #[inline]
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.iter_mut().map(|(_, value)| value)
+    }
+}
+
+/// Rebuilds the key of an occupied slot of a `SecondaryMap`.
+#[inline(always)]
+fn secondary_key<K: Key>(index: usize, version: u32) -> K {
+    K::from_data(KeyData::new(index as u32, version))
+}
+
+impl<K: Key, V, A: Allocator + Default> Default for SecondaryMap<K, V, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<K: Key, V: Clone, A: Allocator + Clone> Clone for SecondaryMap<K, V, A> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            len: self.len,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<K: Key, V, A: Allocator> core::ops::Index<K> for SecondaryMap<K, V, A> {
+    type Output = V;
+
+    /// # Panics
+    /// Panics if the map holds no value for `key`.
+    #[inline]
+    fn index(&self, key: K) -> &Self::Output {
+        self.get(key).expect("Key not found")
+    }
+}
+
+impl<K: Key, V, A: Allocator> core::ops::IndexMut<K> for SecondaryMap<K, V, A> {
+    /// # Panics
+    /// Panics if the map holds no value for `key`.
+    #[inline]
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.get_mut(key).expect("Key not found")
+    }
+}
+
+impl<'a, K: Key, V, A: Allocator> IntoIterator for &'a SecondaryMap<K, V, A> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Key, V, A: Allocator> IntoIterator for &'a mut SecondaryMap<K, V, A> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Key, V, A: Allocator> Extend<(K, V)> for SecondaryMap<K, V, A> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Key, V> FromIterator<(K, V)> for SecondaryMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        map.extend(iter);
+
+        map
+    }
+}
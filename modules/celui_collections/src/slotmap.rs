@@ -0,0 +1,727 @@
+use celui_sys::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+use crate::vec::Vec;
+
+/// Marks the end of the free list, and the index of a null key.
+const NONE: u32 = u32::MAX;
+
+// ------------------------------- KeyData --------------------------------- //
+
+/// The index and version of a slot, which every `SlotMap` key wraps.
+///
+/// A slot's version is odd while it holds a value and is bumped on every insert and removal, so
+/// a key stops matching its slot as soon as the value is removed, even if the slot is reused.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyData {
+    index: u32,   // Index of the slot
+    version: u32, // Version of the slot when the key was created
+}
+
+impl KeyData {
+    /// A key that never refers to a value.
+    pub const NULL: Self = Self {
+        index: NONE,
+        version: 0,
+    };
+
+    /// Returns `true` if this is the null key.
+    #[inline(always)]
+    pub const fn is_null(self) -> bool {
+        self.index == NONE
+    }
+
+    /// Packs the key into a `u64`, e.g. to hand it across an FFI boundary.
+    #[inline(always)]
+    pub const fn to_bits(self) -> u64 {
+        ((self.version as u64) << 32) | self.index as u64
+    }
+
+    /// Unpacks a key from a `u64` created with `to_bits`.
+    ///
+    /// A value that was not created with `to_bits` gives a key that matches no value.
+    #[inline(always)]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self {
+            index: bits as u32,
+            version: (bits >> 32) as u32,
+        }
+    }
+
+    /// Creates the key of a slot.
+    #[inline(always)]
+    pub(crate) const fn new(index: u32, version: u32) -> Self {
+        Self { index, version }
+    }
+
+    /// Returns the index of the slot this key refers to.
+    #[inline(always)]
+    pub(crate) const fn index(self) -> usize {
+        self.index as usize
+    }
+
+    /// Returns the version of the slot when the key was created.
+    #[inline(always)]
+    pub(crate) const fn version(self) -> u32 {
+        self.version
+    }
+
+    /// Returns `true` if the key was handed out for an occupied slot.
+    #[inline(always)]
+    pub(crate) const fn is_occupied(self) -> bool {
+        self.version % 2 == 1
+    }
+}
+
+impl Default for KeyData {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::NULL
+    }
+}
+
+// --------------------------------- Key ----------------------------------- //
+
+/// A handle into a `SlotMap` or a `SecondaryMap`.
+///
+/// Define one type per kind of value with `new_key_type!`, so that a texture handle can't be
+/// used to look up a widget.
+pub trait Key: Copy + Eq + core::hash::Hash {
+    /// Wraps the index and version of a slot.
+    fn from_data(data: KeyData) -> Self;
+
+    /// Returns the index and version of the slot.
+    fn data(&self) -> KeyData;
+
+    /// Returns a key that never refers to a value.
+    #[inline(always)]
+    fn null() -> Self {
+        Self::from_data(KeyData::NULL)
+    }
+
+    /// Returns `true` if this is the null key.
+    #[inline(always)]
+    fn is_null(&self) -> bool {
+        self.data().is_null()
+    }
+}
+
+/// Declares new key types for `SlotMap` and `SecondaryMap`.
+///
+/// The types default to the null key, and can derive `Debug` when the `std` feature is enabled.
+///
+/// # Example
+/// ```
+/// use celui_collections::{new_key_type, slotmap::Key};
+///
+/// new_key_type! {
+///     pub struct WidgetId;
+///     pub struct TextureId;
+/// }
+///
+/// assert!(WidgetId::default().is_null());
+/// ```
+#[macro_export]
+macro_rules! new_key_type {
+    ($($(#[$meta:meta])* $vis:vis struct $name:ident;)*) => {$(
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        #[repr(transparent)]
+        $vis struct $name($crate::slotmap::KeyData);
+
+        impl $crate::slotmap::Key for $name {
+            #[inline(always)]
+            fn from_data(data: $crate::slotmap::KeyData) -> Self {
+                Self(data)
+            }
+
+            #[inline(always)]
+            fn data(&self) -> $crate::slotmap::KeyData {
+                self.0
+            }
+        }
+    )*};
+}
+
+new_key_type! {
+    /// A general purpose key type, for maps that don't need their own.
+    pub struct DefaultKey;
+}
+
+// --------------------------------- Slot ---------------------------------- //
+
+/// A slot of a `SlotMap`, which is either occupied or part of the free list.
+#[derive(Clone, Copy)]
+struct Slot {
+    version: u32, // Odd while occupied
+    index: u32,   // Index of the value if occupied, otherwise the next free slot
+}
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the key-value pairs of a `SlotMap`, in dense order.
+pub struct Iter<'a, K, V> {
+    keys: core::slice::Iter<'a, K>,
+    values: core::slice::Iter<'a, V>,
+}
+
+impl<'a, K: Copy, V> Iterator for Iter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next()?, self.values.next()?))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: Copy, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K: Copy, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+// ------------------------------- IterMut --------------------------------- //
+
+/// A mutable iterator over the key-value pairs of a `SlotMap`, in dense order.
+pub struct IterMut<'a, K, V> {
+    keys: core::slice::Iter<'a, K>,
+    values: core::slice::IterMut<'a, V>,
+}
+
+impl<'a, K: Copy, V> Iterator for IterMut<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next()?, self.values.next()?))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: Copy, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K: Copy, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `SlotMap` and returns its key-value pairs, in dense
+/// order.
+pub struct IntoIter<K, V, A: Allocator = Global> {
+    keys: crate::vec::IntoIter<K, A>,
+    values: crate::vec::IntoIter<V, A>,
+}
+
+impl<K, V, A: Allocator> Iterator for IntoIter<K, V, A> {
+    type Item = (K, V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.keys.next()?, self.values.next()?))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+// ---------------------- Keys / Values / ValuesMut ------------------------ //
+
+/// An iterator over the keys of a `SlotMap`, in dense order.
+pub struct Keys<'a, K> {
+    iter: core::slice::Iter<'a, K>,
+}
+
+impl<K: Copy> Iterator for Keys<'_, K> {
+    type Item = K;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().copied()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K: Copy> DoubleEndedIterator for Keys<'_, K> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().copied()
+    }
+}
+
+impl<K: Copy> ExactSizeIterator for Keys<'_, K> {}
+
+impl<K> Clone for Keys<'_, K> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+/// An iterator over the values of a `SlotMap`, in dense order.
+pub type Values<'a, V> = core::slice::Iter<'a, V>;
+
+/// A mutable iterator over the values of a `SlotMap`, in dense order.
+pub type ValuesMut<'a, V> = core::slice::IterMut<'a, V>;
+
+// ------------------------------- SlotMap --------------------------------- //
+
+/// A map that hands out its own keys, which stay valid until their value is removed.
+///
+/// Values are stored densely, so iterating is as fast as over a `Vec`; a removal moves the last
+/// value into the hole, which changes the iteration order. Keys are generational: once a value
+/// is removed, its key (and every copy of it) refers to nothing, even after the slot is reused
+/// by another insert.
+///
+/// # Example
+/// ```
+/// use celui_collections::{new_key_type, SlotMap};
+///
+/// new_key_type! { struct WidgetId; }
+///
+/// let mut widgets: SlotMap<WidgetId, &str> = SlotMap::new();
+///
+/// let button = widgets.insert("button");
+/// let label = widgets.insert("label");
+///
+/// assert_eq!(widgets.remove(button), Some("button"));
+///
+/// let slider = widgets.insert("slider"); // Reuses the button's slot
+///
+/// assert_eq!(widgets.get(button), None);
+/// assert_eq!(widgets[slider], "slider");
+/// assert_eq!(widgets[label], "label");
+/// ```
+pub struct SlotMap<K: Key, V, A: Allocator = Global> {
+    slots: Vec<Slot, A>, // Every slot ever used, occupied or free
+    keys: Vec<K, A>,     // Key of every value, in dense order
+    values: Vec<V, A>,   // Values, in dense order
+    free_head: u32,      // First slot of the free list
+}
+
+impl<K: Key, V> SlotMap<K, V> {
+    /// Creates a new, empty slot map.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            keys: Vec::new(),
+            values: Vec::new(),
+            free_head: NONE,
+        }
+    }
+
+    /// Creates a new slot map with a specified initial capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new slot map with a specified initial capacity, or an error if allocation
+    /// fails.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<K: Key, V, A: Allocator + Clone> SlotMap<K, V, A> {
+    /// Creates a new, empty slot map which allocates from `allocator`.
+    #[inline]
+    pub fn new_in(allocator: A) -> Self {
+        Self {
+            slots: Vec::new_in(allocator.clone()),
+            keys: Vec::new_in(allocator.clone()),
+            values: Vec::new_in(allocator),
+            free_head: NONE,
+        }
+    }
+
+    /// Creates a new slot map with a specified initial capacity, allocating from `allocator`.
+    #[inline]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self::try_with_capacity_in(capacity, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new slot map with a specified initial capacity, allocating from `allocator`,
+    /// or an error if allocation fails.
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        Ok(Self {
+            slots: Vec::try_with_capacity_in(capacity, allocator.clone())?,
+            keys: Vec::try_with_capacity_in(capacity, allocator.clone())?,
+            values: Vec::try_with_capacity_in(capacity, allocator)?,
+            free_head: NONE,
+        })
+    }
+}
+
+impl<K: Key, V, A: Allocator> SlotMap<K, V, A> {
+    /// Returns a reference to the map's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        self.values.allocator()
+    }
+
+    /// Returns the number of values in the map.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the number of values the map can hold without reallocating.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// Returns `true` if the map holds no values.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns `true` if `key` refers to a value in the map.
+    #[inline(always)]
+    pub fn contains_key(&self, key: K) -> bool {
+        self.dense_index(key).is_some()
+    }
+
+    /// Returns a reference to the value of `key`, or `None` if it was removed.
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.dense_index(key).map(|index| &self.values[index])
+    }
+
+    /// Returns a mutable reference to the value of `key`, or `None` if it was removed.
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        self.dense_index(key).map(|index| &mut self.values[index])
+    }
+
+    /// Returns mutable references to the values of `N` keys at once.
+    ///
+    /// Returns `None` if any key was removed, or if a key appears twice.
+    pub fn get_disjoint_mut<const N: usize>(&mut self, keys: [K; N]) -> Option<[&mut V; N]> {
+        let mut indices = [0; N];
+
+        for (i, &key) in keys.iter().enumerate() {
+            let index = self.dense_index(key)?;
+
+            if indices[..i].contains(&index) {
+                return None;
+            }
+
+            indices[i] = index;
+        }
+
+        let ptr = self.values.as_mut_ptr();
+
+        // SAFETY: Every index is within bounds and they are pairwise distinct, so the
+        // references don't alias
+        Some(core::array::from_fn(|i| unsafe {
+            &mut *ptr.add(indices[i])
+        }))
+    }
+
+    /// Inserts a value and returns its key.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> K {
+        self.try_insert(value)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Inserts a value and returns its key, or an error if allocation fails.
+    #[inline]
+    pub fn try_insert(&mut self, value: V) -> Result<K, AllocError> {
+        self.try_insert_with_key(|_| value)
+    }
+
+    /// Inserts the value returned by `f`, which is given the value's key, e.g. so that a
+    /// widget can store its own handle.
+    #[inline]
+    pub fn insert_with_key<F: FnOnce(K) -> V>(&mut self, f: F) -> K {
+        self.try_insert_with_key(f)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Inserts the value returned by `f`, which is given the value's key, or returns an error
+    /// if allocation fails.
+    ///
+    /// Fails with `AllocError::CapacityOverflow` once `u32::MAX` slots are in use.
+    pub fn try_insert_with_key<F: FnOnce(K) -> V>(&mut self, f: F) -> Result<K, AllocError> {
+        self.keys.try_reserve(1)?;
+        self.values.try_reserve(1)?;
+
+        let (index, version) = if self.free_head != NONE {
+            let slot = self.slots[self.free_head as usize];
+
+            (self.free_head, slot.version.wrapping_add(1))
+        } else {
+            if self.slots.len() >= NONE as usize {
+                return Err(AllocError::CapacityOverflow);
+            }
+
+            self.slots.try_reserve(1)?;
+
+            (self.slots.len() as u32, 1)
+        };
+
+        let key = K::from_data(KeyData::new(index, version));
+        let value = f(key);
+
+        let dense = Slot {
+            version,
+            index: self.values.len() as u32,
+        };
+
+        if index == self.free_head {
+            let slot = &mut self.slots[index as usize];
+
+            self.free_head = slot.index;
+
+            *slot = dense;
+        } else {
+            self.slots.push(dense);
+        }
+
+        self.keys.push(key);
+        self.values.push(value);
+
+        Ok(key)
+    }
+
+    /// Removes the value of `key` and returns it, or `None` if it was already removed.
+    ///
+    /// The last value in dense order is moved into its place.
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let index = self.dense_index(key)?;
+
+        Some(self.remove_dense(index))
+    }
+
+    /// Keeps only the values for which `predicate` returns `true`.
+    pub fn retain<F: FnMut(K, &mut V) -> bool>(&mut self, mut predicate: F) {
+        let mut index = 0;
+
+        while index < self.values.len() {
+            if predicate(self.keys[index], &mut self.values[index]) {
+                index += 1;
+            } else {
+                self.remove_dense(index);
+            }
+        }
+    }
+
+    /// Removes every value, invalidating all keys.
+    pub fn clear(&mut self) {
+        for key in self.keys.iter() {
+            let data = key.data();
+            let slot = &mut self.slots[data.index()];
+
+            slot.version = slot.version.wrapping_add(1);
+            slot.index = self.free_head;
+
+            self.free_head = data.index;
+        }
+
+        self.keys.clear();
+        self.values.clear();
+    }
+
+    /// Reserves capacity for at least `additional` more values.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Reserves capacity for at least `additional` more values, or returns an error if
+    /// allocation fails.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.keys.try_reserve(additional)?;
+        self.values.try_reserve(additional)?;
+
+        let free = self.slots.len() - self.values.len();
+
+        self.slots.try_reserve(additional.saturating_sub(free))
+    }
+
+    /// Returns an iterator over the key-value pairs, in dense order.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            keys: self.keys.as_slice().iter(),
+            values: self.values.as_slice().iter(),
+        }
+    }
+
+    /// Returns a mutable iterator over the key-value pairs, in dense order.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            keys: self.keys.as_slice().iter(),
+            values: self.values.as_slice_mut().iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the keys, in dense order.
+    #[inline(always)]
+    pub fn keys(&self) -> Keys<'_, K> {
+        Keys {
+            iter: self.keys.as_slice().iter(),
+        }
+    }
+
+    /// Returns an iterator over the values, in dense order.
+    #[inline(always)]
+    pub fn values(&self) -> Values<'_, V> {
+        self.values.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the values, in dense order.
+    #[inline(always)]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, V> {
+        self.values.as_slice_mut().iter_mut()
+    }
+
+    /// Returns the values as a slice, in dense order.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[V] {
+        self.values.as_slice()
+    }
+
+    /// Returns the position of the value of `key` in the dense arrays.
+    #[inline]
+    fn dense_index(&self, key: K) -> Option<usize> {
+        let data = key.data();
+        let slot = self.slots.get(data.index())?;
+
+        (data.is_occupied() && slot.version == data.version).then_some(slot.index as usize)
+    }
+
+    /// Removes the value at `index` in the dense arrays, and frees its slot.
+    fn remove_dense(&mut self, index: usize) -> V {
+        let data = self.keys.swap_remove(index).data();
+        let slot = &mut self.slots[data.index()];
+
+        slot.version = slot.version.wrapping_add(1);
+        slot.index = self.free_head;
+
+        self.free_head = data.index;
+
+        if let Some(moved) = self.keys.get(index) {
+            self.slots[moved.data().index()].index = index as u32;
+        }
+
+        self.values.swap_remove(index)
+    }
+}
+
+impl<K: Key, V, A: Allocator + Default> Default for SlotMap<K, V, A> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            slots: Vec::default(),
+            keys: Vec::default(),
+            values: Vec::default(),
+            free_head: NONE,
+        }
+    }
+}
+
+impl<K: Key, V: Clone, A: Allocator + Clone> Clone for SlotMap<K, V, A> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+            free_head: self.free_head,
+        }
+    }
+}
+
+impl<K: Key, V, A: Allocator> core::ops::Index<K> for SlotMap<K, V, A> {
+    type Output = V;
+
+    /// # Panics
+    /// Panics if the value of `key` was removed.
+    #[inline]
+    fn index(&self, key: K) -> &Self::Output {
+        self.get(key).expect("Key not found")
+    }
+}
+
+impl<K: Key, V, A: Allocator> core::ops::IndexMut<K> for SlotMap<K, V, A> {
+    /// # Panics
+    /// Panics if the value of `key` was removed.
+    #[inline]
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.get_mut(key).expect("Key not found")
+    }
+}
+
+impl<K: Key, V, A: Allocator> IntoIterator for SlotMap<K, V, A> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            keys: self.keys.into_iter(),
+            values: self.values.into_iter(),
+        }
+    }
+}
+
+impl<'a, K: Key, V, A: Allocator> IntoIterator for &'a SlotMap<K, V, A> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Key, V, A: Allocator> IntoIterator for &'a mut SlotMap<K, V, A> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
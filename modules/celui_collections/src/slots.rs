@@ -0,0 +1,322 @@
+use crate::vec::slice_range;
+
+// -------------------------------- Drain ---------------------------------- //
+
+/// A draining iterator that removes a range of elements from a `Vec`, an `ArrayVec` or a
+/// `SmallVec`.
+///
+/// Elements not yielded are dropped, and the tail is moved back when the iterator is dropped.
+pub struct Drain<'a, T> {
+    ptr: *mut T,
+    len: &'a mut usize, // Length of the vector, which ends where the drained range starts
+    current: usize,     // Next index to yield from the front
+    end: usize,         // One past the next index to yield from the back
+    tail_start: usize,  // Index of the first element after the drained range
+    tail_len: usize,    // Number of elements after the drained range
+
+    _marker: core::marker::PhantomData<&'a mut [T]>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+
+        // SAFETY: `self.current` is within the drained range, which is no longer owned by the
+        // vector
+        let item = unsafe { core::ptr::read(self.ptr.add(self.current)) };
+
+        self.current += 1;
+
+        Some(item)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: `self.end` is within the drained range, which is no longer owned by the
+        // vector
+        Some(unsafe { core::ptr::read(self.ptr.add(self.end)) })
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> core::iter::FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        let remaining = self.end - self.current;
+        let current = self.current;
+
+        self.current = self.end;
+
+        // SAFETY: Elements in `current..end` were not yielded and are still initialized. Then
+        // the tail is moved right after the elements kept in front of the drained range.
+        unsafe {
+            let slice = core::ptr::slice_from_raw_parts_mut(self.ptr.add(current), remaining);
+
+            core::ptr::drop_in_place(slice);
+
+            let start = *self.len;
+
+            if self.tail_start != start {
+                core::ptr::copy(
+                    self.ptr.add(self.tail_start),
+                    self.ptr.add(start),
+                    self.tail_len,
+                );
+            }
+
+            *self.len = start + self.tail_len;
+        }
+    }
+}
+
+// -------------------------------- Slots ---------------------------------- //
+
+/// The initialized front of a vector's buffer, through which `Vec`, `ArrayVec` and `SmallVec`
+/// share the algorithms that move elements around without growing.
+pub(crate) struct Slots<'a, T> {
+    ptr: *mut T,
+    len: &'a mut usize,
+}
+
+impl<'a, T> Slots<'a, T> {
+    /// Creates a view of the `*len` elements at `ptr`.
+    ///
+    /// # SAFETY
+    /// `ptr` must hold `*len` initialized elements, and stay valid while the view is used.
+    #[inline(always)]
+    pub(crate) unsafe fn new(ptr: *mut T, len: &'a mut usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Inserts an element at the given index, shifting subsequent elements to the right.
+    ///
+    /// # SAFETY
+    /// The buffer must have room for one more element.
+    pub(crate) unsafe fn insert(self, index: usize, element: T) {
+        let len = *self.len;
+
+        assert!(index <= len, "Index out of bounds");
+
+        // SAFETY: `index` is within bounds; we shift elements to prevent overwrites
+        unsafe {
+            core::ptr::copy(self.ptr.add(index), self.ptr.add(index + 1), len - index);
+            core::ptr::write(self.ptr.add(index), element);
+        };
+
+        *self.len = len + 1;
+    }
+
+    /// Removes and returns the element at the given index, shifting subsequent elements left.
+    pub(crate) fn remove(self, index: usize) -> T {
+        let len = *self.len;
+
+        assert!(index < len, "Index out of bounds");
+
+        // SAFETY: `index` is within bounds; we shift elements after reading the value
+        unsafe {
+            let value = core::ptr::read(self.ptr.add(index));
+
+            core::ptr::copy(
+                self.ptr.add(index + 1),
+                self.ptr.add(index),
+                len - index - 1,
+            );
+
+            *self.len = len - 1;
+
+            value
+        }
+    }
+
+    /// Removes and returns the element at the given index, replacing it with the last element.
+    pub(crate) fn swap_remove(self, index: usize) -> T {
+        let len = *self.len;
+
+        assert!(index < len, "Index out of bounds");
+
+        // SAFETY: `index` and `len - 1` are within bounds; the last slot is given up
+        unsafe {
+            let value = core::ptr::read(self.ptr.add(index));
+
+            core::ptr::copy(self.ptr.add(len - 1), self.ptr.add(index), 1);
+
+            *self.len = len - 1;
+
+            value
+        }
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`, preserving their order.
+    pub(crate) fn retain<F: FnMut(&T) -> bool>(self, mut predicate: F) {
+        let original_len = *self.len;
+
+        // Elements are leaked rather than dropped twice if `predicate` panics
+        *self.len = 0;
+
+        let mut guard = BackshiftOnDrop {
+            ptr: self.ptr,
+            len: self.len,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        };
+
+        while guard.processed < original_len {
+            // SAFETY: `processed` is within the original length and the element is initialized
+            unsafe {
+                let current = guard.ptr.add(guard.processed);
+
+                if !predicate(&*current) {
+                    guard.processed += 1;
+                    guard.deleted += 1;
+
+                    core::ptr::drop_in_place(current);
+
+                    continue;
+                }
+
+                if guard.deleted > 0 {
+                    let hole = guard.ptr.add(guard.processed - guard.deleted);
+
+                    core::ptr::copy_nonoverlapping(current, hole, 1);
+                }
+
+                guard.processed += 1;
+            }
+        }
+    }
+
+    /// Removes consecutive elements for which `same_bucket(current, previous)` returns `true`.
+    pub(crate) fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(self, mut same_bucket: F) {
+        let original_len = *self.len;
+
+        if original_len <= 1 {
+            return;
+        }
+
+        // Elements are leaked rather than dropped twice if `same_bucket` panics
+        *self.len = 0;
+
+        let mut guard = BackshiftOnDrop {
+            ptr: self.ptr,
+            len: self.len,
+            processed: 1,
+            deleted: 0,
+            original_len,
+        };
+
+        while guard.processed < original_len {
+            // SAFETY: `processed` is within the original length, and the previous kept element
+            // sits right before the hole left by the deleted ones
+            unsafe {
+                let current = guard.ptr.add(guard.processed);
+                let previous = guard.ptr.add(guard.processed - guard.deleted - 1);
+
+                if same_bucket(&mut *current, &mut *previous) {
+                    guard.processed += 1;
+                    guard.deleted += 1;
+
+                    core::ptr::drop_in_place(current);
+
+                    continue;
+                }
+
+                if guard.deleted > 0 {
+                    let hole = guard.ptr.add(guard.processed - guard.deleted);
+
+                    core::ptr::copy_nonoverlapping(current, hole, 1);
+                }
+
+                guard.processed += 1;
+            }
+        }
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    pub(crate) fn drain<R: core::ops::RangeBounds<usize>>(self, range: R) -> Drain<'a, T> {
+        let (start, end) = slice_range(range, *self.len);
+        let len = *self.len;
+
+        // Leak the drained range and the tail if the `Drain` is leaked
+        *self.len = start;
+
+        Drain {
+            ptr: self.ptr,
+            len: self.len,
+            current: start,
+            end,
+            tail_start: end,
+            tail_len: len - end,
+
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Truncates the elements to a new length, dropping the excess ones.
+    pub(crate) fn truncate(self, new_len: usize) {
+        let old_len = *self.len;
+
+        if new_len < old_len {
+            *self.len = new_len;
+
+            // SAFETY: `new_len` is guaranteed to be less than the old length
+            unsafe {
+                let slice =
+                    core::ptr::slice_from_raw_parts_mut(self.ptr.add(new_len), old_len - new_len);
+
+                core::ptr::drop_in_place(slice);
+            }
+        }
+    }
+}
+
+/// Moves the unprocessed tail back over deleted elements and fixes the length, even on panic.
+///
+/// Used by `retain` and `dedup_by`, which run user code while the buffer has holes.
+struct BackshiftOnDrop<'a, T> {
+    ptr: *mut T,
+    len: &'a mut usize,
+    processed: usize,
+    deleted: usize,
+    original_len: usize,
+}
+
+impl<T> Drop for BackshiftOnDrop<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: Elements in `processed..original_len` are initialized, and the `deleted`
+        // slots before them are holes
+        unsafe {
+            if self.deleted > 0 && self.processed < self.original_len {
+                core::ptr::copy(
+                    self.ptr.add(self.processed),
+                    self.ptr.add(self.processed - self.deleted),
+                    self.original_len - self.processed,
+                );
+            }
+
+            *self.len = self.original_len - self.deleted;
+        }
+    }
+}
@@ -0,0 +1,850 @@
+use core::mem::MaybeUninit;
+
+use celui_sys::alloc::{
+    dealloc_many_in, handle_alloc_error, try_alloc_many_in, try_realloc_many_in, AllocError,
+    Allocator, Global,
+};
+
+use crate::slots::Slots;
+use crate::vec::{extend_with, merge_sort, MIN_NON_ZERO_CAPACITY};
+
+pub use crate::slots::Drain;
+
+// --------------------------------- Array --------------------------------- //
+
+/// An array type whose elements a `SmallVec` can store inline, implemented for `[T; N]`.
+///
+/// # SAFETY
+/// The type must be laid out as `CAPACITY` consecutive `Item`s.
+pub unsafe trait Array {
+    /// The type of the elements.
+    type Item;
+
+    /// The number of elements the array holds.
+    const CAPACITY: usize;
+}
+
+// SAFETY: Arrays are laid out as `N` consecutive elements
+unsafe impl<T, const N: usize> Array for [T; N] {
+    type Item = T;
+
+    const CAPACITY: usize = N;
+}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `SmallVec` and returns its elements.
+pub struct IntoIter<A: Array, Alloc: Allocator = Global> {
+    vec: SmallVec<A, Alloc>, // Its length stays 0, so it only frees the buffer when dropped
+    current: usize,          // Next index to yield from the front
+    end: usize,              // One past the next index to yield from the back
+}
+
+impl<A: Array, Alloc: Allocator> Iterator for IntoIter<A, Alloc> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+
+        // SAFETY: Elements in `current..end` are initialized and not yielded yet
+        let item = unsafe { core::ptr::read(self.vec.as_ptr().add(self.current)) };
+
+        self.current += 1;
+
+        Some(item)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<A: Array, Alloc: Allocator> DoubleEndedIterator for IntoIter<A, Alloc> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: Elements in `current..end` are initialized and not yielded yet
+        Some(unsafe { core::ptr::read(self.vec.as_ptr().add(self.end)) })
+    }
+}
+
+impl<A: Array, Alloc: Allocator> ExactSizeIterator for IntoIter<A, Alloc> {}
+
+impl<A: Array, Alloc: Allocator> core::iter::FusedIterator for IntoIter<A, Alloc> {}
+
+impl<A: Array, Alloc: Allocator> Drop for IntoIter<A, Alloc> {
+    fn drop(&mut self) {
+        // SAFETY: Elements in `current..end` were not yielded and are still initialized
+        unsafe {
+            let slice = core::ptr::slice_from_raw_parts_mut(
+                self.vec.as_mut_ptr().add(self.current),
+                self.end - self.current,
+            );
+
+            core::ptr::drop_in_place(slice);
+        }
+    }
+}
+
+// ------------------------------- SmallVec -------------------------------- //
+
+/// Where the elements of a `SmallVec` live.
+enum Data<A: Array> {
+    Inline(MaybeUninit<A>),
+    Heap { ptr: *mut A::Item, capacity: usize },
+}
+
+/// A vector that stores up to `A::CAPACITY` elements inline, and spills to the heap past that.
+///
+/// Has the same API as `Vec`. Most widgets have a handful of children, which a
+/// `SmallVec<[T; 4]>` holds without ever allocating.
+///
+/// # Example
+/// ```
+/// use celui_collections::SmallVec;
+///
+/// let mut children = SmallVec::<[u32; 4]>::new();
+///
+/// children.extend([1, 2, 3, 4]);
+/// assert!(children.is_inline());
+///
+/// children.push(5);
+/// assert!(!children.is_inline() && children == [1, 2, 3, 4, 5]);
+///
+/// children.truncate(2);
+/// children.shrink_to_fit();
+/// assert!(children.is_inline() && children == [1, 2]);
+/// ```
+pub struct SmallVec<A: Array, Alloc: Allocator = Global> {
+    data: Data<A>,
+    len: usize,
+    allocator: Alloc,
+}
+
+impl<A: Array> SmallVec<A> {
+    /// Creates a new, empty vector with its elements inline.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new vector with room for at least `capacity` elements.
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new vector with room for at least `capacity` elements, or an error if
+    /// allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<A: Array, Alloc: Allocator> SmallVec<A, Alloc> {
+    /// Creates a new, empty vector with its elements inline, that will spill to `allocator`.
+    #[inline(always)]
+    pub const fn new_in(allocator: Alloc) -> Self {
+        Self {
+            data: Data::Inline(MaybeUninit::uninit()),
+            len: 0,
+            allocator,
+        }
+    }
+
+    /// Creates a new vector with room for at least `capacity` elements, allocated from
+    /// `allocator` if they don't fit inline.
+    #[inline(always)]
+    pub fn with_capacity_in(capacity: usize, allocator: Alloc) -> Self {
+        Self::try_with_capacity_in(capacity, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new vector with room for at least `capacity` elements, allocated from
+    /// `allocator` if they don't fit inline, or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, allocator: Alloc) -> Result<Self, AllocError> {
+        let mut vec = Self::new_in(allocator);
+
+        vec.try_reserve(capacity)?;
+
+        Ok(vec)
+    }
+
+    /// Returns a reference to the vector's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &Alloc {
+        &self.allocator
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity of the vector, which is `A::CAPACITY` while it is inline.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        match self.data {
+            Data::Inline(_) => A::CAPACITY,
+            Data::Heap { capacity, .. } => capacity,
+        }
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the elements are stored inline, rather than on the heap.
+    #[inline(always)]
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.data, Data::Inline(_))
+    }
+
+    /// Returns a reference to the element at the given index, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&A::Item> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at the given index, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut A::Item> {
+        self.as_slice_mut().get_mut(index)
+    }
+
+    /// Returns a slice containing all elements of the vector.
+    #[inline]
+    pub fn as_slice(&self) -> &[A::Item] {
+        // SAFETY: The first `self.len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    /// Returns a mutable slice containing all elements of the vector.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [A::Item] {
+        // SAFETY: The first `self.len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Returns a raw pointer to the vector's buffer.
+    ///
+    /// The buffer moves with the vector while it is inline.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const A::Item {
+        match &self.data {
+            Data::Inline(buffer) => buffer.as_ptr() as *const A::Item,
+            Data::Heap { ptr, .. } => *ptr,
+        }
+    }
+
+    /// Returns a raw mutable pointer to the vector's buffer.
+    ///
+    /// The buffer moves with the vector while it is inline.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut A::Item {
+        match &mut self.data {
+            Data::Inline(buffer) => buffer.as_mut_ptr() as *mut A::Item,
+            Data::Heap { ptr, .. } => *ptr,
+        }
+    }
+
+    /// Returns an iterator over the slice.
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, A::Item> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the slice.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, A::Item> {
+        self.as_slice_mut().iter_mut()
+    }
+
+    /// Adds an element to the end of the vector, spilling to the heap if needed.
+    #[inline]
+    pub fn push(&mut self, value: A::Item) {
+        if let Err(error) = self.try_push(value) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Adds an element to the end of the vector, or returns an error if growing fails.
+    ///
+    /// On failure, `value` is dropped and the vector is left unchanged.
+    #[inline]
+    pub fn try_push(&mut self, value: A::Item) -> Result<(), AllocError> {
+        if self.len == self.capacity() {
+            self.try_grow(0)?;
+        }
+
+        // SAFETY: `self.len` is within the capacity
+        unsafe { core::ptr::write(self.as_mut_ptr().add(self.len), value) };
+
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the last element from the vector and returns it, or `None` if empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<A::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: The element was initialized, and is no longer counted by the length
+        Some(unsafe { core::ptr::read(self.as_ptr().add(self.len)) })
+    }
+
+    /// Inserts an element at the given index, shifting subsequent elements to the right.
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: A::Item) {
+        if let Err(error) = self.try_insert(index, element) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Inserts an element at the given index, or returns an error if growing fails.
+    ///
+    /// On failure, `element` is dropped and the vector is left unchanged.
+    pub fn try_insert(&mut self, index: usize, element: A::Item) -> Result<(), AllocError> {
+        assert!(index <= self.len, "Index out of bounds");
+
+        if self.len == self.capacity() {
+            self.try_grow(0)?;
+        }
+
+        // SAFETY: There is room for one more element
+        unsafe { self.slots().insert(index, element) };
+
+        Ok(())
+    }
+
+    /// Removes and returns the element at the given index, shifting subsequent elements left.
+    #[inline(always)]
+    pub fn remove(&mut self, index: usize) -> A::Item {
+        self.slots().remove(index)
+    }
+
+    /// Removes and returns the element at the given index, replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    #[inline(always)]
+    pub fn swap_remove(&mut self, index: usize) -> A::Item {
+        self.slots().swap_remove(index)
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`, preserving their order.
+    #[inline(always)]
+    pub fn retain<F: FnMut(&A::Item) -> bool>(&mut self, predicate: F) {
+        self.slots().retain(predicate);
+    }
+
+    /// Removes consecutive repeated elements.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        A::Item: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut A::Item) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same_bucket(current, previous)` returns `true`.
+    #[inline(always)]
+    pub fn dedup_by<F: FnMut(&mut A::Item, &mut A::Item) -> bool>(&mut self, same_bucket: F) {
+        self.slots().dedup_by(same_bucket);
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// The elements are removed even if the iterator is not fully consumed.
+    #[inline(always)]
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, A::Item> {
+        self.slots().drain(range)
+    }
+
+    /// Splits the vector in two at `at`, returning the elements from `at` onwards.
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        Alloc: Clone,
+    {
+        assert!(at <= self.len, "Index out of bounds");
+
+        let count = self.len - at;
+        let mut other = Self::with_capacity_in(count, self.allocator.clone());
+
+        // SAFETY: `at..self.len` is initialized and `other` has room for `count` elements
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), count);
+
+            self.len = at;
+            other.len = count;
+        }
+
+        other
+    }
+
+    /// Moves all elements of `other` to the end of the vector, leaving `other` empty.
+    pub fn append<B: Array<Item = A::Item>, OtherAlloc: Allocator>(
+        &mut self,
+        other: &mut SmallVec<B, OtherAlloc>,
+    ) {
+        let count = other.len;
+
+        self.reserve(count);
+
+        // SAFETY: We reserved `count` elements, and `other` gives up ownership of its elements
+        unsafe {
+            core::ptr::copy_nonoverlapping(other.as_ptr(), self.as_mut_ptr().add(self.len), count);
+
+            other.len = 0;
+            self.len += count;
+        }
+    }
+
+    /// Resizes the vector to `new_len`, filling new slots with clones of `value`.
+    pub fn resize(&mut self, new_len: usize, value: A::Item)
+    where
+        A::Item: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+
+        while self.len + 1 < new_len {
+            self.push(value.clone());
+        }
+
+        self.push(value);
+    }
+
+    /// Appends clones of all elements of `slice` to the vector.
+    pub fn extend_from_slice(&mut self, slice: &[A::Item])
+    where
+        A::Item: Clone,
+    {
+        self.reserve(slice.len());
+
+        for item in slice {
+            self.push(item.clone());
+        }
+    }
+
+    /// Sorts the vector, preserving the order of equal elements.
+    ///
+    /// Uses a merge sort with a temporary buffer of `len` elements from the vector's
+    /// allocator. `sort_unstable` is available through the slice and doesn't allocate.
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        A::Item: Ord,
+    {
+        self.merge_sort(&mut |a: &A::Item, b: &A::Item| a.lt(b));
+    }
+
+    /// Sorts the vector with a comparator, preserving the order of equal elements.
+    #[inline]
+    pub fn sort_by<F: FnMut(&A::Item, &A::Item) -> core::cmp::Ordering>(&mut self, mut compare: F) {
+        self.merge_sort(&mut |a: &A::Item, b: &A::Item| compare(a, b) == core::cmp::Ordering::Less);
+    }
+
+    /// Sorts the vector by a key, preserving the order of equal elements.
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(&A::Item) -> K>(&mut self, mut key: F) {
+        self.merge_sort(&mut |a: &A::Item, b: &A::Item| key(a).lt(&key(b)));
+    }
+
+    /// Sorts the vector with `is_less`, using scratch space from the vector's allocator.
+    #[inline(always)]
+    fn merge_sort<F: FnMut(&A::Item, &A::Item) -> bool>(&mut self, is_less: &mut F) {
+        // SAFETY: The first `self.len` elements are initialized
+        let slice = unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) };
+
+        merge_sort(slice, &self.allocator, is_less);
+    }
+
+    /// Fills the vector with elements by cloning `value`.
+    pub fn fill(&mut self, value: A::Item)
+    where
+        A::Item: Clone,
+    {
+        self.as_slice_mut().fill(value);
+    }
+
+    /// Removes all elements from the vector, keeping its capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Sets the length of the vector.
+    ///
+    /// # SAFETY
+    /// The caller must ensure:
+    /// - `new_len` does not exceed the vector's capacity
+    /// - All elements at `old_len..new_len` are initialized
+    /// - The elements at `new_len..old_len` will be leaked if the length is being truncated,
+    ///   resources must be properly cleaned up if necessary
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+
+        self.len = new_len;
+    }
+
+    /// Extends the vector with the contents of an iterator.
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) {
+        extend_with(self, iter, Self::reserve, Self::push);
+    }
+
+    /// Truncates the vector to a new length, removing excess elements.
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.slots().truncate(new_len);
+    }
+
+    /// Ensures that the vector has at least `additional` capacity.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Ensures that the vector has at least `additional` capacity, or returns an error if
+    /// growing fails, leaving the vector unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        let new_capacity = self
+            .len
+            .checked_add(additional)
+            .ok_or(AllocError::CapacityOverflow)?;
+
+        if new_capacity > self.capacity() {
+            self.try_grow(new_capacity)?;
+        }
+
+        Ok(())
+    }
+
+    /// Shrinks the capacity of the vector to its length, moving the elements back inline if
+    /// they fit.
+    pub fn shrink_to_fit(&mut self) {
+        let Data::Heap { ptr, capacity } = self.data else {
+            return;
+        };
+
+        if self.len <= A::CAPACITY {
+            let mut buffer = MaybeUninit::<A>::uninit();
+
+            // SAFETY: The elements fit inline, and the heap buffer gives up ownership of them
+            // before being freed
+            unsafe {
+                core::ptr::copy_nonoverlapping(ptr, buffer.as_mut_ptr().cast(), self.len);
+                dealloc_many_in(&self.allocator, ptr, capacity);
+            }
+
+            self.data = Data::Inline(buffer);
+        } else if capacity > self.len {
+            // SAFETY: `ptr` holds `capacity` elements, and the first `self.len` are kept
+            let ptr = unsafe {
+                try_realloc_many_in(&self.allocator, ptr, capacity, self.len)
+                    .unwrap_or_else(|error| handle_alloc_error(error))
+            };
+
+            self.data = Data::Heap {
+                ptr,
+                capacity: self.len,
+            };
+        }
+    }
+
+    /// Increases the vector's capacity to accommodate at least `minimum_capacity` elements,
+    /// spilling to the heap if it is inline, and leaving the vector unchanged on failure.
+    fn try_grow(&mut self, minimum_capacity: usize) -> Result<(), AllocError> {
+        // An overflow here is reported by the allocation below
+        let new_capacity = self
+            .capacity()
+            .saturating_mul(2)
+            .max(MIN_NON_ZERO_CAPACITY)
+            .max(minimum_capacity);
+
+        match &mut self.data {
+            Data::Inline(buffer) => {
+                // SAFETY: `try_alloc_many_in` returns a valid pointer or an error, with room
+                // for the `self.len` inline elements, which are moved over
+                let ptr = unsafe {
+                    let ptr = try_alloc_many_in(&self.allocator, new_capacity)?;
+
+                    core::ptr::copy_nonoverlapping(buffer.as_ptr().cast(), ptr, self.len);
+
+                    ptr
+                };
+
+                self.data = Data::Heap {
+                    ptr,
+                    capacity: new_capacity,
+                };
+            }
+            Data::Heap { ptr, capacity } => {
+                // SAFETY: `ptr` holds `capacity` elements, and reallocating keeps the first
+                // `self.len` of them, as `new_capacity` is larger
+                *ptr =
+                    unsafe { try_realloc_many_in(&self.allocator, *ptr, *capacity, new_capacity)? };
+                *capacity = new_capacity;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a view of the vector's elements for the algorithms shared with `Vec`.
+    #[inline(always)]
+    fn slots(&mut self) -> Slots<'_, A::Item> {
+        let ptr = self.as_mut_ptr();
+
+        // SAFETY: The buffer holds `self.len` initialized elements
+        unsafe { Slots::new(ptr, &mut self.len) }
+    }
+}
+
+impl<A: Array, Alloc: Allocator> Drop for SmallVec<A, Alloc> {
+    fn drop(&mut self) {
+        self.clear();
+
+        if let Data::Heap { ptr, capacity } = self.data {
+            // SAFETY: `ptr` was allocated from `self.allocator` with room for `capacity`
+            // elements
+            unsafe { dealloc_many_in(&self.allocator, ptr, capacity) };
+        }
+    }
+}
+
+impl<A: Array, Alloc: Allocator + Default> Default for SmallVec<A, Alloc> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_in(Alloc::default())
+    }
+}
+
+impl<A: Array, Alloc: Allocator + Clone> Clone for SmallVec<A, Alloc>
+where
+    A::Item: Clone,
+{
+    /// Clones every element. If a clone panics, the elements cloned so far are dropped.
+    fn clone(&self) -> Self {
+        let mut new_vec = Self::with_capacity_in(self.len, self.allocator.clone());
+
+        new_vec.extend_from_slice(self.as_slice());
+
+        new_vec
+    }
+
+    /// Clones `source` into `self`, reusing its buffer and existing elements.
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+
+        let (prefix, rest) = source.split_at(self.len);
+
+        self.as_slice_mut().clone_from_slice(prefix);
+        self.extend_from_slice(rest);
+    }
+}
+
+impl<A: Array, Alloc: Allocator> IntoIterator for SmallVec<A, Alloc> {
+    type Item = A::Item;
+    type IntoIter = IntoIter<A, Alloc>;
+
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.len;
+
+        // The iterator owns the elements from now on
+        self.len = 0;
+
+        IntoIter {
+            vec: self,
+            current: 0,
+            end,
+        }
+    }
+}
+
+impl<'a, A: Array, Alloc: Allocator> IntoIterator for &'a SmallVec<A, Alloc> {
+    type Item = &'a A::Item;
+    type IntoIter = core::slice::Iter<'a, A::Item>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, A: Array, Alloc: Allocator> IntoIterator for &'a mut SmallVec<A, Alloc> {
+    type Item = &'a mut A::Item;
+    type IntoIter = core::slice::IterMut<'a, A::Item>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<A: Array, Alloc: Allocator, I: core::slice::SliceIndex<[A::Item]>> core::ops::Index<I>
+    for SmallVec<A, Alloc>
+{
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<A: Array, Alloc: Allocator, I: core::slice::SliceIndex<[A::Item]>> core::ops::IndexMut<I>
+    for SmallVec<A, Alloc>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_slice_mut()[index]
+    }
+}
+
+impl<A: Array, Alloc: Allocator> core::ops::Deref for SmallVec<A, Alloc> {
+    type Target = [A::Item];
+
+    #[inline(always)]
+    fn deref(&self) -> &[A::Item] {
+        self.as_slice()
+    }
+}
+
+impl<A: Array, Alloc: Allocator> core::ops::DerefMut for SmallVec<A, Alloc> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [A::Item] {
+        self.as_slice_mut()
+    }
+}
+
+impl<A: Array> FromIterator<A::Item> for SmallVec<A> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = A::Item>>(iter: I) -> Self {
+        let mut vec = Self::new();
+
+        vec.extend(iter);
+
+        vec
+    }
+}
+
+impl<A: Array, Alloc: Allocator> Extend<A::Item> for SmallVec<A, Alloc> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) {
+        SmallVec::extend(self, iter);
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SmallVec<[T; N]> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Self {
+            data: Data::Inline(MaybeUninit::new(array)),
+            len: N,
+            allocator: Global,
+        }
+    }
+}
+
+impl<A: Array> From<&[A::Item]> for SmallVec<A>
+where
+    A::Item: Clone,
+{
+    #[inline]
+    fn from(slice: &[A::Item]) -> Self {
+        let mut vec = Self::new();
+
+        vec.extend_from_slice(slice);
+
+        vec
+    }
+}
+
+impl<A: Array, B: Array, Alloc: Allocator, OtherAlloc: Allocator> PartialEq<SmallVec<B, OtherAlloc>>
+    for SmallVec<A, Alloc>
+where
+    A::Item: PartialEq<B::Item>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &SmallVec<B, OtherAlloc>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: Array, Alloc: Allocator, U> PartialEq<[U]> for SmallVec<A, Alloc>
+where
+    A::Item: PartialEq<U>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<A: Array, Alloc: Allocator, U> PartialEq<&[U]> for SmallVec<A, Alloc>
+where
+    A::Item: PartialEq<U>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<A: Array, Alloc: Allocator, U, const N: usize> PartialEq<[U; N]> for SmallVec<A, Alloc>
+where
+    A::Item: PartialEq<U>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: Array, Alloc: Allocator> Eq for SmallVec<A, Alloc> where A::Item: Eq {}
+
+impl<A: Array, Alloc: Allocator> core::hash::Hash for SmallVec<A, Alloc>
+where
+    A::Item: core::hash::Hash,
+{
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
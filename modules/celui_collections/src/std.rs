@@ -0,0 +1,224 @@
+// -------------------------------- std.rs --------------------------------- //
+
+//! Support functions and utility methods for a standard (`std`) environment.
+//!
+//! This file is included in the build *only* when the `std` feature is enabled.
+//! If the `std` feature is *not* enabled, this file will be excluded from compilation.
+//!
+//! To enable standard library support, add the `std` feature to your `Cargo.toml`:
+//!
+//! ```toml
+//! [features]
+//! std = []
+//! ```
+
+// --------------------------------- Vec ----------------------------------- //
+
+impl<T: std::fmt::Debug, A: celui_sys::alloc::Allocator> std::fmt::Debug for crate::Vec<T, A> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- ArrayVec -------------------------------- //
+
+impl<T: std::fmt::Debug, const N: usize> std::fmt::Debug for crate::ArrayVec<T, N> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// ----------------------------- CapacityError ----------------------------- //
+
+impl<T> std::fmt::Debug for crate::arrayvec::CapacityError<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+// ------------------------------- SmallVec -------------------------------- //
+
+impl<A, Alloc> std::fmt::Debug for crate::SmallVec<A, Alloc>
+where
+    A: crate::smallvec::Array,
+    A::Item: std::fmt::Debug,
+    Alloc: celui_sys::alloc::Allocator,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- VecDeque -------------------------------- //
+
+impl<T: std::fmt::Debug, A: celui_sys::alloc::Allocator> std::fmt::Debug for crate::VecDeque<T, A> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------ BinaryHeap ------------------------------- //
+
+impl<T: std::fmt::Debug, A: celui_sys::alloc::Allocator> std::fmt::Debug
+    for crate::BinaryHeap<T, A>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------ RingBuffer ------------------------------- //
+
+impl<T: std::fmt::Debug, const N: usize> std::fmt::Debug for crate::RingBuffer<T, N> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// -------------------------------- String --------------------------------- //
+
+impl<A: celui_sys::alloc::Allocator> std::fmt::Debug for crate::String<A> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+// ------------------------------ ArrayString ------------------------------ //
+
+impl<const N: usize> std::fmt::Debug for crate::ArrayString<N> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+// ------------------------------- HashMap --------------------------------- //
+
+impl<K, V, S, A> std::fmt::Debug for crate::HashMap<K, V, S, A>
+where
+    K: std::hash::Hash + Eq + std::fmt::Debug,
+    V: std::fmt::Debug,
+    S: std::hash::BuildHasher,
+    A: celui_sys::alloc::Allocator + Clone,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- HashSet --------------------------------- //
+
+impl<T, S, A> std::fmt::Debug for crate::HashSet<T, S, A>
+where
+    T: std::hash::Hash + Eq + std::fmt::Debug,
+    S: std::hash::BuildHasher,
+    A: celui_sys::alloc::Allocator + Clone,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- IndexMap -------------------------------- //
+
+impl<K, V, S, A> std::fmt::Debug for crate::IndexMap<K, V, S, A>
+where
+    K: std::hash::Hash + Eq + std::fmt::Debug,
+    V: std::fmt::Debug,
+    S: std::hash::BuildHasher,
+    A: celui_sys::alloc::Allocator + Clone,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- IndexSet -------------------------------- //
+
+impl<T, S, A> std::fmt::Debug for crate::IndexSet<T, S, A>
+where
+    T: std::hash::Hash + Eq + std::fmt::Debug,
+    S: std::hash::BuildHasher,
+    A: celui_sys::alloc::Allocator + Clone,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- BTreeMap -------------------------------- //
+
+impl<K, V, A> std::fmt::Debug for crate::BTreeMap<K, V, A>
+where
+    K: std::fmt::Debug,
+    V: std::fmt::Debug,
+    A: celui_sys::alloc::Allocator,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- BTreeSet -------------------------------- //
+
+impl<T: std::fmt::Debug, A: celui_sys::alloc::Allocator> std::fmt::Debug for crate::BTreeSet<T, A> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// ------------------------------- SlotMap --------------------------------- //
+
+impl std::fmt::Debug for crate::slotmap::KeyData {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}v{}", self.index(), self.version())
+    }
+}
+
+impl std::fmt::Debug for crate::DefaultKey {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&crate::Key::data(self), f)
+    }
+}
+
+impl<K, V, A> std::fmt::Debug for crate::SlotMap<K, V, A>
+where
+    K: crate::Key + std::fmt::Debug,
+    V: std::fmt::Debug,
+    A: celui_sys::alloc::Allocator,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+// ----------------------------- SecondaryMap ------------------------------ //
+
+impl<K, V, A> std::fmt::Debug for crate::SecondaryMap<K, V, A>
+where
+    K: crate::Key + std::fmt::Debug,
+    V: std::fmt::Debug,
+    A: celui_sys::alloc::Allocator,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
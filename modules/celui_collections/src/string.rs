@@ -0,0 +1,930 @@
+use celui_sys::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+use crate::vec::Vec;
+
+/// Bytes held without allocating. With the length and the variant tag, the inline form
+/// takes 32 bytes, which sets the size of a `String` (a `Vec` alone is 24 bytes).
+const INLINE_CAPACITY: usize = 30;
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+// ------------------------------- Graphemes ------------------------------- //
+
+/// An iterator over the grapheme clusters of a string, as string slices.
+///
+/// Clusters follow the parts of the Unicode extended grapheme cluster rules that labels run
+/// into: `"\r\n"`, combining marks, variation selectors, emoji modifiers, zero width joiner
+/// sequences of emoji and flags stay together, while controls are clusters on their own.
+/// Scripts that need the full Unicode tables (Hangul jamo, Indic conjuncts) may be split into
+/// several clusters, but never inside a `char`.
+#[derive(Clone)]
+pub struct Graphemes<'a> {
+    rest: &'a str, // Text not yielded yet
+}
+
+impl<'a> Graphemes<'a> {
+    /// Creates an iterator over the grapheme clusters of `text`.
+    #[inline(always)]
+    pub const fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (cluster, rest) = self.rest.split_at(grapheme_len(self.rest));
+
+        self.rest = rest;
+
+        Some(cluster)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        ((!self.rest.is_empty()) as usize, Some(self.rest.len()))
+    }
+}
+
+/// Returns the length in bytes of the first grapheme cluster of `text`, which isn't empty.
+fn grapheme_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    let Some((_, mut previous)) = chars.next() else {
+        return 0;
+    };
+
+    let mut regional_indicators = is_regional_indicator(previous) as usize;
+    // Whether the cluster so far is an emoji followed by extenders, which a zero width
+    // joiner can link to the next emoji
+    let mut emoji = is_extended_pictographic(previous);
+
+    for (i, c) in chars {
+        let joins = match previous {
+            // GB3, GB4: Break after controls, except inside "\r\n"
+            '\r' => c == '\n',
+            _ if is_control(previous) => false,
+            // GB5: Break before controls
+            _ if is_control(c) => false,
+            // GB9: Don't break before extenders
+            _ if is_extend(c) => true,
+            // GB11: Only emoji are joined into zero width joiner sequences
+            ZERO_WIDTH_JOINER => emoji && is_extended_pictographic(c),
+            // GB12, GB13: Flags are pairs of regional indicators
+            _ if is_regional_indicator(c) => {
+                is_regional_indicator(previous) && regional_indicators % 2 == 1
+            }
+            _ => false,
+        };
+
+        if !joins {
+            return i;
+        }
+
+        regional_indicators += is_regional_indicator(c) as usize;
+        emoji =
+            is_extended_pictographic(c) || (emoji && is_extend(c) && previous != ZERO_WIDTH_JOINER);
+        previous = c;
+    }
+
+    text.len()
+}
+
+/// Returns `true` if `c` is a control, which is always a cluster on its own.
+#[inline(always)]
+fn is_control(c: char) -> bool {
+    c.is_control() || matches!(c, '\u{2028}' | '\u{2029}') // Line and paragraph separators
+}
+
+/// Returns `true` if `c` extends the cluster before it instead of starting a new one.
+#[inline]
+const fn is_extend(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036F // Combining diacritical marks
+            | 0x0483..=0x0489 // Cyrillic
+            | 0x0591..=0x05BD // Hebrew
+            | 0x0610..=0x061A | 0x064B..=0x065F | 0x0670 // Arabic
+            | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E // Thai
+            | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF // Combining marks extended and supplement
+            | 0x200C..=0x200D // Zero width non-joiner and joiner
+            | 0x20D0..=0x20FF // Combining marks for symbols
+            | 0xFE00..=0xFE0F // Variation selectors
+            | 0xFE20..=0xFE2F // Combining half marks
+            | 0x1F3FB..=0x1F3FF // Emoji skin tone modifiers
+            | 0xE0020..=0xE007F // Tags, as in subdivision flags
+            | 0xE0100..=0xE01EF // Variation selectors supplement
+    )
+}
+
+/// Returns `true` if `c` is an emoji or pictograph, which zero width joiners link into
+/// sequences.
+#[inline]
+const fn is_extended_pictographic(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139 // Copyright, marks, punctuation
+            | 0x2194..=0x2199 | 0x21A9..=0x21AA | 0x2934..=0x2935 | 0x2B05..=0x2B07 // Arrows
+            | 0x231A..=0x231B | 0x2328 | 0x23CF | 0x23E9..=0x23F3 | 0x23F8..=0x23FA // Technical
+            | 0x24C2 | 0x25AA..=0x25AB | 0x25B6 | 0x25C0 | 0x25FB..=0x25FE // Shapes
+            | 0x2600..=0x27BF // Miscellaneous symbols and dingbats
+            | 0x2B1B..=0x2B1C | 0x2B50 | 0x2B55 | 0x3030 | 0x303D | 0x3297 | 0x3299
+            | 0x1F000..=0x1F1E5 | 0x1F200..=0x1F3FA | 0x1F400..=0x1FAFF // Emoji blocks
+            | 0x1FC00..=0x1FFFD // Reserved for future emoji
+    )
+}
+
+/// Returns `true` if `c` is a regional indicator, half of a flag.
+#[inline(always)]
+const fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+/// Returns the byte index of the `char` at position `char_index` in `text`; see
+/// `String::char_to_byte_index`.
+pub(crate) fn char_to_byte_index(text: &str, char_index: usize) -> Option<usize> {
+    text.char_indices()
+        .map(|(index, _)| index)
+        .chain(core::iter::once(text.len()))
+        .nth(char_index)
+}
+
+/// Returns the position of the `char` starting at byte `byte_index` in `text`; see
+/// `String::byte_to_char_index`.
+pub(crate) fn byte_to_char_index(text: &str, byte_index: usize) -> Option<usize> {
+    if !text.is_char_boundary(byte_index) {
+        return None;
+    }
+
+    Some(text[..byte_index].chars().count())
+}
+
+/// Returns the largest grapheme cluster boundary of `text` at or before byte `index`; see
+/// `String::floor_grapheme_boundary`.
+pub(crate) fn floor_grapheme_boundary(text: &str, index: usize) -> usize {
+    if index >= text.len() {
+        return text.len();
+    }
+
+    let mut boundary = 0;
+
+    for cluster in Graphemes::new(text) {
+        if boundary + cluster.len() > index {
+            break;
+        }
+
+        boundary += cluster.len();
+    }
+
+    boundary
+}
+
+// ----------------------------- FromUtf8Error ----------------------------- //
+
+/// The error returned by `String::from_utf8`, which gives the bytes back.
+pub struct FromUtf8Error<A: Allocator = Global> {
+    bytes: Vec<u8, A>,
+    error: core::str::Utf8Error,
+}
+
+impl<A: Allocator> FromUtf8Error<A> {
+    /// Returns the bytes that were not valid UTF-8.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns the bytes that were not valid UTF-8, giving back their allocation.
+    #[inline(always)]
+    pub fn into_bytes(self) -> Vec<u8, A> {
+        self.bytes
+    }
+
+    /// Returns where and why the bytes are not valid UTF-8.
+    #[inline(always)]
+    pub const fn utf8_error(&self) -> core::str::Utf8Error {
+        self.error
+    }
+}
+
+// -------------------------------- String --------------------------------- //
+
+/// The storage of a `String`: inline while short, then a `Vec`.
+enum Repr<A: Allocator> {
+    Inline {
+        len: u8,
+        bytes: [u8; INLINE_CAPACITY],
+        allocator: A, // Moved into the `Vec` once the string outgrows the inline buffer
+    },
+    Heap(Vec<u8, A>),
+}
+
+/// A growable UTF-8 string, built on `Vec<u8>`.
+///
+/// Strings of up to 30 bytes are stored inline, without allocating, which covers most
+/// widget labels and ids; longer ones move to a `Vec` and stay there until
+/// `shrink_to_fit`. A `String` derefs to `str`, so every `str` method, like `chars`, is
+/// available on it.
+///
+/// Text is written with `push_str`, or formatted with `core::fmt::Write`.
+///
+/// # Example
+/// ```
+/// use celui_collections::String;
+/// use core::fmt::Write;
+///
+/// let mut label = String::from("Volume: ");
+/// write!(label, "{}%", 69).unwrap();
+///
+/// assert!(label == "Volume: 69%");
+/// assert!(label.is_inline());
+/// assert!(label.chars().rev().next() == Some('%'));
+///
+/// label.push_str(", and a label too long to be stored inline");
+/// assert!(!label.is_inline());
+/// ```
+pub struct String<A: Allocator = Global> {
+    repr: Repr<A>,
+}
+
+// The inline buffer is sized so a `String` stays 32 bytes, whether pointers take 4 or 8
+const _: () = assert!(core::mem::size_of::<String>() == 32);
+
+impl String {
+    /// Creates a new, empty string, which doesn't allocate.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new string with room for at least `capacity` bytes.
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new string with room for at least `capacity` bytes, or an error if
+    /// allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<A: Allocator> String<A> {
+    /// Creates a new, empty string that will allocate from `allocator` once it outgrows the
+    /// inline buffer.
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            repr: Repr::Inline {
+                len: 0,
+                bytes: [0; INLINE_CAPACITY],
+                allocator,
+            },
+        }
+    }
+
+    /// Converts a vector of bytes to a string, or returns an error holding the bytes if they
+    /// are not valid UTF-8. The vector's allocation is reused.
+    #[inline]
+    pub fn from_utf8(bytes: Vec<u8, A>) -> Result<Self, FromUtf8Error<A>> {
+        match core::str::from_utf8(bytes.as_slice()) {
+            // SAFETY: The bytes were just checked
+            Ok(_) => Ok(unsafe { Self::from_utf8_unchecked(bytes) }),
+            Err(error) => Err(FromUtf8Error { bytes, error }),
+        }
+    }
+
+    /// Converts a vector of bytes to a string, without checking them.
+    ///
+    /// # SAFETY
+    /// The bytes must be valid UTF-8.
+    #[inline(always)]
+    pub const unsafe fn from_utf8_unchecked(bytes: Vec<u8, A>) -> Self {
+        Self {
+            repr: Repr::Heap(bytes),
+        }
+    }
+
+    /// Returns a reference to the string's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        match &self.repr {
+            Repr::Inline { allocator, .. } => allocator,
+            Repr::Heap(bytes) => bytes.allocator(),
+        }
+    }
+
+    /// Returns the length of the string, in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { len, .. } => *len as usize,
+            Repr::Heap(bytes) => bytes.len(),
+        }
+    }
+
+    /// Returns the number of bytes the string can hold without allocating.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { .. } => INLINE_CAPACITY,
+            Repr::Heap(bytes) => bytes.capacity(),
+        }
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the string is stored inline, without an allocation.
+    #[inline(always)]
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+
+    /// Returns the string's bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.repr {
+            Repr::Inline { len, bytes, .. } => &bytes[..*len as usize],
+            Repr::Heap(bytes) => bytes.as_slice(),
+        }
+    }
+
+    /// Returns the string as a string slice.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The bytes are always valid UTF-8
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns the string as a mutable string slice.
+    #[inline(always)]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: The bytes are always valid UTF-8, and `str` keeps them that way
+        unsafe { core::str::from_utf8_unchecked_mut(self.as_bytes_mut()) }
+    }
+
+    /// Shortens the string to `new_len` bytes. Does nothing if it is already shorter.
+    ///
+    /// # Panics
+    /// Panics if `new_len` is not on a `char` boundary.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            assert!(
+                self.is_char_boundary(new_len),
+                "Index not on a char boundary"
+            );
+
+            // SAFETY: `new_len` is shorter, and on a char boundary
+            unsafe { self.set_len(new_len) };
+        }
+    }
+
+    /// Removes the last `char` of the string, and returns it, or `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.chars().next_back()?;
+
+        // SAFETY: The `char` is removed whole
+        unsafe { self.set_len(self.len() - c.len_utf8()) };
+
+        Some(c)
+    }
+
+    /// Removes the `char` starting at byte `index`, and returns it.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or not on a `char` boundary.
+    pub fn remove(&mut self, index: usize) -> char {
+        let Some(c) = self[index..].chars().next() else {
+            panic!("Index out of bounds");
+        };
+
+        let len = self.len();
+
+        // SAFETY: The `char` is moved to the end whole, then cut off
+        unsafe {
+            self.as_bytes_mut()[index..].rotate_left(c.len_utf8());
+            self.set_len(len - c.len_utf8());
+        }
+
+        c
+    }
+
+    /// Removes all text from the string, keeping its capacity.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        // SAFETY: An empty string is valid UTF-8
+        unsafe { self.set_len(0) };
+    }
+
+    /// Returns the byte index of the `char` at position `char_index`, or the length if
+    /// `char_index` is the number of `char`s. Returns `None` if it is past that.
+    ///
+    /// Takes time linear in `char_index`.
+    #[inline(always)]
+    pub fn char_to_byte_index(&self, char_index: usize) -> Option<usize> {
+        char_to_byte_index(self, char_index)
+    }
+
+    /// Returns the position of the `char` starting at byte `byte_index`, or the number of
+    /// `char`s if `byte_index` is the length. Returns `None` if `byte_index` is out of bounds
+    /// or not on a `char` boundary.
+    ///
+    /// Takes time linear in `byte_index`.
+    #[inline(always)]
+    pub fn byte_to_char_index(&self, byte_index: usize) -> Option<usize> {
+        byte_to_char_index(self, byte_index)
+    }
+
+    /// Returns an iterator over the string's grapheme clusters; see `Graphemes`.
+    #[inline(always)]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::new(self.as_str())
+    }
+
+    /// Returns the largest grapheme cluster boundary at or before byte `index`, or the length
+    /// if `index` is past it.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_collections::String;
+    ///
+    /// // "e" and a combining acute accent, then a flag made of two regional indicators
+    /// let mut label = String::from("Cafe\u{301} \u{1F1EB}\u{1F1F7}");
+    ///
+    /// assert_eq!(label.floor_grapheme_boundary(5), 3);
+    /// assert_eq!(label.floor_grapheme_boundary(11), 7);
+    ///
+    /// label.truncate(label.floor_grapheme_boundary(10));
+    /// assert!(label == "Cafe\u{301} ");
+    /// ```
+    #[inline(always)]
+    pub fn floor_grapheme_boundary(&self, index: usize) -> usize {
+        floor_grapheme_boundary(self, index)
+    }
+
+    /// Shortens the string to its first `count` grapheme clusters. Does nothing if it has
+    /// fewer.
+    #[inline]
+    pub fn truncate_graphemes(&mut self, count: usize) {
+        let len = self.graphemes().take(count).map(str::len).sum();
+
+        // SAFETY: Cluster boundaries are char boundaries
+        unsafe { self.set_len(len) };
+    }
+
+    /// Returns the string's bytes, mutably.
+    ///
+    /// # SAFETY
+    /// The bytes must be valid UTF-8 again before the string is used as a `str`.
+    #[inline]
+    unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        match &mut self.repr {
+            Repr::Inline { len, bytes, .. } => &mut bytes[..*len as usize],
+            Repr::Heap(bytes) => bytes.as_slice_mut(),
+        }
+    }
+
+    /// Sets the length of the string, in bytes.
+    ///
+    /// # SAFETY
+    /// `new_len` must be at most the length, and on a char boundary.
+    #[inline]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.len());
+
+        match &mut self.repr {
+            Repr::Inline { len, .. } => *len = new_len as u8,
+            Repr::Heap(bytes) => bytes.truncate(new_len),
+        }
+    }
+}
+
+impl<A: Allocator + Clone> String<A> {
+    /// Creates a new string with room for at least `capacity` bytes, allocated from
+    /// `allocator` if they don't fit inline.
+    #[inline(always)]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self::try_with_capacity_in(capacity, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new string with room for at least `capacity` bytes, allocated from
+    /// `allocator` if they don't fit inline, or an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        if capacity <= INLINE_CAPACITY {
+            return Ok(Self::new_in(allocator));
+        }
+
+        Ok(Self {
+            repr: Repr::Heap(Vec::try_with_capacity_in(capacity, allocator)?),
+        })
+    }
+
+    /// Converts the string into its bytes.
+    ///
+    /// Inline strings are copied into a new vector, heap ones give their vector back.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8, A> {
+        match self.repr {
+            Repr::Inline {
+                len,
+                bytes,
+                allocator,
+            } => {
+                let mut vec = Vec::with_capacity_in(len as usize, allocator);
+
+                vec.extend_from_slice(&bytes[..len as usize]);
+
+                vec
+            }
+            Repr::Heap(bytes) => bytes,
+        }
+    }
+
+    /// Appends a `char` to the end of the string.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends a `char` to the end of the string, or returns an error if growing fails.
+    #[inline]
+    pub fn try_push(&mut self, c: char) -> Result<(), AllocError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends a string slice to the end of the string.
+    #[inline]
+    pub fn push_str(&mut self, text: &str) {
+        if let Err(error) = self.try_push_str(text) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Appends a string slice to the end of the string, or returns an error if growing fails,
+    /// leaving the string unchanged.
+    #[inline]
+    pub fn try_push_str(&mut self, text: &str) -> Result<(), AllocError> {
+        self.try_reserve(text.len())?;
+
+        // SAFETY: Room was just made, and `text` is valid UTF-8
+        unsafe { self.append(text.as_bytes()) };
+
+        Ok(())
+    }
+
+    /// Inserts a `char` at byte `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or not on a `char` boundary.
+    #[inline]
+    pub fn insert(&mut self, index: usize, c: char) {
+        self.insert_str(index, c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts a string slice at byte `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds or not on a `char` boundary.
+    pub fn insert_str(&mut self, index: usize, text: &str) {
+        assert!(self.is_char_boundary(index), "Index not on a char boundary");
+
+        self.reserve(text.len());
+
+        // SAFETY: Room was just made. `text` is appended then rotated into place whole, so the
+        // string is valid UTF-8 again once done.
+        unsafe {
+            self.append(text.as_bytes());
+            self.as_bytes_mut()[index..].rotate_right(text.len());
+        }
+    }
+
+    /// Ensures the string has room for at least `additional` more bytes.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Ensures the string has room for at least `additional` more bytes, or returns an error
+    /// if growing fails, leaving the string unchanged.
+    ///
+    /// An inline string that needs more room moves to a `Vec`, with room to double first.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        match &mut self.repr {
+            Repr::Inline {
+                len,
+                bytes,
+                allocator,
+            } => {
+                let needed = (*len as usize)
+                    .checked_add(additional)
+                    .ok_or(AllocError::CapacityOverflow)?;
+
+                if needed <= INLINE_CAPACITY {
+                    return Ok(());
+                }
+
+                let capacity = needed.max(INLINE_CAPACITY * 2);
+                let mut heap = Vec::try_with_capacity_in(capacity, allocator.clone())?;
+
+                heap.extend_from_slice(&bytes[..*len as usize]);
+
+                self.repr = Repr::Heap(heap);
+
+                Ok(())
+            }
+            Repr::Heap(bytes) => bytes.try_reserve(additional),
+        }
+    }
+
+    /// Shrinks the capacity as much as possible, moving the string back inline if it fits.
+    pub fn shrink_to_fit(&mut self) {
+        let Repr::Heap(heap) = &mut self.repr else {
+            return;
+        };
+
+        if heap.len() > INLINE_CAPACITY {
+            heap.shrink_to_fit();
+
+            return;
+        }
+
+        let mut bytes = [0; INLINE_CAPACITY];
+
+        bytes[..heap.len()].copy_from_slice(heap.as_slice());
+
+        self.repr = Repr::Inline {
+            len: heap.len() as u8,
+            bytes,
+            allocator: heap.allocator().clone(),
+        };
+    }
+
+    /// Appends `bytes` to the end of the string.
+    ///
+    /// # SAFETY
+    /// There must be room for `bytes`, and the string must be valid UTF-8 again before it is
+    /// used as a `str`.
+    #[inline]
+    unsafe fn append(&mut self, text: &[u8]) {
+        match &mut self.repr {
+            Repr::Inline { len, bytes, .. } => {
+                let start = *len as usize;
+
+                debug_assert!(start + text.len() <= INLINE_CAPACITY);
+
+                bytes[start..start + text.len()].copy_from_slice(text);
+                *len += text.len() as u8;
+            }
+            Repr::Heap(bytes) => bytes.extend_from_slice(text),
+        }
+    }
+}
+
+impl<A: Allocator> core::ops::Deref for String<A> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator> core::ops::DerefMut for String<A> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<A: Allocator> AsRef<str> for String<A> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator> AsRef<[u8]> for String<A> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<A: Allocator> core::borrow::Borrow<str> for String<A> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator + Default> Default for String<A> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<A: Allocator + Clone> Clone for String<A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let repr = match &self.repr {
+            Repr::Inline {
+                len,
+                bytes,
+                allocator,
+            } => Repr::Inline {
+                len: *len,
+                bytes: *bytes,
+                allocator: allocator.clone(),
+            },
+            Repr::Heap(bytes) => Repr::Heap(bytes.clone()),
+        };
+
+        Self { repr }
+    }
+}
+
+impl<A: Allocator, B: Allocator> PartialEq<String<B>> for String<A> {
+    #[inline(always)]
+    fn eq(&self, other: &String<B>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<A: Allocator> PartialEq<str> for String<A> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<A: Allocator> PartialEq<&str> for String<A> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<A: Allocator> PartialEq<String<A>> for str {
+    #[inline(always)]
+    fn eq(&self, other: &String<A>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<A: Allocator> PartialEq<String<A>> for &str {
+    #[inline(always)]
+    fn eq(&self, other: &String<A>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<A: Allocator> Eq for String<A> {}
+
+impl<A: Allocator> PartialOrd for String<A> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Allocator> Ord for String<A> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<A: Allocator> core::hash::Hash for String<A> {
+    /// Hashes like `str`, so maps keyed by `String` can be looked up with a `&str`.
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<A: Allocator> core::fmt::Display for String<A> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<A: Allocator + Clone> core::fmt::Write for String<A> {
+    /// Appends `text`, or returns an error if growing the string fails.
+    #[inline]
+    fn write_str(&mut self, text: &str) -> core::fmt::Result {
+        self.try_push_str(text).map_err(|_| core::fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.try_push(c).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl<A: Allocator + Clone> core::ops::Add<&str> for String<A> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, text: &str) -> Self {
+        self.push_str(text);
+
+        self
+    }
+}
+
+impl<A: Allocator + Clone> core::ops::AddAssign<&str> for String<A> {
+    #[inline]
+    fn add_assign(&mut self, text: &str) {
+        self.push_str(text);
+    }
+}
+
+impl<A: Allocator + Clone> Extend<char> for String<A> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        self.reserve(iter.size_hint().0);
+
+        for c in iter {
+            self.push(c);
+        }
+    }
+}
+
+impl<'a, A: Allocator + Clone> Extend<&'a char> for String<A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, A: Allocator + Clone> Extend<&'a str> for String<A> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for text in iter {
+            self.push_str(text);
+        }
+    }
+}
+
+impl FromIterator<char> for String {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut string = Self::new();
+
+        string.extend(iter);
+
+        string
+    }
+}
+
+impl<'a> FromIterator<&'a str> for String {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut string = Self::new();
+
+        string.extend(iter);
+
+        string
+    }
+}
+
+impl From<&str> for String {
+    #[inline]
+    fn from(text: &str) -> Self {
+        let mut string = Self::with_capacity(text.len());
+
+        string.push_str(text);
+
+        string
+    }
+}
+
+impl From<char> for String {
+    #[inline]
+    fn from(c: char) -> Self {
+        let mut string = Self::new();
+
+        string.push(c);
+
+        string
+    }
+}
@@ -1,18 +1,26 @@
-use celui_sys::alloc::{alloc_many, dealloc_many};
+use celui_sys::alloc::{
+    dealloc_many_in, handle_alloc_error, try_alloc_many_in, try_realloc_many_in, AllocError,
+    Allocator, Global,
+};
 
-const MIN_NON_ZERO_CAPACITY: usize = 4; // Start with a slightly larger minimum capacity
+use crate::slots::Slots;
+
+pub use crate::slots::Drain;
+
+pub(crate) const MIN_NON_ZERO_CAPACITY: usize = 4; // Start with a slightly larger minimum capacity
 
 // ------------------------------- IntoIter -------------------------------- //
 
 /// An owning iterator that consumes the `Vec` and returns its elements.
-pub struct IntoIter<T> {
+pub struct IntoIter<T, A: Allocator = Global> {
     ptr: *mut T,
     len: usize,
     capacity: usize,
     current: usize,
+    allocator: A,
 }
 
-impl<T> Drop for IntoIter<T> {
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
     fn drop(&mut self) {
         // Drop remaining elements
         while self.current < self.len {
@@ -26,12 +34,12 @@ impl<T> Drop for IntoIter<T> {
 
         if !self.ptr.is_null() && self.capacity != 0 {
             // SAFETY: `self.ptr` is a valid pointer, and `self.capacity` is not 0
-            unsafe { dealloc_many(self.ptr, self.capacity) };
+            unsafe { dealloc_many_in(&self.allocator, self.ptr, self.capacity) };
         }
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -53,25 +61,19 @@ impl<T> Iterator for IntoIter<T> {
 /// An iterator over the elements of a `Vec`.
 pub struct Iter<'a, T> {
     ptr: *const T,
-    end: *const T,
+    remaining: usize, // Counted rather than an end pointer, which can't move for zero-sized types
 
     _marker: core::marker::PhantomData<&'a T>,
 }
 
 impl<'a, T> Iter<'a, T> {
     #[inline]
-    pub fn new(vec: &'a Vec<T>) -> Self {
-        let ptr = vec.ptr;
-        let len = vec.len;
-
-        // SAFETY: `vec` is a valid Vec<T>, therefore its fields `ptr` and `len` are valid as well
-        unsafe {
-            Self {
-                ptr,
-                end: ptr.add(len),
+    pub fn new<A: Allocator>(vec: &'a Vec<T, A>) -> Self {
+        Self {
+            ptr: vec.ptr,
+            remaining: vec.len,
 
-                _marker: core::marker::PhantomData,
-            }
+            _marker: core::marker::PhantomData,
         }
     }
 }
@@ -81,11 +83,13 @@ impl<'a, T> Iterator for Iter<'a, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.ptr == self.end {
+        if self.remaining == 0 {
             return None;
         }
 
-        // SAFETY: `self.ptr` is a valid pointer for reads up to `self.end` elements
+        self.remaining -= 1;
+
+        // SAFETY: `self.ptr` is valid for reads of the `self.remaining + 1` elements left
         unsafe {
             let item = &*self.ptr;
 
@@ -95,11 +99,9 @@ impl<'a, T> Iterator for Iter<'a, T> {
         }
     }
 
-    #[inline]
+    #[inline(always)]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = (self.end as usize - self.ptr as usize) / core::mem::size_of::<T>();
-
-        (remaining, Some(remaining))
+        (self.remaining, Some(self.remaining))
     }
 }
 
@@ -108,25 +110,19 @@ impl<'a, T> Iterator for Iter<'a, T> {
 /// A mutable iterator over the elements of a `Vec`.
 pub struct IterMut<'a, T> {
     ptr: *mut T,
-    end: *mut T,
+    remaining: usize, // Counted rather than an end pointer, which can't move for zero-sized types
 
     _marker: core::marker::PhantomData<&'a T>,
 }
 
 impl<'a, T> IterMut<'a, T> {
     #[inline]
-    pub fn new(vec: &'a mut Vec<T>) -> Self {
-        let ptr = vec.ptr;
-        let len = vec.len;
-
-        // SAFETY: `vec` is a valid Vec<T>, therefore its fields `ptr` and `len` are valid as well
-        unsafe {
-            Self {
-                ptr,
-                end: ptr.add(len),
+    pub fn new<A: Allocator>(vec: &'a mut Vec<T, A>) -> Self {
+        Self {
+            ptr: vec.ptr,
+            remaining: vec.len,
 
-                _marker: core::marker::PhantomData,
-            }
+            _marker: core::marker::PhantomData,
         }
     }
 }
@@ -136,11 +132,13 @@ impl<'a, T> Iterator for IterMut<'a, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.ptr == self.end {
+        if self.remaining == 0 {
             return None;
         }
 
-        // SAFETY: `self.ptr` is a valid pointer for reads up to `self.end` elements
+        self.remaining -= 1;
+
+        // SAFETY: `self.ptr` is valid for reads of the `self.remaining + 1` elements left
         unsafe {
             let item = &mut *self.ptr;
 
@@ -150,11 +148,9 @@ impl<'a, T> Iterator for IterMut<'a, T> {
         }
     }
 
-    #[inline]
+    #[inline(always)]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = (self.end as usize - self.ptr as usize) / core::mem::size_of::<T>();
-
-        (remaining, Some(remaining))
+        (self.remaining, Some(self.remaining))
     }
 }
 
@@ -172,40 +168,125 @@ impl<'a, T> Iterator for IterMut<'a, T> {
 /// vec.push(69);
 /// assert_eq!(vec.pop(), Some(69));
 /// ```
-pub struct Vec<T> {
+///
+/// `Vec` dereferences to a slice, so every slice method such as `contains`,
+/// `binary_search` or `sort_unstable` is available as well:
+/// ```
+/// use celui_collections::{vec, Vec};
+///
+/// let mut vec = vec![5, 1, 4, 1, 3];
+/// vec.sort();
+/// vec.dedup();
+/// assert_eq!(vec, [1, 3, 4, 5]);
+/// assert_eq!(vec.binary_search(&4), Ok(2));
+///
+/// vec.retain(|&x| x != 3);
+/// let drained: Vec<_> = vec.drain(1..).collect();
+/// assert_eq!(vec, [1]);
+/// assert_eq!(drained[..], [4, 5]);
+/// ```
+pub struct Vec<T, A: Allocator = Global> {
     ptr: *mut T,
     len: usize,
     capacity: usize,
+    allocator: A,
 }
 
 impl<T> Vec<T> {
     /// Creates a new, empty vector with zero capacity.
     #[inline(always)]
     pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new vector with a specified initial capacity.
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new vector with a specified initial capacity, or an error if allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> Vec<T, A> {
+    /// Creates a new, empty vector with zero capacity that will allocate from `allocator`.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_collections::Vec;
+    /// use celui_sys::alloc::{AllocError, Allocator, Global};
+    /// use core::{cell::Cell, ptr::NonNull};
+    ///
+    /// /// Counts the live allocations made through it.
+    /// #[derive(Default)]
+    /// struct Counting(Cell<usize>);
+    ///
+    /// unsafe impl Allocator for Counting {
+    ///     fn allocate(&self, size: usize, align: usize) -> Result<NonNull<u8>, AllocError> {
+    ///         self.0.set(self.0.get() + 1);
+    ///         Global.allocate(size, align)
+    ///     }
+    ///
+    ///     unsafe fn deallocate(&self, ptr: NonNull<u8>, size: usize, align: usize) {
+    ///         self.0.set(self.0.get() - 1);
+    ///         unsafe { Global.deallocate(ptr, size, align) }
+    ///     }
+    /// }
+    ///
+    /// let counting = Counting::default();
+    /// let mut vec = Vec::new_in(&counting);
+    ///
+    /// vec.extend(0..100);
+    /// assert_eq!(counting.0.get(), 1);
+    ///
+    /// drop(vec);
+    /// assert_eq!(counting.0.get(), 0);
+    /// ```
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
         Self {
-            ptr: core::ptr::null_mut(),
+            // Dangling but aligned, so slices of an empty vector stay valid
+            ptr: core::ptr::NonNull::dangling().as_ptr(),
             len: 0,
             capacity: 0,
+            allocator,
         }
     }
 
-    /// Creates a new vector with a specified initial capacity.
+    /// Creates a new vector with a specified initial capacity, allocated from `allocator`.
     #[inline(always)]
-    pub fn with_capacity(capacity: usize) -> Self {
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self::try_with_capacity_in(capacity, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new vector with a specified initial capacity, allocated from `allocator`, or
+    /// an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
         if capacity == 0 {
-            return Self::new();
+            return Ok(Self::new_in(allocator));
         }
 
-        // SAFETY: `alloc_many` returns a valid pointer
-        unsafe {
-            let ptr = alloc_many(capacity);
+        // SAFETY: `try_alloc_many_in` returns a valid pointer or an error
+        let ptr = unsafe { try_alloc_many_in(&allocator, capacity)? };
 
-            Self {
-                ptr,
-                len: 0,
-                capacity,
-            }
-        }
+        Ok(Self {
+            ptr,
+            len: 0,
+            capacity,
+            allocator,
+        })
+    }
+
+    /// Returns a reference to the vector's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        &self.allocator
     }
 
     /// Returns the number of elements in the vector.
@@ -262,13 +343,30 @@ impl<T> Vec<T> {
         unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
 
+    /// Returns a raw pointer to the vector's buffer.
+    ///
+    /// Unlike going through `as_slice`, no reference to the elements is created.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// Returns a raw mutable pointer to the vector's buffer.
+    ///
+    /// Unlike going through `as_slice_mut`, no reference to the elements is created, so
+    /// pointers previously derived from the buffer stay valid.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
     /// Returns an iterator over the slice.
     pub fn iter(&self) -> Iter<'_, T> {
         Iter::new(self)
     }
 
     /// Returns a mutable iterator over the slice.
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut::new(self)
     }
 
@@ -286,6 +384,20 @@ impl<T> Vec<T> {
         self.len += 1;
     }
 
+    /// Adds an element to the end of the vector, or returns an error if growing fails.
+    ///
+    /// On failure, `value` is dropped and the vector is left unchanged.
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), AllocError> {
+        if self.len == self.capacity {
+            self.try_grow(0)?;
+        }
+
+        self.push(value);
+
+        Ok(())
+    }
+
     /// Removes the last element from the vector and returns it, or `None` if empty.
     #[inline]
     pub fn pop(&mut self) -> Option<T> {
@@ -310,40 +422,185 @@ impl<T> Vec<T> {
             self.grow(0);
         }
 
-        // SAFETY: `index` is within bounds; we shift elements to prevent overwrites
-        unsafe {
-            core::ptr::copy(
-                self.ptr.add(index),
-                self.ptr.add(index + 1),
-                self.len - index,
-            );
+        // SAFETY: There is room for one more element
+        unsafe { self.slots().insert(index, element) };
+    }
 
-            core::ptr::write(self.ptr.add(index), element);
-        };
+    /// Inserts an element at the given index, or returns an error if growing fails.
+    ///
+    /// On failure, `element` is dropped and the vector is left unchanged.
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), AllocError> {
+        assert!(index <= self.len, "Index out of bounds");
 
-        self.len += 1;
+        if self.len == self.capacity {
+            self.try_grow(0)?;
+        }
+
+        self.insert(index, element);
+
+        Ok(())
     }
 
     /// Removes and returns the element at the given index, shifting subsequent elements left.
+    #[inline(always)]
     pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.len, "Index out of bounds");
+        self.slots().remove(index)
+    }
+
+    /// Removes and returns the element at the given index, replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    #[inline(always)]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.slots().swap_remove(index)
+    }
 
-        // SAFETY: `index` is within bounds; we shift elements after reading the value
+    /// Keeps only the elements for which `predicate` returns `true`, preserving their order.
+    #[inline(always)]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        self.slots().retain(predicate);
+    }
+
+    /// Removes consecutive repeated elements.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same_bucket(current, previous)` returns `true`.
+    #[inline(always)]
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
+        self.slots().dedup_by(same_bucket);
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// The elements are removed even if the iterator is not fully consumed.
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        self.slots().drain(range)
+    }
+
+    /// Splits the vector in two at `at`, returning the elements from `at` onwards.
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        assert!(at <= self.len, "Index out of bounds");
+
+        let count = self.len - at;
+        let mut other = Self::with_capacity_in(count, self.allocator.clone());
+
+        // SAFETY: `at..self.len` is initialized and `other` has room for `count` elements
         unsafe {
-            let value = core::ptr::read(self.ptr.add(index));
+            core::ptr::copy_nonoverlapping(self.ptr.add(at), other.ptr, count);
+
+            self.len = at;
+            other.len = count;
+        }
+
+        other
+    }
+
+    /// Moves all elements of `other` to the end of the vector, leaving `other` empty.
+    pub fn append<B: Allocator>(&mut self, other: &mut Vec<T, B>) {
+        let count = other.len;
 
-            core::ptr::copy(
-                self.ptr.add(index + 1),
-                self.ptr.add(index),
-                self.len - index - 1,
-            );
+        self.reserve(count);
 
-            self.len -= 1;
+        // SAFETY: We reserved `count` elements, and `other` gives up ownership of its elements
+        unsafe {
+            core::ptr::copy_nonoverlapping(other.ptr, self.ptr.add(self.len), count);
 
-            value
+            other.len = 0;
+            self.len += count;
         }
     }
 
+    /// Resizes the vector to `new_len`, filling new slots with clones of `value`.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+
+        while self.len + 1 < new_len {
+            self.push(value.clone());
+        }
+
+        self.push(value);
+    }
+
+    /// Appends clones of all elements of `slice` to the vector.
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(slice.len());
+
+        // Keeps the length in a local, so the loop has no stores or checks besides the clones
+        // and a slice of `Copy` elements compiles to one `memcpy`
+        let ptr = self.ptr;
+        let mut guard = SetLenOnDrop {
+            local_len: self.len,
+            len: &mut self.len,
+        };
+
+        for item in slice {
+            // SAFETY: We reserved room for every element of `slice` past the length
+            unsafe { ptr.add(guard.local_len).write(item.clone()) };
+
+            guard.local_len += 1;
+        }
+    }
+
+    /// Sorts the vector, preserving the order of equal elements.
+    ///
+    /// Uses a merge sort with a temporary buffer of `len` elements. `sort_unstable` is
+    /// available through the slice and doesn't allocate.
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.merge_sort(&mut |a: &T, b: &T| a.lt(b));
+    }
+
+    /// Sorts the vector with a comparator, preserving the order of equal elements.
+    #[inline]
+    pub fn sort_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, mut compare: F) {
+        self.merge_sort(&mut |a: &T, b: &T| compare(a, b) == core::cmp::Ordering::Less);
+    }
+
+    /// Sorts the vector by a key, preserving the order of equal elements.
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.merge_sort(&mut |a: &T, b: &T| key(a).lt(&key(b)));
+    }
+
+    /// Sorts the vector with `is_less`, using scratch space from the vector's allocator.
+    #[inline(always)]
+    fn merge_sort<F: FnMut(&T, &T) -> bool>(&mut self, is_less: &mut F) {
+        // SAFETY: `self.len` ensures we only create a valid slice within the bounds
+        let slice = unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) };
+
+        merge_sort(slice, &self.allocator, is_less);
+    }
+
     /// Fills the vector with elements by cloning `value`.
     pub fn fill(&mut self, value: T)
     where
@@ -374,74 +631,87 @@ impl<T> Vec<T> {
     }
 
     /// Extends the vector with the contents of an iterator.
+    #[inline]
     pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        let iter = iter.into_iter();
-        let (lower, upper) = iter.size_hint();
-        let additional = upper.unwrap_or(lower);
-
-        if additional > 0 {
-            self.reserve(additional);
-        }
-
-        // SAFETY: We reserved `additional` elements, and the iterator is bounded
-        unsafe {
-            let mut ptr = self.ptr.add(self.len);
-            let mut count = 0;
-
-            for item in iter {
-                if count < additional {
-                    core::ptr::write(ptr, item);
-
-                    ptr = ptr.add(1);
-                    count += 1;
-
-                    continue;
-                }
-
-                // Fallback to `push()` if iterator yields more than expected
-                self.push(item);
-            }
-
-            self.len += count;
-        }
+        extend_with(self, iter, Self::reserve, Self::push);
     }
 
     /// Truncates the vector to a new length, removing excess elements.
+    #[inline(always)]
     pub fn truncate(&mut self, new_len: usize) {
-        if new_len < self.len {
-            let old_len = self.len;
-
-            self.len = new_len;
-
-            // SAFETY: `new_len` is guaranteed to be less than `self.len`
-            unsafe {
-                let slice =
-                    core::ptr::slice_from_raw_parts_mut(self.ptr.add(new_len), old_len - new_len);
-
-                core::ptr::drop_in_place(slice);
-            }
-        }
+        self.slots().truncate(new_len);
     }
 
     /// Ensures that the vector has at least `additional` capacity.
     pub fn reserve(&mut self, additional: usize) {
-        let new_capacity = self.len.checked_add(additional).expect("Capacity overflow");
+        if let Err(error) = self.try_reserve(additional) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Ensures that the vector has at least `additional` capacity, or returns an error if
+    /// growing fails, leaving the vector unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_collections::Vec;
+    /// use celui_sys::alloc::AllocError;
+    ///
+    /// let mut vec: Vec<u64> = Vec::new();
+    ///
+    /// assert!(vec.try_reserve(usize::MAX) == Err(AllocError::CapacityOverflow));
+    /// assert!(vec.try_reserve(16).is_ok() && vec.capacity() >= 16);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        let new_capacity = self
+            .len
+            .checked_add(additional)
+            .ok_or(AllocError::CapacityOverflow)?;
 
         if new_capacity > self.capacity {
-            self.grow(new_capacity);
+            self.try_grow(new_capacity)?;
         }
+
+        Ok(())
+    }
+
+    /// Shrinks the capacity of the vector to its length, freeing the buffer if it is empty.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity > self.len {
+            // SAFETY: `self.ptr` holds `self.capacity` elements, and the first `self.len` are
+            // kept
+            self.ptr = unsafe {
+                try_realloc_many_in(&self.allocator, self.ptr, self.capacity, self.len)
+                    .unwrap_or_else(|error| handle_alloc_error(error))
+            };
+            self.capacity = self.len;
+        }
+    }
+
+    /// Returns a view of the vector's elements for the algorithms shared with `ArrayVec`
+    /// and `SmallVec`.
+    #[inline(always)]
+    fn slots(&mut self) -> Slots<'_, T> {
+        // SAFETY: `self.ptr` holds `self.len` initialized elements
+        unsafe { Slots::new(self.ptr, &mut self.len) }
     }
 
     /// Increases the vector's capacity to accommodate at least `minimum_capacity` elements.
     #[inline]
     fn grow(&mut self, minimum_capacity: usize) {
+        if let Err(error) = self.try_grow(minimum_capacity) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Increases the vector's capacity to accommodate at least `minimum_capacity` elements,
+    /// leaving the vector unchanged on failure.
+    fn try_grow(&mut self, minimum_capacity: usize) -> Result<(), AllocError> {
         let new_capacity = if self.capacity == 0 {
             MIN_NON_ZERO_CAPACITY.max(minimum_capacity)
         } else {
-            self.capacity
-                .checked_mul(2)
-                .expect("Capacity overflow")
-                .max(minimum_capacity)
+            // An overflow here is reported by the allocation below
+            self.capacity.saturating_mul(2).max(minimum_capacity)
         };
 
         debug_assert!(
@@ -449,95 +719,492 @@ impl<T> Vec<T> {
             "New capacity must be at least current length"
         );
 
-        // SAFETY: `alloc_many` returns a valid pointer
-        let new_ptr = unsafe { alloc_many(new_capacity) };
-
-        if !self.ptr.is_null() {
-            // SAFETY: `self.ptr` is a valid pointer, and `new_capacity` is >= `self.len`
-            unsafe {
-                core::ptr::copy_nonoverlapping(self.ptr, new_ptr, self.len);
-
-                dealloc_many(self.ptr, self.capacity);
-            }
-        }
-
-        self.ptr = new_ptr;
+        // SAFETY: `self.ptr` holds `self.capacity` elements, or nothing if the capacity is 0.
+        // Reallocating keeps the first `self.len` elements, as `new_capacity` is >= `self.len`.
+        self.ptr =
+            unsafe { try_realloc_many_in(&self.allocator, self.ptr, self.capacity, new_capacity)? };
         self.capacity = new_capacity;
+
+        Ok(())
     }
 }
 
-impl<T> Default for Vec<T> {
+impl<T, A: Allocator + Default> Default for Vec<T, A> {
     #[inline(always)]
     fn default() -> Self {
-        Self::new()
+        Self::new_in(A::default())
     }
 }
 
-impl<T: Copy> Clone for Vec<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for Vec<T, A> {
+    /// Clones every element into a vector allocated once, with a capacity of exactly `len`;
+    /// `Copy` types are copied with a single `memcpy`.
+    ///
+    /// If a clone panics, the elements cloned so far are dropped and nothing is leaked.
     fn clone(&self) -> Self {
-        let mut new_vec = Vec::with_capacity(self.capacity);
-
-        // SAFETY: `self.ptr` and `new_vec.ptr` are both valid pointers
-        unsafe {
-            core::ptr::copy_nonoverlapping(self.ptr, new_vec.ptr, self.len);
-        }
+        let mut new_vec = Vec::with_capacity_in(self.len, self.allocator.clone());
 
-        new_vec.len = self.len;
+        new_vec.extend_from_slice(self.as_slice());
 
         new_vec
     }
+
+    /// Clones `source` into `self`, reusing its allocation and existing elements.
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+
+        let (prefix, rest) = source.split_at(self.len);
+
+        self.as_slice_mut().clone_from_slice(prefix);
+        self.extend_from_slice(rest);
+    }
 }
 
-impl<T> IntoIterator for Vec<T> {
+impl<T, A: Allocator> IntoIterator for Vec<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
-
-    fn into_iter(mut self) -> Self::IntoIter {
-        let ptr = self.ptr;
-        let len = self.len;
-        let capacity = self.capacity;
+    type IntoIter = IntoIter<T, A>;
 
+    fn into_iter(self) -> Self::IntoIter {
         // Prevent the Vec from deallocating the buffer
-        self.ptr = core::ptr::null_mut();
-        self.len = 0;
-        self.capacity = 0;
+        let vec = core::mem::ManuallyDrop::new(self);
 
         IntoIter {
-            ptr,
-            len,
-            capacity,
+            ptr: vec.ptr,
+            len: vec.len,
+            capacity: vec.capacity,
             current: 0,
+            // SAFETY: `vec` is never used or dropped again, so the allocator is moved out once
+            allocator: unsafe { core::ptr::read(&vec.allocator) },
         }
     }
 }
 
-impl<T> Drop for Vec<T> {
+impl<T, A: Allocator> Drop for Vec<T, A> {
     fn drop(&mut self) {
         self.clear();
 
         if !self.ptr.is_null() && self.capacity != 0 {
             // SAFETY: `self.ptr` is a valid pointer, and `self.capacity` is not 0
-            unsafe { dealloc_many(self.ptr, self.capacity) };
+            unsafe { dealloc_many_in(&self.allocator, self.ptr, self.capacity) };
+        }
+    }
+}
+
+impl<T, A: Allocator, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for Vec<T, A> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, A: Allocator, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for Vec<T, A> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_slice_mut()[index]
+    }
+}
+
+impl<T, A: Allocator> core::ops::Deref for Vec<T, A> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> core::ops::DerefMut for Vec<T, A> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_slice_mut()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a Vec<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Vec<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Vec<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+
+        vec.extend(iter);
+
+        vec
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Vec<T, A> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        Vec::extend(self, iter);
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vec<T> {
+    fn from(array: [T; N]) -> Self {
+        let array = core::mem::ManuallyDrop::new(array);
+        let mut vec = Self::with_capacity(N);
+
+        // SAFETY: `vec` has room for `N` elements, and the array gives up ownership of them
+        unsafe {
+            core::ptr::copy_nonoverlapping(array.as_ptr(), vec.ptr, N);
+
+            vec.len = N;
+        }
+
+        vec
+    }
+}
+
+impl<T: Clone> From<&[T]> for Vec<T> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        let mut vec = Self::new();
+
+        vec.extend_from_slice(slice);
+
+        vec
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator, B: Allocator> PartialEq<Vec<U, B>> for Vec<T, A> {
+    #[inline(always)]
+    fn eq(&self, other: &Vec<U, B>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<[U]> for Vec<T, A> {
+    #[inline(always)]
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<&[U]> for Vec<T, A> {
+    #[inline(always)]
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator, const N: usize> PartialEq<[U; N]> for Vec<T, A> {
+    #[inline(always)]
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for Vec<T, A> {}
+
+impl<T: core::hash::Hash, A: Allocator> core::hash::Hash for Vec<T, A> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+/// Creates a `Vec` containing the arguments, like `std`'s `vec!`.
+///
+/// `vec![value; count]` clones `value` to fill `count` slots.
+///
+/// # Example
+/// ```
+/// use celui_collections::vec;
+///
+/// let numbers = vec![1, 2, 3];
+/// let zeros = vec![0u8; 4];
+///
+/// assert_eq!(numbers.len(), 3);
+/// assert_eq!(zeros, [0, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! vec {
+    () => {
+        $crate::Vec::new()
+    };
+    ($value:expr; $count:expr) => {{
+        let mut vec = $crate::Vec::new();
+
+        vec.resize($count, $value);
+
+        vec
+    }};
+    ($($item:expr),+ $(,)?) => {
+        $crate::Vec::from([$($item),+])
+    };
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Resolves `range` against a slice of `len` elements, panicking if it is out of bounds.
+pub(crate) fn slice_range<R: core::ops::RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> (usize, usize) {
+    use core::ops::Bound;
+
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("Range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("Range end overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "Range start is greater than its end");
+    assert!(end <= len, "Range end out of bounds");
+
+    (start, end)
+}
+
+/// Pushes every item of `iter` into `collection`, reserving room for the items the iterator is
+/// sure to yield first.
+///
+/// Only the lower bound of the size hint is reserved: the upper bound of e.g. a filtered range is
+/// the length of the whole range, and `push` grows the collection if more items come.
+pub(crate) fn extend_with<C, I: IntoIterator>(
+    collection: &mut C,
+    iter: I,
+    reserve: fn(&mut C, usize),
+    push: fn(&mut C, I::Item),
+) {
+    let iter = iter.into_iter();
+    let (lower, _) = iter.size_hint();
+
+    if lower > 0 {
+        reserve(collection, lower);
+    }
+
+    for item in iter {
+        push(collection, item);
+    }
+}
+
+/// Runs shorter than this are sorted with insertion sort before merging.
+const INSERTION_SORT_RUN: usize = 16;
+
+/// Sorts `slice` with a stable, bottom-up merge sort, taking scratch space from `allocator`.
+///
+/// If `is_less` panics, `slice` is left in an unspecified order but every element stays in it
+/// exactly once.
+pub(crate) fn merge_sort<T, A: Allocator, F: FnMut(&T, &T) -> bool>(
+    slice: &mut [T],
+    allocator: &A,
+    is_less: &mut F,
+) {
+    // Short slices are sorted by insertion alone and need no scratch space
+    if slice.len() <= INSERTION_SORT_RUN || core::mem::size_of::<T>() == 0 {
+        // SAFETY: The buffer is never touched when no runs are merged
+        unsafe { merge_sort_with_buffer(slice, core::ptr::null_mut(), is_less) };
+
+        return;
+    }
+
+    // Only used as scratch space; its length stays 0 so it never drops elements
+    let buffer = Vec::<T, &A>::with_capacity_in(slice.len(), allocator);
+
+    // SAFETY: `buffer` has room for every element of `slice`
+    unsafe { merge_sort_with_buffer(slice, buffer.ptr, is_less) };
+}
+
+/// Sorts `slice` like `merge_sort`, using `buffer` as scratch space.
+///
+/// # SAFETY
+/// Unless `slice` has at most `INSERTION_SORT_RUN` elements or they are zero-sized, `buffer`
+/// must be valid for writes of `slice.len()` elements and must not overlap `slice`.
+pub(crate) unsafe fn merge_sort_with_buffer<T, F: FnMut(&T, &T) -> bool>(
+    slice: &mut [T],
+    buffer: *mut T,
+    is_less: &mut F,
+) {
+    let len = slice.len();
+
+    // Zero-sized elements can't be told apart by their position
+    if len < 2 || core::mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    for start in (0..len).step_by(INSERTION_SORT_RUN) {
+        let end = (start + INSERTION_SORT_RUN).min(len);
+
+        insertion_sort(&mut slice[start..end], is_less);
+    }
+
+    let mut width = INSERTION_SORT_RUN;
+
+    while width < len {
+        for start in (0..len).step_by(2 * width) {
+            let mid = start + width;
+
+            if mid >= len {
+                break;
+            }
+
+            let end = (mid + width).min(len);
+
+            // SAFETY: `buffer` has room for the `width` elements of the left run
+            unsafe { merge(&mut slice[start..end], mid - start, buffer, is_less) };
+        }
+
+        width *= 2;
+    }
+}
+
+/// Sorts a short `slice` by inserting each element into the sorted prefix before it.
+fn insertion_sort<T, F: FnMut(&T, &T) -> bool>(slice: &mut [T], is_less: &mut F) {
+    let ptr = slice.as_mut_ptr();
+
+    for i in 1..slice.len() {
+        // SAFETY: All indices are below `slice.len()`. The element being inserted is held in
+        // `hole`, which writes it back into the single vacant slot on drop, even on panic.
+        unsafe {
+            if !is_less(&*ptr.add(i), &*ptr.add(i - 1)) {
+                continue;
+            }
+
+            let mut hole = Hole {
+                value: core::mem::ManuallyDrop::new(core::ptr::read(ptr.add(i))),
+                dest: ptr.add(i - 1),
+            };
+
+            core::ptr::copy_nonoverlapping(ptr.add(i - 1), ptr.add(i), 1);
+
+            for j in (0..i - 1).rev() {
+                if !is_less(&*hole.value, &*ptr.add(j)) {
+                    break;
+                }
+
+                core::ptr::copy_nonoverlapping(ptr.add(j), ptr.add(j + 1), 1);
+
+                hole.dest = ptr.add(j);
+            }
+        }
+    }
+}
+
+/// Merges the sorted runs `slice[..mid]` and `slice[mid..]`, using `buffer` for the left run.
+///
+/// # SAFETY
+/// `buffer` must be valid for writes of `mid` elements and must not overlap `slice`.
+unsafe fn merge<T, F: FnMut(&T, &T) -> bool>(
+    slice: &mut [T],
+    mid: usize,
+    buffer: *mut T,
+    is_less: &mut F,
+) {
+    let len = slice.len();
+    let ptr = slice.as_mut_ptr();
+
+    // SAFETY: Both runs are non-empty, so `mid - 1..=mid` is within bounds. While merging,
+    // `out` trails `right` by exactly the number of elements left in the left run.
+    unsafe {
+        // Already in order, nothing to merge
+        if !is_less(&*ptr.add(mid), &*ptr.add(mid - 1)) {
+            return;
+        }
+
+        core::ptr::copy_nonoverlapping(ptr, buffer, mid);
+
+        // Whatever is left of the left run fills the gap in front of the right run on drop
+        let mut state = MergeState {
+            left: buffer,
+            left_end: buffer.add(mid),
+            right: ptr.add(mid),
+            right_end: ptr.add(len),
+            out: ptr,
+        };
+
+        while state.left < state.left_end && state.right < state.right_end {
+            // Taking from the left on ties keeps the sort stable
+            let source = if is_less(&*state.right, &*state.left) {
+                let source = state.right;
+
+                state.right = state.right.add(1);
+
+                source
+            } else {
+                let source = state.left;
+
+                state.left = state.left.add(1);
+
+                source
+            };
+
+            core::ptr::copy_nonoverlapping(source, state.out, 1);
+
+            state.out = state.out.add(1);
         }
     }
 }
 
-impl<T> core::ops::Index<usize> for Vec<T> {
-    type Output = T;
+/// Stores `local_len` into `len` on drop, so elements written before a panic are owned.
+struct SetLenOnDrop<'a> {
+    len: &'a mut usize,
+    local_len: usize,
+}
 
-    fn index(&self, index: usize) -> &Self::Output {
-        assert!(index < self.len, "Index out of bounds");
+impl Drop for SetLenOnDrop<'_> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        *self.len = self.local_len;
+    }
+}
+
+/// Holds an element taken out of a slice and writes it back to `dest` on drop.
+pub(crate) struct Hole<T> {
+    pub(crate) value: core::mem::ManuallyDrop<T>,
+    pub(crate) dest: *mut T,
+}
 
-        // SAFETY: `index` is guaranteed to be within bounds
-        unsafe { &*self.ptr.add(index) }
+impl<T> Drop for Hole<T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        // SAFETY: `dest` is the single vacant slot of the slice being sorted
+        unsafe { core::ptr::copy_nonoverlapping(&*self.value, self.dest, 1) };
     }
 }
 
-impl<T> core::ops::IndexMut<usize> for Vec<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        assert!(index < self.len, "Index out of bounds");
+/// The progress of a `merge`, which moves the rest of the left run into place on drop.
+struct MergeState<T> {
+    left: *mut T,
+    left_end: *mut T,
+    right: *mut T,
+    right_end: *mut T,
+    out: *mut T,
+}
 
-        // SAFETY: `index` is guaranteed to be within bounds
-        unsafe { &mut *self.ptr.add(index) }
+impl<T> Drop for MergeState<T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        // SAFETY: The remaining left elements exactly fill the gap at `out`
+        unsafe {
+            let count = self.left_end.offset_from(self.left) as usize;
+
+            core::ptr::copy_nonoverlapping(self.left, self.out, count);
+        }
     }
 }
@@ -0,0 +1,887 @@
+use celui_sys::alloc::{
+    dealloc_many_in, handle_alloc_error, try_alloc_many_in, try_realloc_many_in, AllocError,
+    Allocator, Global,
+};
+
+use crate::slots::Slots;
+use crate::vec::{extend_with, MIN_NON_ZERO_CAPACITY};
+
+pub use crate::slots::Drain;
+
+// --------------------------------- Iter ---------------------------------- //
+
+/// An iterator over the elements of a `VecDeque` or a `RingBuffer`, front to back.
+#[derive(Clone)]
+pub struct Iter<'a, T> {
+    front: core::slice::Iter<'a, T>, // Elements from the head to the end of the buffer
+    back: core::slice::Iter<'a, T>,  // Elements that wrapped around to the buffer's start
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Creates an iterator over `front`, then `back`.
+    #[inline(always)]
+    pub(crate) fn new(front: &'a [T], back: &'a [T]) -> Self {
+        Self {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.front.next() {
+            Some(item) => Some(item),
+            None => self.back.next(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.front.len() + self.back.len();
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.back.next_back() {
+            Some(item) => Some(item),
+            None => self.front.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> core::iter::FusedIterator for Iter<'_, T> {}
+
+// ------------------------------- IterMut --------------------------------- //
+
+/// A mutable iterator over the elements of a `VecDeque` or a `RingBuffer`, front to back.
+pub struct IterMut<'a, T> {
+    front: core::slice::IterMut<'a, T>, // Elements from the head to the end of the buffer
+    back: core::slice::IterMut<'a, T>,  // Elements that wrapped around to the buffer's start
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Creates an iterator over `front`, then `back`.
+    #[inline(always)]
+    pub(crate) fn new(front: &'a mut [T], back: &'a mut [T]) -> Self {
+        Self {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.front.next() {
+            Some(item) => Some(item),
+            None => self.back.next(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.front.len() + self.back.len();
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.back.next_back() {
+            Some(item) => Some(item),
+            None => self.front.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> core::iter::FusedIterator for IterMut<'_, T> {}
+
+// ------------------------------- IntoIter -------------------------------- //
+
+/// An owning iterator that consumes the `VecDeque` and returns its elements.
+pub struct IntoIter<T, A: Allocator = Global> {
+    deque: VecDeque<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.deque.pop_front()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> core::iter::FusedIterator for IntoIter<T, A> {}
+
+// ------------------------------- VecDeque -------------------------------- //
+
+/// A double-ended queue, stored as a growable ring buffer.
+///
+/// Elements are pushed and popped at both ends in O(1). They are stored from `head` onwards
+/// and wrap around to the start of the buffer, so the deque is seen as two slices; see
+/// `as_slices` and `make_contiguous`.
+///
+/// # Example
+/// ```
+/// use celui_collections::VecDeque;
+///
+/// let mut events = VecDeque::new();
+///
+/// events.push_back("resize");
+/// events.push_back("redraw");
+/// events.push_front("focus"); // Handled before anything else
+///
+/// assert_eq!(events.pop_front(), Some("focus"));
+/// assert_eq!(events.pop_front(), Some("resize"));
+/// assert_eq!(events.len(), 1);
+/// ```
+pub struct VecDeque<T, A: Allocator = Global> {
+    ptr: *mut T,
+    capacity: usize,
+    head: usize, // Physical index of the front element
+    len: usize,
+    allocator: A,
+}
+
+impl<T> VecDeque<T> {
+    /// Creates a new, empty deque with zero capacity.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates a new deque with a specified initial capacity.
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a new deque with a specified initial capacity, or an error if allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> VecDeque<T, A> {
+    /// Creates a new, empty deque with zero capacity that will allocate from `allocator`.
+    #[inline(always)]
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            // Dangling but aligned, so slices of an empty deque stay valid
+            ptr: core::ptr::NonNull::dangling().as_ptr(),
+            capacity: 0,
+            head: 0,
+            len: 0,
+            allocator,
+        }
+    }
+
+    /// Creates a new deque with a specified initial capacity, allocated from `allocator`.
+    #[inline(always)]
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+        Self::try_with_capacity_in(capacity, allocator)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Creates a new deque with a specified initial capacity, allocated from `allocator`, or
+    /// an error if allocation fails.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, allocator: A) -> Result<Self, AllocError> {
+        if capacity == 0 {
+            return Ok(Self::new_in(allocator));
+        }
+
+        // SAFETY: `try_alloc_many_in` returns a valid pointer or an error
+        let ptr = unsafe { try_alloc_many_in(&allocator, capacity)? };
+
+        Ok(Self {
+            ptr,
+            capacity,
+            head: 0,
+            len: 0,
+            allocator,
+        })
+    }
+
+    /// Returns a reference to the deque's allocator.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
+    /// Returns the number of elements in the deque.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity of the deque.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at the given index from the front, or `None` if
+    /// out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: `index` is within bounds, so its slot is initialized
+            return Some(unsafe { &*self.ptr.add(self.physical(index)) });
+        }
+
+        None
+    }
+
+    /// Returns a mutable reference to the element at the given index from the front, or
+    /// `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            // SAFETY: `index` is within bounds, so its slot is initialized
+            return Some(unsafe { &mut *self.ptr.add(self.physical(index)) });
+        }
+
+        None
+    }
+
+    /// Returns the front element, or `None` if the deque is empty.
+    #[inline(always)]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the front element mutably, or `None` if the deque is empty.
+    #[inline(always)]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns the back element, or `None` if the deque is empty.
+    #[inline(always)]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns the back element mutably, or `None` if the deque is empty.
+    #[inline(always)]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns `true` if the deque contains an element equal to `value`.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (front, back) = self.as_slices();
+
+        front.contains(value) || back.contains(value)
+    }
+
+    /// Returns the elements as two slices, which hold them front to back when joined.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front_len, back_len) = self.slice_lens();
+
+        // SAFETY: The elements are initialized from `head` to the end of the buffer, then
+        // wrap around to its start
+        unsafe {
+            (
+                core::slice::from_raw_parts(self.ptr.add(self.head), front_len),
+                core::slice::from_raw_parts(self.ptr, back_len),
+            )
+        }
+    }
+
+    /// Returns the elements as two mutable slices, which hold them front to back when joined.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front_len, back_len) = self.slice_lens();
+
+        // SAFETY: The elements are initialized from `head` to the end of the buffer, then
+        // wrap around to its start, so the two slices never overlap
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(self.ptr.add(self.head), front_len),
+                core::slice::from_raw_parts_mut(self.ptr, back_len),
+            )
+        }
+    }
+
+    /// Rearranges the elements so they don't wrap around the buffer, and returns them as a
+    /// single slice.
+    ///
+    /// Takes time linear in the capacity if they wrap, and does nothing otherwise.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.len > self.capacity {
+            // SAFETY: The buffer holds `self.capacity` slots, which are rotated as a whole, so
+            // the front element ends up first and the rest follow it in order
+            unsafe {
+                let slots = core::slice::from_raw_parts_mut(
+                    self.ptr as *mut core::mem::MaybeUninit<T>,
+                    self.capacity,
+                );
+
+                slots.rotate_left(self.head);
+            }
+
+            self.head = 0;
+        }
+
+        // SAFETY: The elements are now initialized from `head` onwards, without wrapping
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.add(self.head), self.len) }
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+
+        Iter::new(front, back)
+    }
+
+    /// Returns a mutable iterator over the elements, front to back.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+
+        IterMut::new(front, back)
+    }
+
+    /// Adds an element to the back of the deque, growing its capacity if needed.
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        if let Err(error) = self.try_push_back(value) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Adds an element to the back of the deque, or returns an error if growing fails.
+    ///
+    /// On failure, `value` is dropped and the deque is left unchanged.
+    #[inline]
+    pub fn try_push_back(&mut self, value: T) -> Result<(), AllocError> {
+        if self.len == self.capacity {
+            self.try_grow(0)?;
+        }
+
+        // SAFETY: There is room for one more element, right after the back one
+        unsafe { core::ptr::write(self.ptr.add(self.physical(self.len)), value) };
+
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Adds an element to the front of the deque, growing its capacity if needed.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        if let Err(error) = self.try_push_front(value) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Adds an element to the front of the deque, or returns an error if growing fails.
+    ///
+    /// On failure, `value` is dropped and the deque is left unchanged.
+    #[inline]
+    pub fn try_push_front(&mut self, value: T) -> Result<(), AllocError> {
+        if self.len == self.capacity {
+            self.try_grow(0)?;
+        }
+
+        self.head = self.physical(self.capacity - 1);
+
+        // SAFETY: There is room for one more element, right before the front one
+        unsafe { core::ptr::write(self.ptr.add(self.head), value) };
+
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the front element and returns it, or `None` if the deque is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let head = self.head;
+
+        self.head = self.physical(1);
+        self.len -= 1;
+
+        // SAFETY: The front element was initialized, and is no longer counted by the length
+        Some(unsafe { core::ptr::read(self.ptr.add(head)) })
+    }
+
+    /// Removes the back element and returns it, or `None` if the deque is empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: The back element was initialized, and is no longer counted by the length
+        Some(unsafe { core::ptr::read(self.ptr.add(self.physical(self.len))) })
+    }
+
+    /// Inserts an element at the given index from the front, shifting whichever side of it
+    /// is shorter.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "Index out of bounds");
+
+        if self.len == self.capacity {
+            // `0` means default growth strategy
+            self.grow(0);
+        }
+
+        // SAFETY: There is room for one more element. Each element is moved into the slot
+        // next to it, which is either vacant or was just moved out of.
+        unsafe {
+            if index < self.len / 2 {
+                self.head = self.physical(self.capacity - 1);
+
+                for i in 0..index {
+                    self.copy_slot(i + 1, i);
+                }
+            } else {
+                for i in (index..self.len).rev() {
+                    self.copy_slot(i, i + 1);
+                }
+            }
+
+            core::ptr::write(self.ptr.add(self.physical(index)), value);
+        }
+
+        self.len += 1;
+    }
+
+    /// Removes and returns the element at the given index from the front, or `None` if out
+    /// of bounds, shifting whichever side of it is shorter.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        // SAFETY: `index` is within bounds. Each element is moved into the slot next to it,
+        // which was just moved out of.
+        unsafe {
+            let value = core::ptr::read(self.ptr.add(self.physical(index)));
+
+            if index < self.len / 2 {
+                for i in (0..index).rev() {
+                    self.copy_slot(i, i + 1);
+                }
+
+                self.head = self.physical(1);
+            } else {
+                for i in index + 1..self.len {
+                    self.copy_slot(i, i - 1);
+                }
+            }
+
+            self.len -= 1;
+
+            Some(value)
+        }
+    }
+
+    /// Swaps the elements at indices `a` and `b` from the front.
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len && b < self.len, "Index out of bounds");
+
+        // SAFETY: Both indices are within bounds
+        unsafe {
+            core::ptr::swap(
+                self.ptr.add(self.physical(a)),
+                self.ptr.add(self.physical(b)),
+            )
+        };
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`, preserving their order.
+    ///
+    /// Makes the deque contiguous first.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        self.contiguous_slots().retain(predicate);
+    }
+
+    /// Removes the elements in `range` from the front and returns them as an iterator.
+    ///
+    /// Makes the deque contiguous first. The elements are removed even if the iterator is not
+    /// fully consumed.
+    #[inline]
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        self.contiguous_slots().drain(range)
+    }
+
+    /// Truncates the deque to its first `new_len` elements, dropping the ones at the back.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len {
+            return;
+        }
+
+        let (front_len, _) = self.slice_lens();
+        let old_len = self.len;
+
+        self.len = new_len;
+
+        // SAFETY: The elements in `new_len..old_len` are initialized, and no longer counted by
+        // the length. Their part before the wrap is dropped first, then the wrapped part.
+        unsafe {
+            if new_len < front_len {
+                let slice = core::ptr::slice_from_raw_parts_mut(
+                    self.ptr.add(self.head + new_len),
+                    front_len - new_len,
+                );
+
+                core::ptr::drop_in_place(slice);
+            }
+
+            let wrapped_start = new_len.saturating_sub(front_len);
+            let slice = core::ptr::slice_from_raw_parts_mut(
+                self.ptr.add(wrapped_start),
+                (old_len - front_len).saturating_sub(wrapped_start),
+            );
+
+            core::ptr::drop_in_place(slice);
+        }
+    }
+
+    /// Removes all elements from the deque, keeping its capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+
+        self.head = 0;
+    }
+
+    /// Extends the deque at the back with the contents of an iterator.
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        extend_with(self, iter, Self::reserve, Self::push_back);
+    }
+
+    /// Ensures that the deque has at least `additional` capacity.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Ensures that the deque has at least `additional` capacity, or returns an error if
+    /// growing fails, leaving the deque unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        let new_capacity = self
+            .len
+            .checked_add(additional)
+            .ok_or(AllocError::CapacityOverflow)?;
+
+        if new_capacity > self.capacity {
+            self.try_grow(new_capacity)?;
+        }
+
+        Ok(())
+    }
+
+    /// Shrinks the capacity of the deque to its length, freeing the buffer if it is empty.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity > self.len {
+            self.make_contiguous();
+
+            // SAFETY: The elements are contiguous from `head`, so they are moved to the start
+            // of the buffer, which is then shrunk to hold exactly them
+            unsafe {
+                core::ptr::copy(self.ptr.add(self.head), self.ptr, self.len);
+
+                self.ptr = try_realloc_many_in(&self.allocator, self.ptr, self.capacity, self.len)
+                    .unwrap_or_else(|error| handle_alloc_error(error));
+            }
+
+            self.head = 0;
+            self.capacity = self.len;
+        }
+    }
+
+    /// Returns the physical index of the element at logical `index`, which is at most the
+    /// capacity.
+    #[inline(always)]
+    fn physical(&self, index: usize) -> usize {
+        let index = self.head + index;
+
+        if index >= self.capacity {
+            index - self.capacity
+        } else {
+            index
+        }
+    }
+
+    /// Returns the lengths of the two slices returned by `as_slices`.
+    #[inline(always)]
+    fn slice_lens(&self) -> (usize, usize) {
+        let front_len = self.len.min(self.capacity - self.head);
+
+        (front_len, self.len - front_len)
+    }
+
+    /// Moves the element at logical index `from` into the slot at logical index `to`.
+    ///
+    /// # SAFETY
+    /// Both indices must be within the capacity, `from` initialized and `to` vacant.
+    #[inline(always)]
+    unsafe fn copy_slot(&mut self, from: usize, to: usize) {
+        // SAFETY: Guaranteed by the caller
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.ptr.add(self.physical(from)),
+                self.ptr.add(self.physical(to)),
+                1,
+            )
+        };
+    }
+
+    /// Makes the deque contiguous and returns a view of its elements for the algorithms
+    /// shared with `Vec`.
+    #[inline(always)]
+    fn contiguous_slots(&mut self) -> Slots<'_, T> {
+        self.make_contiguous();
+
+        // SAFETY: The elements are contiguous from `head`
+        unsafe { Slots::new(self.ptr.add(self.head), &mut self.len) }
+    }
+
+    /// Increases the deque's capacity to accommodate at least `minimum_capacity` elements.
+    #[inline]
+    fn grow(&mut self, minimum_capacity: usize) {
+        if let Err(error) = self.try_grow(minimum_capacity) {
+            handle_alloc_error(error);
+        }
+    }
+
+    /// Increases the deque's capacity to accommodate at least `minimum_capacity` elements,
+    /// leaving the deque unchanged on failure.
+    fn try_grow(&mut self, minimum_capacity: usize) -> Result<(), AllocError> {
+        let old_capacity = self.capacity;
+        let new_capacity = if old_capacity == 0 {
+            MIN_NON_ZERO_CAPACITY.max(minimum_capacity)
+        } else {
+            // An overflow here is reported by the allocation below
+            old_capacity.saturating_mul(2).max(minimum_capacity)
+        };
+
+        // SAFETY: `self.ptr` holds `old_capacity` elements, or nothing if it is 0, and all of
+        // them are kept as the capacity only grows
+        self.ptr =
+            unsafe { try_realloc_many_in(&self.allocator, self.ptr, old_capacity, new_capacity)? };
+        self.capacity = new_capacity;
+
+        // Elements that wrapped around the old end must now sit before the new one
+        if self.head + self.len > old_capacity {
+            let front_len = old_capacity - self.head;
+            let wrapped_len = self.len - front_len;
+
+            // SAFETY: Either the wrapped elements fit right after the old end, or the front
+            // ones are moved to the new end, past the wrapped ones as the capacity grew
+            unsafe {
+                if wrapped_len <= new_capacity - old_capacity {
+                    core::ptr::copy_nonoverlapping(
+                        self.ptr,
+                        self.ptr.add(old_capacity),
+                        wrapped_len,
+                    );
+                } else {
+                    let new_head = new_capacity - front_len;
+
+                    core::ptr::copy(self.ptr.add(self.head), self.ptr.add(new_head), front_len);
+
+                    self.head = new_head;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, A: Allocator> Drop for VecDeque<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+
+        // SAFETY: `self.ptr` holds `self.capacity` elements, or nothing if the capacity is 0
+        unsafe { dealloc_many_in(&self.allocator, self.ptr, self.capacity) };
+    }
+}
+
+impl<T, A: Allocator + Default> Default for VecDeque<T, A> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for VecDeque<T, A> {
+    /// Clones every element into a contiguous deque.
+    fn clone(&self) -> Self {
+        let mut new_deque = Self::with_capacity_in(self.len, self.allocator.clone());
+
+        new_deque.extend(self.iter().cloned());
+
+        new_deque
+    }
+}
+
+impl<T, A: Allocator> core::ops::Index<usize> for VecDeque<T, A> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+
+impl<T, A: Allocator> core::ops::IndexMut<usize> for VecDeque<T, A> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("Index out of bounds")
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for VecDeque<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a VecDeque<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut VecDeque<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for VecDeque<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+
+        deque.extend(iter);
+
+        deque
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for VecDeque<T, A> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        VecDeque::extend(self, iter);
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecDeque<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        let mut deque = Self::with_capacity(N);
+
+        deque.extend(array);
+
+        deque
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator, B: Allocator> PartialEq<VecDeque<U, B>> for VecDeque<T, A> {
+    #[inline]
+    fn eq(&self, other: &VecDeque<U, B>) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<[U]> for VecDeque<T, A> {
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        let (front, back) = self.as_slices();
+
+        self.len == other.len() && front == &other[..front.len()] && back == &other[front.len()..]
+    }
+}
+
+impl<T: PartialEq<U>, U, A: Allocator, const N: usize> PartialEq<[U; N]> for VecDeque<T, A> {
+    #[inline(always)]
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for VecDeque<T, A> {}
+
+impl<T: core::hash::Hash, A: Allocator> core::hash::Hash for VecDeque<T, A> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
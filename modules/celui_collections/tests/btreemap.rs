@@ -0,0 +1,409 @@
+//! Property tests for `BTreeMap` and `BTreeSet`, which run random operations against a sorted
+//! `Vec` of pairs and check that both always agree.
+
+use std::{
+    cell::Cell,
+    ops::{Bound, RangeBounds},
+    rc::Rc,
+};
+
+use celui_collections::{BTreeMap, BTreeSet};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn bound(&mut self, max: u64) -> Bound<u32> {
+        let key = self.below(max) as u32;
+
+        match self.below(3) {
+            0 => Bound::Included(key),
+            1 => Bound::Excluded(key),
+            _ => Bound::Unbounded,
+        }
+    }
+}
+
+/// The reference model: pairs sorted by key, without duplicates.
+#[derive(Default)]
+struct Reference(Vec<(u32, u32)>);
+
+impl Reference {
+    fn insert(&mut self, key: u32, value: u32) -> Option<u32> {
+        match self.0.binary_search_by_key(&key, |&(key, _)| key) {
+            Ok(index) => Some(std::mem::replace(&mut self.0[index].1, value)),
+            Err(index) => {
+                self.0.insert(index, (key, value));
+
+                None
+            }
+        }
+    }
+
+    fn remove(&mut self, key: u32) -> Option<u32> {
+        let index = self.0.binary_search_by_key(&key, |&(key, _)| key).ok()?;
+
+        Some(self.0.remove(index).1)
+    }
+
+    fn pop_first(&mut self) -> Option<(u32, u32)> {
+        (!self.0.is_empty()).then(|| self.0.remove(0))
+    }
+
+    fn get(&self, key: u32) -> Option<u32> {
+        let index = self.0.binary_search_by_key(&key, |&(key, _)| key).ok()?;
+
+        Some(self.0[index].1)
+    }
+
+    fn range(&self, start: Bound<u32>, end: Bound<u32>) -> Vec<(u32, u32)> {
+        self.0
+            .iter()
+            .copied()
+            .filter(|&(key, _)| (start, end).contains(&key))
+            .collect()
+    }
+}
+
+/// Returns `true` if `range` would make the map panic, because it starts after it ends.
+fn is_reversed(start: Bound<u32>, end: Bound<u32>) -> bool {
+    match (start, end) {
+        (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end))
+        | (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end)) => start > end,
+        _ => false,
+    }
+}
+
+fn pairs(map: &BTreeMap<u32, u32>) -> Vec<(u32, u32)> {
+    map.iter().map(|(&key, &value)| (key, value)).collect()
+}
+
+#[test]
+fn random_operations_match_sorted_vec() {
+    for seed in 1..=40u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut map = BTreeMap::new();
+        let mut reference = Reference::default();
+
+        // Small key spaces hit existing keys often, large ones grow deep trees
+        let keys = [16, 200, 5000][seed as usize % 3];
+
+        for step in 0..3000 {
+            let key = rng.below(keys) as u32;
+
+            match rng.below(12) {
+                0..=4 => {
+                    let value = rng.next() as u32;
+
+                    assert_eq!(map.insert(key, value), reference.insert(key, value));
+                }
+                5..=7 => assert_eq!(map.remove(&key), reference.remove(key)),
+                8 => assert_eq!(map.pop_first(), reference.pop_first()),
+                9 => assert_eq!(map.pop_last(), reference.0.pop()),
+                10 => {
+                    if let Some(value) = map.get_mut(&key) {
+                        *value = value.wrapping_add(1);
+                    }
+
+                    if let Some(pair) = reference.0.iter_mut().find(|pair| pair.0 == key) {
+                        pair.1 = pair.1.wrapping_add(1);
+                    }
+                }
+                _ => {
+                    let modulus = rng.below(5) as u32 + 2;
+
+                    map.retain(|key, value| {
+                        *value = value.wrapping_mul(3);
+
+                        key % modulus != 0
+                    });
+
+                    reference.0.retain_mut(|(key, value)| {
+                        *value = value.wrapping_mul(3);
+
+                        *key % modulus != 0
+                    });
+                }
+            }
+
+            assert_eq!(map.len(), reference.0.len());
+            assert_eq!(map.get(&key).copied(), reference.get(key));
+            assert_eq!(map.contains_key(&key), reference.get(key).is_some());
+
+            if step % 50 == 0 {
+                assert_eq!(pairs(&map), reference.0);
+                assert_eq!(
+                    map.first_key_value().map(|(&key, &value)| (key, value)),
+                    reference.0.first().copied()
+                );
+                assert_eq!(
+                    map.last_key_value().map(|(&key, &value)| (key, value)),
+                    reference.0.last().copied()
+                );
+            }
+        }
+
+        assert_eq!(pairs(&map), reference.0);
+    }
+}
+
+#[test]
+fn ranges_match_sorted_vec() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut map = BTreeMap::new();
+    let mut reference = Reference::default();
+
+    for _ in 0..2000 {
+        let key = rng.below(3000) as u32;
+
+        map.insert(key, key * 2);
+        reference.insert(key, key * 2);
+
+        let (start, end) = (rng.bound(3100), rng.bound(3100));
+
+        if is_reversed(start, end) {
+            continue;
+        }
+
+        let expected = reference.range(start, end);
+
+        let forward: Vec<_> = map
+            .range((start, end))
+            .map(|(&key, &value)| (key, value))
+            .collect();
+
+        assert_eq!(forward, expected);
+
+        let mut backward: Vec<_> = map
+            .range((start, end))
+            .rev()
+            .map(|(&key, &value)| (key, value))
+            .collect();
+
+        backward.reverse();
+        assert_eq!(backward, expected);
+
+        // Alternate ends, which must meet in the middle without skipping or repeating a pair
+        let mut range = map.range((start, end));
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+
+        loop {
+            let pair = if rng.below(2) == 0 {
+                range.next().map(|pair| front.push(pair))
+            } else {
+                range.next_back().map(|pair| back.push(pair))
+            };
+
+            if pair.is_none() {
+                break;
+            }
+        }
+
+        front.extend(back.into_iter().rev());
+
+        let front: Vec<_> = front
+            .into_iter()
+            .map(|(&key, &value)| (key, value))
+            .collect();
+
+        assert_eq!(front, expected);
+    }
+
+    assert!(map.range(5..5).next().is_none());
+    assert!(map
+        .range((Bound::Excluded(5), Bound::Excluded(5)))
+        .next()
+        .is_none());
+}
+
+#[test]
+fn mutable_iteration_matches_sorted_vec() {
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+    let mut map = BTreeMap::new();
+    let mut reference = Reference::default();
+
+    for _ in 0..1500 {
+        let key = rng.below(1000) as u32;
+
+        map.insert(key, 0);
+        reference.insert(key, 0);
+    }
+
+    for round in 0..50 {
+        let (start, end) = (rng.bound(1100), rng.bound(1100));
+
+        if is_reversed(start, end) {
+            continue;
+        }
+
+        for (_, value) in map.range_mut((start, end)) {
+            *value += round;
+        }
+
+        for (key, value) in reference.0.iter_mut() {
+            if (start, end).contains(key) {
+                *value += round;
+            }
+        }
+
+        // Alternate ends, which must meet in the middle without skipping or repeating a pair
+        let mut iter = map.iter_mut();
+
+        loop {
+            let value = if rng.below(2) == 0 {
+                iter.next()
+            } else {
+                iter.next_back()
+            };
+
+            match value {
+                Some((_, value)) => *value += 1,
+                None => break,
+            }
+        }
+
+        for (_, value) in reference.0.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(pairs(&map), reference.0);
+    }
+
+    for value in map.values_mut() {
+        *value = 7;
+    }
+
+    assert!(map.values().all(|&value| value == 7));
+    assert!(map
+        .keys()
+        .copied()
+        .eq(reference.0.iter().map(|&(key, _)| key)));
+}
+
+#[test]
+fn set_matches_sorted_vec() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    let mut set = BTreeSet::new();
+    let mut reference: Vec<u32> = Vec::new();
+
+    for _ in 0..4000 {
+        let value = rng.below(700) as u32;
+
+        match rng.below(3) {
+            0 | 1 => {
+                let inserted = match reference.binary_search(&value) {
+                    Ok(_) => false,
+                    Err(index) => {
+                        reference.insert(index, value);
+
+                        true
+                    }
+                };
+
+                assert_eq!(set.insert(value), inserted);
+            }
+            _ => {
+                let removed = match reference.binary_search(&value) {
+                    Ok(index) => Some(reference.remove(index)),
+                    Err(_) => None,
+                };
+
+                assert_eq!(set.take(&value), removed);
+            }
+        }
+
+        assert_eq!(set.len(), reference.len());
+        assert_eq!(
+            set.contains(&value),
+            reference.binary_search(&value).is_ok()
+        );
+    }
+
+    assert!(set.iter().copied().eq(reference.iter().copied()));
+    assert!(set.range(100..300).copied().eq(reference
+        .iter()
+        .copied()
+        .filter(|value| (100..300).contains(value))));
+
+    let clone = set.clone();
+
+    assert!(clone == set);
+    assert!(clone.into_iter().rev().eq(reference.iter().rev().copied()));
+}
+
+/// A value that counts how many of its kind are alive.
+struct Counted(Rc<Cell<i64>>);
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self(live.clone())
+    }
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        Self::new(&self.0)
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    let mut rng = Rng(0x0bad_5eed_1234_4321);
+
+    for round in 0..20 {
+        let mut map = BTreeMap::new();
+
+        for _ in 0..2000 {
+            let key = rng.below(800) as u32;
+
+            match rng.below(4) {
+                0..=2 => drop(map.insert(key, Counted::new(&live))),
+                _ => drop(map.remove(&key)),
+            }
+        }
+
+        map.retain(|key, _| key % 3 != 0);
+
+        assert_eq!(live.get(), map.len() as i64);
+
+        let clone = map.clone();
+
+        assert_eq!(live.get(), 2 * map.len() as i64);
+
+        match round % 3 {
+            0 => map.clear(),
+            1 => {
+                let mut iter = map.into_iter();
+
+                iter.next();
+                iter.next_back();
+            }
+            _ => drop(map),
+        }
+
+        assert_eq!(live.get(), clone.len() as i64);
+    }
+
+    assert_eq!(live.get(), 0);
+}
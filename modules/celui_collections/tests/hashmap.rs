@@ -0,0 +1,376 @@
+//! Property tests for `HashMap`, which run random operations against the standard library's
+//! map and check that both always agree, under good, weak and colliding hashers.
+
+use std::{
+    cell::Cell,
+    collections::HashMap as StdMap,
+    hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use celui_collections::{hash::BuildQuickHasher, HashMap};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// A hasher that sends every key to one of 16 slots with the same control byte, so probe
+/// sequences are long and every lookup compares keys.
+#[derive(Default)]
+struct Colliding(u64);
+
+impl Hasher for Colliding {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.wrapping_add(byte as u64);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0 % 16
+    }
+}
+
+fn sorted<S: BuildHasher>(map: &HashMap<u32, u32, S>) -> Vec<(u32, u32)> {
+    let mut pairs: Vec<_> = map.iter().map(|(&key, &value)| (key, value)).collect();
+
+    pairs.sort_unstable();
+    pairs
+}
+
+fn sorted_std(map: &StdMap<u32, u32>) -> Vec<(u32, u32)> {
+    let mut pairs: Vec<_> = map.iter().map(|(&key, &value)| (key, value)).collect();
+
+    pairs.sort_unstable();
+    pairs
+}
+
+fn check_against_std<S: BuildHasher>(hash_builder: S, seed: u64, keys: u64, steps: usize) {
+    let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    let mut map = HashMap::with_hasher(hash_builder);
+    let mut reference = StdMap::new();
+
+    for step in 0..steps {
+        let key = rng.below(keys) as u32;
+
+        match rng.below(16) {
+            0..=5 => {
+                let value = rng.next() as u32;
+
+                assert_eq!(map.insert(key, value), reference.insert(key, value));
+            }
+            6..=9 => assert_eq!(map.remove(&key), reference.remove(&key)),
+            10 => {
+                *map.entry(key).or_insert(0) += 1;
+                *reference.entry(key).or_insert(0) += 1;
+            }
+            11 => {
+                if let Some(value) = map.get_mut(&key) {
+                    *value = value.wrapping_mul(7);
+                }
+
+                if let Some(value) = reference.get_mut(&key) {
+                    *value = value.wrapping_mul(7);
+                }
+            }
+            12 => {
+                let modulus = rng.below(4) as u32 + 2;
+
+                map.retain(|key, _| key % modulus != 0);
+                reference.retain(|key, _| key % modulus != 0);
+            }
+            13 => {
+                map.shrink_to_fit();
+
+                assert!(map.capacity() >= map.len());
+            }
+            14 => {
+                let additional = rng.below(64) as usize;
+
+                map.reserve(additional);
+
+                assert!(map.capacity() >= map.len() + additional);
+            }
+            _ => {
+                if rng.below(20) == 0 {
+                    map.clear();
+                    reference.clear();
+                }
+            }
+        }
+
+        assert_eq!(map.len(), reference.len());
+        assert_eq!(map.get(&key), reference.get(&key));
+        assert_eq!(map.contains_key(&key), reference.contains_key(&key));
+
+        if step % 100 == 0 {
+            assert_eq!(sorted(&map), sorted_std(&reference));
+            assert_eq!(map.iter().len(), reference.len());
+        }
+    }
+
+    assert_eq!(sorted(&map), sorted_std(&reference));
+
+    let mut drained: Vec<_> = map.drain().collect();
+
+    drained.sort_unstable();
+    assert_eq!(drained, sorted_std(&reference));
+    assert!(map.is_empty());
+}
+
+#[test]
+fn random_operations_match_std() {
+    for seed in 1..=12u64 {
+        // Small key spaces hit existing keys and tombstones often, large ones force growth
+        let keys = [16, 300, 20_000][seed as usize % 3];
+
+        check_against_std(
+            celui_collections::hash::RandomState::new(),
+            seed,
+            keys,
+            4000,
+        );
+    }
+}
+
+#[test]
+fn random_operations_match_std_with_quick_hasher() {
+    for seed in 1..=6u64 {
+        check_against_std(BuildQuickHasher::default(), seed, 500, 4000);
+    }
+}
+
+#[test]
+fn random_operations_match_std_when_every_key_collides() {
+    for seed in 1..=4u64 {
+        check_against_std(BuildHasherDefault::<Colliding>::default(), seed, 200, 3000);
+    }
+}
+
+#[test]
+fn churn_reuses_deleted_slots_instead_of_growing() {
+    let mut map = HashMap::new();
+    let mut max_capacity = 0;
+
+    // A sliding window of 40 live keys, which leaves a deleted slot behind on most removals
+    for key in 0..100_000u32 {
+        map.insert(key, key);
+
+        if key >= 40 {
+            assert_eq!(map.remove(&(key - 40)), Some(key - 40));
+        }
+
+        max_capacity = max_capacity.max(map.capacity());
+    }
+
+    assert_eq!(map.len(), 40);
+    // 128 slots hold 112 pairs, and 40 live pairs never need more
+    assert!(max_capacity <= 112, "capacity grew to {max_capacity}");
+    assert!((99_960..100_000).all(|key| map.get(&key) == Some(&key)));
+}
+
+#[test]
+fn clones_are_equal_and_independent() {
+    let mut map = HashMap::new();
+
+    for key in 0..500u32 {
+        map.insert(key, key * 3);
+    }
+
+    for key in (0..500u32).step_by(3) {
+        map.remove(&key);
+    }
+
+    let mut clone = map.clone();
+
+    assert!(clone == map);
+
+    clone.insert(1, 0);
+
+    assert_eq!(map[&1], 3);
+    assert!(clone != map);
+    assert!((0..500u32).all(|key| clone.contains_key(&key) == map.contains_key(&key)));
+}
+
+/// A value that counts how many of its kind are alive, and panics when `clones_left` runs out.
+struct Counted {
+    live: Rc<Cell<i64>>,
+    clones_left: Rc<Cell<u32>>,
+}
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>, clones_left: &Rc<Cell<u32>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self {
+            live: live.clone(),
+            clones_left: clones_left.clone(),
+        }
+    }
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        let clones_left = self.clones_left.get();
+
+        assert!(clones_left > 0, "Clone failed");
+
+        self.clones_left.set(clones_left - 1);
+
+        Self::new(&self.live, &self.clones_left)
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut rng = Rng(0x0bad_5eed_1234_4321);
+
+    for round in 0..12 {
+        let mut map = HashMap::new();
+
+        for _ in 0..3000 {
+            let key = rng.below(700) as u32;
+
+            match rng.below(4) {
+                0..=2 => drop(map.insert(key, Counted::new(&live, &clones_left))),
+                _ => drop(map.remove(&key)),
+            }
+        }
+
+        map.retain(|key, _| key % 3 != 0);
+
+        assert_eq!(live.get(), map.len() as i64);
+
+        let clone = map.clone();
+
+        assert_eq!(live.get(), 2 * map.len() as i64);
+
+        match round % 4 {
+            0 => map.clear(),
+            1 => {
+                // A partly consumed drain still removes everything
+                let mut drain = map.drain();
+
+                drain.next();
+                drain.next();
+                drop(drain);
+
+                assert!(map.is_empty());
+            }
+            2 => {
+                let mut iter = map.into_iter();
+
+                iter.next();
+            }
+            _ => drop(map),
+        }
+
+        assert_eq!(live.get(), clone.len() as i64);
+    }
+
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn a_panicking_clone_drops_what_was_cloned() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut map = HashMap::new();
+
+    for key in 0..200u32 {
+        map.insert(key, Counted::new(&live, &clones_left));
+    }
+
+    clones_left.set(120);
+
+    let result = catch_unwind(AssertUnwindSafe(|| map.clone()));
+
+    assert!(result.is_err());
+    assert_eq!(live.get(), 200);
+
+    drop(map);
+    assert_eq!(live.get(), 0);
+}
+
+thread_local! {
+    static HASHES_LEFT: Cell<u32> = const { Cell::new(u32::MAX) };
+}
+
+/// A key whose `Hash` panics once `HASHES_LEFT` runs out.
+#[derive(PartialEq, Eq)]
+struct FragileKey(u32);
+
+impl Hash for FragileKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let left = HASHES_LEFT.get();
+
+        assert!(left > 0, "Hash failed");
+
+        HASHES_LEFT.set(left - 1);
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn a_panicking_hash_during_growth_leaves_a_usable_map() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut map = HashMap::new();
+    let mut key = 0;
+
+    while map.len() < map.capacity() || map.is_empty() {
+        map.insert(FragileKey(key), Counted::new(&live, &clones_left));
+        key += 1;
+    }
+
+    // The new key hashes fine, then growing panics after moving five pairs
+    HASHES_LEFT.set(6);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        map.insert(FragileKey(key), Counted::new(&live, &clones_left))
+    }));
+
+    HASHES_LEFT.set(u32::MAX);
+
+    assert!(result.is_err());
+    assert_eq!(map.len(), 5);
+    assert_eq!(live.get(), 5);
+    assert_eq!(
+        (0..key)
+            .filter(|&key| map.contains_key(&FragileKey(key)))
+            .count(),
+        5
+    );
+
+    for key in 1000..1100 {
+        map.insert(FragileKey(key), Counted::new(&live, &clones_left));
+    }
+
+    assert_eq!(map.len(), 105);
+    assert!((1000..1100).all(|key| map.contains_key(&FragileKey(key))));
+
+    drop(map);
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,369 @@
+//! Property tests for `HashSet`, `IndexMap` and `IndexSet`, which run random operations against
+//! the standard library's set and an ordered `Vec`, and check that both always agree.
+
+use std::{
+    cell::Cell,
+    collections::HashSet as StdSet,
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use celui_collections::{HashSet, IndexMap, IndexSet};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn sorted(set: &HashSet<u32>) -> Vec<u32> {
+    let mut values: Vec<_> = set.iter().copied().collect();
+
+    values.sort_unstable();
+    values
+}
+
+fn sorted_std<'a>(values: impl Iterator<Item = &'a u32>) -> Vec<u32> {
+    let mut values: Vec<_> = values.copied().collect();
+
+    values.sort_unstable();
+    values
+}
+
+#[test]
+fn hash_set_matches_std() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    let mut set = HashSet::new();
+    let mut reference = StdSet::new();
+
+    for step in 0..20_000 {
+        let value = rng.below(900) as u32;
+
+        match rng.below(10) {
+            0..=4 => assert_eq!(set.insert(value), reference.insert(value)),
+            5..=6 => assert_eq!(set.remove(&value), reference.remove(&value)),
+            7 => assert_eq!(set.take(&value), reference.take(&value)),
+            8 => {
+                let modulus = rng.below(5) as u32 + 2;
+
+                set.retain(|value| value % modulus != 0);
+                reference.retain(|value| value % modulus != 0);
+            }
+            _ => set.shrink_to_fit(),
+        }
+
+        assert_eq!(set.len(), reference.len());
+        assert_eq!(set.contains(&value), reference.contains(&value));
+
+        if step % 500 == 0 {
+            assert_eq!(sorted(&set), sorted_std(reference.iter()));
+        }
+    }
+
+    let other: HashSet<u32> = (0..900).step_by(7).collect();
+    let other_std: StdSet<u32> = (0..900).step_by(7).collect();
+
+    assert_eq!(
+        sorted_std(set.union(&other)),
+        sorted_std(reference.union(&other_std))
+    );
+    assert_eq!(
+        sorted_std(set.intersection(&other)),
+        sorted_std(reference.intersection(&other_std))
+    );
+    assert_eq!(
+        sorted_std(set.difference(&other)),
+        sorted_std(reference.difference(&other_std))
+    );
+    assert_eq!(
+        sorted_std(set.symmetric_difference(&other)),
+        sorted_std(reference.symmetric_difference(&other_std))
+    );
+    assert_eq!(set.is_disjoint(&other), reference.is_disjoint(&other_std));
+    assert_eq!(set.is_subset(&other), reference.is_subset(&other_std));
+
+    let mut drained: Vec<_> = set.drain().collect();
+
+    drained.sort_unstable();
+    assert_eq!(drained, sorted_std(reference.iter()));
+    assert!(set.is_empty());
+}
+
+/// The reference model: pairs in insertion order.
+#[derive(Default)]
+struct Reference(Vec<(u32, u32)>);
+
+impl Reference {
+    fn position(&self, key: u32) -> Option<usize> {
+        self.0.iter().position(|&(other, _)| other == key)
+    }
+
+    fn insert(&mut self, key: u32, value: u32) -> (usize, Option<u32>) {
+        match self.position(key) {
+            Some(index) => (index, Some(std::mem::replace(&mut self.0[index].1, value))),
+            None => {
+                self.0.push((key, value));
+
+                (self.0.len() - 1, None)
+            }
+        }
+    }
+}
+
+fn pairs(map: &IndexMap<u32, u32>) -> Vec<(u32, u32)> {
+    map.iter().map(|(&key, &value)| (key, value)).collect()
+}
+
+#[test]
+fn index_map_matches_ordered_vec() {
+    for seed in 1..=20u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut map = IndexMap::new();
+        let mut reference = Reference::default();
+
+        // Small key spaces hit existing keys often, large ones force growth
+        let keys = [16, 300, 3000][seed as usize % 3];
+
+        for step in 0..3000 {
+            let key = rng.below(keys) as u32;
+
+            match rng.below(14) {
+                0..=5 => {
+                    let value = rng.next() as u32;
+
+                    assert_eq!(map.insert_full(key, value), reference.insert(key, value));
+                }
+                6 => {
+                    let expected = reference.position(key).map(|index| {
+                        let (_, value) = reference.0.swap_remove(index);
+
+                        value
+                    });
+
+                    assert_eq!(map.swap_remove(&key), expected);
+                }
+                7 => {
+                    let expected = reference
+                        .position(key)
+                        .map(|index| reference.0.remove(index).1);
+
+                    assert_eq!(map.shift_remove(&key), expected);
+                }
+                8 if !reference.0.is_empty() => {
+                    let index = rng.below(reference.0.len() as u64) as usize;
+
+                    assert_eq!(
+                        map.swap_remove_index(index),
+                        Some(reference.0.swap_remove(index))
+                    );
+                }
+                9 if !reference.0.is_empty() => {
+                    let index = rng.below(reference.0.len() as u64) as usize;
+
+                    assert_eq!(
+                        map.shift_remove_index(index),
+                        Some(reference.0.remove(index))
+                    );
+                }
+                10 => assert_eq!(map.pop(), reference.0.pop()),
+                11 if !reference.0.is_empty() => {
+                    let len = reference.0.len() as u64;
+                    let (from, to) = (rng.below(len) as usize, rng.below(len) as usize);
+                    let pair = reference.0.remove(from);
+
+                    reference.0.insert(to, pair);
+                    map.move_index(from, to);
+                }
+                12 => {
+                    let modulus = rng.below(5) as u32 + 2;
+
+                    map.retain(|key, _| key % modulus != 0);
+                    reference.0.retain(|(key, _)| key % modulus != 0);
+                }
+                13 if step % 50 == 0 => {
+                    map.sort_keys();
+                    reference.0.sort_by_key(|&(key, _)| key);
+                }
+                _ => {
+                    *map.entry(key).or_insert(0) += 1;
+
+                    match reference.position(key) {
+                        Some(index) => reference.0[index].1 += 1,
+                        None => reference.0.push((key, 1)),
+                    }
+                }
+            }
+
+            assert_eq!(map.len(), reference.0.len());
+            assert_eq!(map.get_index_of(&key), reference.position(key));
+
+            if step % 50 == 0 {
+                assert_eq!(pairs(&map), reference.0);
+                assert!(reference
+                    .0
+                    .iter()
+                    .enumerate()
+                    .all(|(index, (key, _))| map.get_index_of(key) == Some(index)));
+            }
+        }
+
+        assert_eq!(pairs(&map), reference.0);
+        assert!(map.clone() == map);
+    }
+}
+
+#[test]
+fn index_set_matches_ordered_vec() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut set = IndexSet::new();
+    let mut reference: Vec<u32> = Vec::new();
+
+    for step in 0..10_000 {
+        let value = rng.below(400) as u32;
+        let position = reference.iter().position(|&other| other == value);
+
+        match rng.below(6) {
+            0..=2 => {
+                let expected = match position {
+                    Some(index) => (index, false),
+                    None => {
+                        reference.push(value);
+
+                        (reference.len() - 1, true)
+                    }
+                };
+
+                assert_eq!(set.insert_full(value), expected);
+            }
+            3 => {
+                if let Some(index) = position {
+                    reference.swap_remove(index);
+                }
+
+                assert_eq!(set.swap_remove(&value), position.is_some());
+            }
+            4 => {
+                if let Some(index) = position {
+                    reference.remove(index);
+                }
+
+                assert_eq!(set.shift_remove(&value), position.is_some());
+            }
+            _ => assert_eq!(set.pop(), reference.pop()),
+        }
+
+        assert_eq!(set.len(), reference.len());
+
+        if step % 100 == 0 {
+            assert!(set.iter().eq(reference.iter()));
+            assert_eq!(set.first(), reference.first());
+            assert_eq!(set.last(), reference.last());
+        }
+    }
+
+    assert!(set.iter().eq(reference.iter()));
+}
+
+/// A value that counts how many of its kind are alive, and panics when `clones_left` runs out.
+struct Counted {
+    live: Rc<Cell<i64>>,
+    clones_left: Rc<Cell<u32>>,
+}
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>, clones_left: &Rc<Cell<u32>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self {
+            live: live.clone(),
+            clones_left: clones_left.clone(),
+        }
+    }
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        let clones_left = self.clones_left.get();
+
+        assert!(clones_left > 0, "Clone failed");
+
+        self.clones_left.set(clones_left - 1);
+
+        Self::new(&self.live, &self.clones_left)
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+#[test]
+fn every_index_map_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut rng = Rng(0x0bad_5eed_1234_4321);
+
+    for round in 0..9 {
+        let mut map = IndexMap::new();
+
+        for _ in 0..2000 {
+            let key = rng.below(500) as u32;
+
+            match rng.below(6) {
+                0..=3 => drop(map.insert(key, Counted::new(&live, &clones_left))),
+                4 => drop(map.swap_remove(&key)),
+                _ => drop(map.shift_remove(&key)),
+            }
+        }
+
+        map.retain(|key, _| key % 3 != 0);
+
+        assert_eq!(live.get(), map.len() as i64);
+
+        let clone = map.clone();
+
+        assert_eq!(live.get(), 2 * map.len() as i64);
+
+        match round % 3 {
+            0 => map.clear(),
+            1 => {
+                let mut iter = map.into_iter();
+
+                iter.next();
+            }
+            _ => drop(map),
+        }
+
+        assert_eq!(live.get(), clone.len() as i64);
+    }
+
+    assert_eq!(live.get(), 0);
+
+    // A clone that panics part way drops the values it already cloned
+    let mut map = IndexMap::new();
+
+    for key in 0..100u32 {
+        map.insert(key, Counted::new(&live, &clones_left));
+    }
+
+    clones_left.set(60);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| map.clone())).is_err());
+    assert_eq!(live.get(), 100);
+
+    drop(map);
+    assert_eq!(live.get(), 0);
+}
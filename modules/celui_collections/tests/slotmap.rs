@@ -0,0 +1,375 @@
+//! Property tests for `SlotMap` and `SecondaryMap`, which run random operations against the
+//! standard library's map and check that both always agree, and that removed keys stay dead
+//! after their slot is reused.
+
+use std::{
+    cell::Cell,
+    collections::HashMap as StdMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use celui_collections::{
+    slotmap::{Key, KeyData},
+    DefaultKey, SecondaryMap, SlotMap,
+};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Returns the slot index of `key`.
+fn index(key: DefaultKey) -> u32 {
+    key.data().to_bits() as u32
+}
+
+/// Returns the slot version of `key`.
+fn version(key: DefaultKey) -> u32 {
+    (key.data().to_bits() >> 32) as u32
+}
+
+#[test]
+fn slot_map_matches_std() {
+    for seed in 1..=12u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut map: SlotMap<DefaultKey, u32> = SlotMap::new();
+        let mut reference: StdMap<DefaultKey, u32> = StdMap::new();
+        let mut live: Vec<DefaultKey> = Vec::new();
+        let mut dead: Vec<DefaultKey> = Vec::new();
+        let mut most_live = 0;
+
+        for step in 0..5000 {
+            let value = rng.next() as u32;
+
+            match rng.below(10) {
+                0..=3 => {
+                    let key = map.insert(value);
+
+                    // A reused slot gets a newer version, so the key is new even if its index isn't
+                    assert!(!key.is_null());
+                    assert!(!reference.contains_key(&key));
+                    assert!(!dead.contains(&key));
+
+                    reference.insert(key, value);
+                    live.push(key);
+                }
+                4 => {
+                    let key = map.insert_with_key(|key| index(key) ^ version(key));
+
+                    reference.insert(key, index(key) ^ version(key));
+                    live.push(key);
+                }
+                5..=7 if !live.is_empty() => {
+                    let key = live.swap_remove(rng.below(live.len() as u64) as usize);
+
+                    assert_eq!(map.remove(key), reference.remove(&key));
+                    assert_eq!(map.remove(key), None);
+
+                    dead.push(key);
+                }
+                8 if live.len() >= 2 => {
+                    let (a, b) = (live[0], live[live.len() - 1]);
+
+                    if let Some([first, second]) = map.get_disjoint_mut([a, b]) {
+                        std::mem::swap(first, second);
+                    }
+
+                    let (first, second) = (reference[&a], reference[&b]);
+
+                    reference.insert(a, second);
+                    reference.insert(b, first);
+
+                    assert!(map.get_disjoint_mut([a, a]).is_none());
+                }
+                9 if step % 10 == 0 => {
+                    let modulus = rng.below(4) as u32 + 2;
+
+                    map.retain(|_, value| *value % modulus != 0);
+                    reference.retain(|_, value| *value % modulus != 0);
+
+                    dead.extend(live.iter().filter(|key| !reference.contains_key(key)));
+                    live.retain(|key| reference.contains_key(key));
+                }
+                _ => {
+                    if let Some(&key) = live.first() {
+                        map[key] = value;
+                        reference.insert(key, value);
+                    }
+                }
+            }
+
+            most_live = most_live.max(live.len());
+
+            assert_eq!(map.len(), reference.len());
+            // Freed slots are reused before new ones are added
+            assert!(live.iter().all(|&key| (index(key) as usize) < most_live));
+
+            if step % 50 == 0 {
+                assert!(live.iter().all(|&key| map.get(key) == reference.get(&key)));
+                assert!(dead.iter().all(|&key| !map.contains_key(key)));
+                assert!(map
+                    .iter()
+                    .all(|(key, value)| reference.get(&key) == Some(value)));
+                assert!(map.keys().zip(map.values()).eq(map.iter()));
+            }
+        }
+
+        let clone = map.clone();
+
+        assert!(live.iter().all(|&key| clone.get(key) == map.get(key)));
+
+        map.clear();
+
+        assert!(map.is_empty());
+        assert!(live.iter().all(|&key| !map.contains_key(key)));
+        assert!(clone
+            .into_iter()
+            .all(|(key, value)| reference[&key] == value));
+    }
+}
+
+#[test]
+fn keys_survive_a_round_trip_through_bits() {
+    let mut map: SlotMap<DefaultKey, u32> = SlotMap::new();
+    let first = map.insert(1);
+
+    map.remove(first);
+
+    let second = map.insert(2);
+
+    // Same slot, newer version
+    assert_eq!(index(first), index(second));
+    assert_eq!(version(second), version(first) + 2);
+
+    let restored = DefaultKey::from_data(KeyData::from_bits(second.data().to_bits()));
+
+    assert!(restored == second);
+    assert_eq!(map.get(restored), Some(&2));
+    assert_eq!(map.get(DefaultKey::null()), None);
+    assert_eq!(
+        map.get(DefaultKey::from_data(KeyData::from_bits(u64::MAX))),
+        None
+    );
+}
+
+/// The reference model of a `SecondaryMap`: the newest key and value inserted per slot.
+#[derive(Default)]
+struct Reference(StdMap<u32, (DefaultKey, u32)>);
+
+impl Reference {
+    fn insert(&mut self, key: DefaultKey, value: u32) -> Option<u32> {
+        match self.0.get_mut(&index(key)) {
+            Some((stored, old)) if *stored == key => Some(std::mem::replace(old, value)),
+            Some((stored, _)) if version(*stored) > version(key) => None,
+            _ => {
+                self.0.insert(index(key), (key, value));
+
+                None
+            }
+        }
+    }
+
+    fn get(&self, key: DefaultKey) -> Option<&u32> {
+        match self.0.get(&index(key)) {
+            Some((stored, value)) if *stored == key => Some(value),
+            _ => None,
+        }
+    }
+
+    fn remove(&mut self, key: DefaultKey) -> Option<u32> {
+        let value = self.get(key).copied();
+
+        if value.is_some() {
+            self.0.remove(&index(key));
+        }
+
+        value
+    }
+}
+
+#[test]
+fn secondary_map_keeps_the_newest_key_of_each_slot() {
+    for seed in 1..=8u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut primary: SlotMap<DefaultKey, ()> = SlotMap::new();
+        let mut secondary = SecondaryMap::new();
+        let mut reference = Reference::default();
+        let mut keys: Vec<DefaultKey> = Vec::new();
+
+        for step in 0..5000 {
+            let value = rng.next() as u32;
+
+            match rng.below(8) {
+                0..=1 => keys.push(primary.insert(())),
+                2 if !keys.is_empty() => {
+                    let key = keys[rng.below(keys.len() as u64) as usize];
+
+                    primary.remove(key);
+                }
+                // Keys are picked from every key ever handed out, so many of them are stale
+                3..=5 if !keys.is_empty() => {
+                    let key = keys[rng.below(keys.len() as u64) as usize];
+
+                    assert_eq!(secondary.insert(key, value), reference.insert(key, value));
+                }
+                6 if !keys.is_empty() => {
+                    let key = keys[rng.below(keys.len() as u64) as usize];
+
+                    assert_eq!(secondary.remove(key), reference.remove(key));
+                }
+                7 if step % 20 == 0 => {
+                    secondary.retain(|key, _| primary.contains_key(key));
+                    reference.0.retain(|_, (key, _)| primary.contains_key(*key));
+                }
+                _ => {}
+            }
+
+            assert_eq!(secondary.len(), reference.0.len());
+
+            if step % 50 == 0 {
+                assert!(keys
+                    .iter()
+                    .all(|&key| secondary.get(key) == reference.get(key)));
+                assert!(secondary
+                    .iter()
+                    .all(|(key, value)| reference.get(key) == Some(value)));
+            }
+        }
+
+        assert!(secondary.insert(DefaultKey::null(), 0).is_none());
+        assert_eq!(secondary.len(), reference.0.len());
+    }
+}
+
+/// A value that counts how many of its kind are alive, and panics when `clones_left` runs out.
+struct Counted {
+    live: Rc<Cell<i64>>,
+    clones_left: Rc<Cell<u32>>,
+}
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>, clones_left: &Rc<Cell<u32>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self {
+            live: live.clone(),
+            clones_left: clones_left.clone(),
+        }
+    }
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        let clones_left = self.clones_left.get();
+
+        assert!(clones_left > 0, "Clone failed");
+
+        self.clones_left.set(clones_left - 1);
+
+        Self::new(&self.live, &self.clones_left)
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut rng = Rng(0x0bad_5eed_1234_4321);
+
+    for round in 0..9 {
+        let mut primary: SlotMap<DefaultKey, Counted> = SlotMap::new();
+        let mut secondary = SecondaryMap::new();
+        let mut keys = Vec::new();
+
+        for _ in 0..2000 {
+            match rng.below(6) {
+                0..=2 => keys.push(primary.insert(Counted::new(&live, &clones_left))),
+                3 if !keys.is_empty() => {
+                    let key = keys[rng.below(keys.len() as u64) as usize];
+
+                    drop(primary.remove(key));
+                }
+                // A stale key's value is dropped right away, a newer key's replaces the old one
+                _ if !keys.is_empty() => {
+                    let key = keys[rng.below(keys.len() as u64) as usize];
+
+                    drop(secondary.insert(key, Counted::new(&live, &clones_left)));
+                }
+                _ => {}
+            }
+
+            assert_eq!(live.get(), (primary.len() + secondary.len()) as i64);
+        }
+
+        primary.retain(|_, _| rng.below(2) == 0);
+        secondary.retain(|key, _| primary.contains_key(key));
+
+        assert_eq!(live.get(), (primary.len() + secondary.len()) as i64);
+
+        let clone = primary.clone();
+
+        match round % 3 {
+            0 => {
+                primary.clear();
+                secondary.clear();
+            }
+            1 => {
+                let mut iter = primary.into_iter();
+
+                iter.next();
+                drop(secondary);
+            }
+            _ => drop((primary, secondary)),
+        }
+
+        assert_eq!(live.get(), clone.len() as i64);
+    }
+
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn a_panicking_clone_drops_what_was_cloned() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut primary: SlotMap<DefaultKey, Counted> = SlotMap::new();
+    let mut secondary = SecondaryMap::new();
+
+    for _ in 0..100 {
+        let key = primary.insert(Counted::new(&live, &clones_left));
+
+        secondary.insert(key, Counted::new(&live, &clones_left));
+    }
+
+    clones_left.set(60);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| primary.clone())).is_err());
+    assert_eq!(live.get(), 200);
+
+    clones_left.set(60);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| secondary.clone())).is_err());
+    assert_eq!(live.get(), 200);
+
+    drop((primary, secondary));
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,297 @@
+//! Property tests for `SmallVec` and `ArrayVec`, which run random operations against `Vec` and
+//! check that both always agree, across spilling to the heap and moving back inline.
+
+use std::{
+    cell::Cell,
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use celui_collections::{ArrayVec, SmallVec};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// Returns a random `start..end` within `0..len`.
+    fn range(&mut self, len: usize) -> (usize, usize) {
+        let start = self.below(len as u64 + 1) as usize;
+        let end = start + self.below((len - start) as u64 + 1) as usize;
+
+        (start, end)
+    }
+}
+
+#[test]
+fn small_vec_matches_vec() {
+    for seed in 1..=30u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut small: SmallVec<[u32; 4]> = SmallVec::new();
+        let mut reference: Vec<u32> = Vec::new();
+
+        for _ in 0..2000 {
+            let value = rng.below(8) as u32;
+            let len = reference.len();
+
+            match rng.below(14) {
+                0..=3 => {
+                    small.push(value);
+                    reference.push(value);
+                }
+                4 => assert_eq!(small.pop(), reference.pop()),
+                5 => {
+                    let index = rng.below(len as u64 + 1) as usize;
+
+                    small.insert(index, value);
+                    reference.insert(index, value);
+                }
+                6 if len > 0 => {
+                    let index = rng.below(len as u64) as usize;
+
+                    assert_eq!(small.remove(index), reference.remove(index));
+                }
+                7 if len > 0 => {
+                    let index = rng.below(len as u64) as usize;
+
+                    assert_eq!(small.swap_remove(index), reference.swap_remove(index));
+                }
+                8 => {
+                    small.retain(|&item| item != value);
+                    reference.retain(|&item| item != value);
+                }
+                9 => {
+                    small.dedup();
+                    reference.dedup();
+                }
+                10 => {
+                    let (start, end) = rng.range(len);
+
+                    // Only part of the drained range is taken, the rest is dropped with the iterator
+                    let taken = rng.below((end - start) as u64 + 1) as usize;
+                    let drained: Vec<_> = small.drain(start..end).take(taken).collect();
+
+                    assert_eq!(drained, reference[start..start + taken]);
+                    reference.drain(start..end);
+                }
+                11 => {
+                    let slice = [value, value + 1, value + 2];
+
+                    small.extend_from_slice(&slice);
+                    reference.extend_from_slice(&slice);
+                }
+                12 => {
+                    let new_len = rng.below(len as u64 + 3) as usize;
+
+                    if rng.below(2) == 0 {
+                        small.truncate(new_len);
+                        reference.truncate(new_len);
+                    } else {
+                        small.resize(new_len, value);
+                        reference.resize(new_len, value);
+                    }
+                }
+                _ => {
+                    small.shrink_to_fit();
+
+                    assert_eq!(small.is_inline(), reference.len() <= 4);
+                }
+            }
+
+            assert_eq!(small.as_slice(), reference.as_slice());
+            assert!(small.capacity() >= small.len());
+            assert!(!small.is_inline() || small.capacity() == 4);
+        }
+
+        let split = small.len() / 2;
+        let tail = small.split_off(split);
+
+        assert_eq!(tail.as_slice(), &reference[split..]);
+        assert_eq!(small.as_slice(), &reference[..split]);
+    }
+}
+
+#[test]
+fn array_vec_matches_vec() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut array: ArrayVec<u32, 12> = ArrayVec::new();
+    let mut reference: Vec<u32> = Vec::new();
+
+    for _ in 0..20_000 {
+        let value = rng.below(6) as u32;
+        let len = reference.len();
+
+        match rng.below(8) {
+            0..=2 => match array.try_push(value) {
+                Ok(()) => reference.push(value),
+                Err(error) => {
+                    assert_eq!(len, 12);
+                    assert_eq!(error.into_element(), value);
+                }
+            },
+            3 => {
+                let index = rng.below(len as u64 + 1) as usize;
+
+                match array.try_insert(index, value) {
+                    Ok(()) => reference.insert(index, value),
+                    Err(error) => {
+                        assert_eq!(len, 12);
+                        assert_eq!(*error.element(), value);
+                    }
+                }
+            }
+            4 => assert_eq!(array.pop(), reference.pop()),
+            5 if len > 0 => {
+                let index = rng.below(len as u64) as usize;
+
+                assert_eq!(array.remove(index), reference.remove(index));
+            }
+            6 => {
+                let (start, end) = rng.range(len);
+                // Drained from the back, to cover both ends of the iterator
+                assert!(array
+                    .drain(start..end)
+                    .rev()
+                    .eq(reference.drain(start..end).rev()));
+            }
+            _ => {
+                array.dedup();
+                reference.dedup();
+            }
+        }
+
+        assert_eq!(array.as_slice(), reference.as_slice());
+    }
+}
+
+/// A value that counts how many of its kind are alive, and panics when `clones_left` runs out.
+struct Counted {
+    live: Rc<Cell<i64>>,
+    clones_left: Rc<Cell<u32>>,
+}
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>, clones_left: &Rc<Cell<u32>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self {
+            live: live.clone(),
+            clones_left: clones_left.clone(),
+        }
+    }
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        let clones_left = self.clones_left.get();
+
+        assert!(clones_left > 0, "Clone failed");
+
+        self.clones_left.set(clones_left - 1);
+
+        Self::new(&self.live, &self.clones_left)
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut rng = Rng(0x0bad_5eed_1234_4321);
+
+    for round in 0..30 {
+        let mut small: SmallVec<[Counted; 3]> = SmallVec::new();
+
+        for _ in 0..200 {
+            let len = small.len();
+
+            match rng.below(6) {
+                0..=2 => small.push(Counted::new(&live, &clones_left)),
+                3 => drop(small.pop()),
+                4 => {
+                    let (start, end) = rng.range(len);
+                    let mut drain = small.drain(start..end);
+
+                    drain.next();
+                }
+                _ => {
+                    small.truncate(rng.below(4) as usize);
+                    small.shrink_to_fit();
+                }
+            }
+
+            assert_eq!(live.get(), small.len() as i64);
+        }
+
+        let clone = small.clone();
+
+        assert_eq!(live.get(), 2 * small.len() as i64);
+
+        match round % 3 {
+            0 => small.clear(),
+            1 => {
+                let mut iter = small.into_iter();
+
+                iter.next();
+            }
+            _ => drop(small),
+        }
+
+        assert_eq!(live.get(), clone.len() as i64);
+    }
+
+    assert_eq!(live.get(), 0);
+
+    let mut array: ArrayVec<Counted, 8> = ArrayVec::new();
+
+    while array.try_push(Counted::new(&live, &clones_left)).is_ok() {}
+
+    // The rejected element was handed back and dropped
+    assert_eq!(live.get(), 8);
+
+    drop(array.drain(2..5));
+    assert_eq!(live.get(), 5);
+
+    drop(array);
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn a_panicking_clone_drops_what_was_cloned() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+
+    for count in [2, 10] {
+        let mut small: SmallVec<[Counted; 4]> = SmallVec::new();
+
+        for _ in 0..count {
+            small.push(Counted::new(&live, &clones_left));
+        }
+
+        clones_left.set(count as u32 - 1);
+
+        assert!(catch_unwind(AssertUnwindSafe(|| small.clone())).is_err());
+        assert_eq!(live.get(), count);
+
+        clones_left.set(u32::MAX);
+        drop(small);
+        assert_eq!(live.get(), 0);
+    }
+}
@@ -0,0 +1,68 @@
+//! Tests for grapheme cluster segmentation, against the Unicode rules it implements.
+
+use celui_collections::String;
+
+fn clusters(text: &str) -> Vec<&str> {
+    celui_collections::string::Graphemes::new(text).collect()
+}
+
+#[test]
+fn controls_are_clusters_on_their_own() {
+    // GB3: "\r\n" is a single cluster
+    assert_eq!(clusters("a\r\nb"), ["a", "\r\n", "b"]);
+    assert_eq!(clusters("\n\r"), ["\n", "\r"]);
+
+    // GB4: Nothing extends a control, not even a combining mark or a joiner
+    assert_eq!(clusters("\t\u{0301}"), ["\t", "\u{0301}"]);
+    assert_eq!(clusters("\n\u{200D}"), ["\n", "\u{200D}"]);
+    assert_eq!(clusters("\u{2028}\u{0301}"), ["\u{2028}", "\u{0301}"]);
+
+    // GB5: Controls start a new cluster, even after a joiner
+    assert_eq!(clusters("a\u{200D}\n"), ["a\u{200D}", "\n"]);
+    assert_eq!(clusters("e\u{0301}\r\n"), ["e\u{0301}", "\r\n"]);
+}
+
+#[test]
+fn joiners_only_link_emoji() {
+    // GB11: An emoji, extenders and a joiner attach to the next emoji
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let dark_family = "\u{1F468}\u{1F3FF}\u{200D}\u{1F469}";
+    let rainbow_flag = "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}";
+
+    assert_eq!(clusters(family), [family]);
+    assert_eq!(clusters(dark_family), [dark_family]);
+    assert_eq!(clusters(rainbow_flag), [rainbow_flag]);
+    assert_eq!(
+        clusters("\u{2764}\u{200D}\u{1F525}"),
+        ["\u{2764}\u{200D}\u{1F525}"]
+    );
+
+    // GB9 keeps the joiner in the cluster before it, but GB11 doesn't apply to other chars
+    assert_eq!(clusters("a\u{200D}b"), ["a\u{200D}", "b"]);
+    assert_eq!(clusters("a\u{200D}\u{1F600}"), ["a\u{200D}", "\u{1F600}"]);
+    assert_eq!(clusters("\u{1F600}\u{200D}b"), ["\u{1F600}\u{200D}", "b"]);
+
+    // Only extenders may come between the emoji and the joiner
+    assert_eq!(
+        clusters("\u{1F600}\u{200D}\u{200D}\u{1F600}"),
+        ["\u{1F600}\u{200D}\u{200D}", "\u{1F600}"]
+    );
+    assert_eq!(
+        clusters("\u{1F600}\u{200D}\u{0301}\u{1F600}"),
+        ["\u{1F600}\u{200D}\u{0301}", "\u{1F600}"]
+    );
+}
+
+#[test]
+fn marks_and_flags_stay_together() {
+    assert_eq!(clusters("e\u{0301}a"), ["e\u{0301}", "a"]);
+    assert_eq!(
+        clusters("\u{1F1EB}\u{1F1F7}\u{1F1E9}"),
+        ["\u{1F1EB}\u{1F1F7}", "\u{1F1E9}"]
+    );
+
+    let mut label = String::from("ok\u{1F44D}\u{1F3FD}!");
+
+    label.truncate_graphemes(3);
+    assert!(label == "ok\u{1F44D}\u{1F3FD}");
+}
@@ -0,0 +1,102 @@
+//! Tests for `Vec` behavior that doc examples don't cover.
+
+use celui_collections::Vec;
+
+#[test]
+fn collecting_from_a_huge_size_hint_reserves_only_what_is_yielded() {
+    // Both iterators report an upper bound of `usize::MAX` but stop right away. `take_while` is
+    // used over `filter`, which would have to walk the whole range.
+    let empty: Vec<usize> = (0..usize::MAX).take_while(|_| false).collect();
+
+    assert!(empty.is_empty());
+    assert_eq!(empty.capacity(), 0);
+
+    let few: Vec<usize> = (0..usize::MAX).take_while(|&x| x < 3).collect();
+
+    assert!(few == [0, 1, 2]);
+
+    let hundreds: Vec<usize> = (0..100_000).filter(|x| x % 100 == 0).collect();
+
+    assert_eq!(hundreds.len(), 1000);
+    assert!(hundreds.capacity() < 100_000);
+    assert!(hundreds.iter().copied().eq((0..100_000).step_by(100)));
+
+    let mut extended = few.clone();
+
+    extended.extend((0..usize::MAX).take_while(|_| false));
+
+    assert!(extended == [0, 1, 2]);
+}
+
+#[test]
+fn zero_sized_elements_are_iterated() {
+    let mut units: Vec<()> = Vec::new();
+
+    for _ in 0..5 {
+        units.push(());
+    }
+
+    assert_eq!(units.len(), 5);
+    assert_eq!(units.iter().size_hint(), (5, Some(5)));
+    assert_eq!(units.iter().count(), 5);
+    assert_eq!(units.iter_mut().count(), 5);
+    assert_eq!(units.clone().into_iter().count(), 5);
+
+    let mut iter = units.iter();
+
+    iter.next();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+}
+
+#[test]
+fn clones_drop_what_they_cloned_when_a_clone_panics() {
+    use std::{cell::Cell, rc::Rc};
+
+    /// Counts live values, and panics when the `fail_at`th one is cloned.
+    struct Counted {
+        live: Rc<Cell<usize>>,
+        index: usize,
+        fail_at: usize,
+    }
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            assert!(self.index != self.fail_at, "Clone failed");
+
+            self.live.set(self.live.get() + 1);
+
+            Self {
+                live: self.live.clone(),
+                ..*self
+            }
+        }
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.live.set(self.live.get() - 1);
+        }
+    }
+
+    let live = Rc::new(Cell::new(0));
+    let mut values = Vec::new();
+
+    for index in 0..8 {
+        live.set(live.get() + 1);
+        values.push(Counted {
+            live: live.clone(),
+            index,
+            fail_at: 5,
+        });
+    }
+
+    let clone = values.iter().take(5).cloned().collect::<Vec<_>>().clone();
+
+    assert_eq!(clone.len(), 5);
+    assert_eq!(live.get(), 13);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| values.clone()));
+
+    assert!(result.is_err());
+    assert_eq!(live.get(), 13);
+}
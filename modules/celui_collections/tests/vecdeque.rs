@@ -0,0 +1,412 @@
+//! Property tests for `VecDeque`, `RingBuffer` and `BinaryHeap`, which run random operations
+//! against the standard library's deque and heap and check that both always agree, with the
+//! elements wrapping around the end of the buffer.
+
+use std::{
+    cell::Cell,
+    collections::{BinaryHeap as StdHeap, VecDeque as StdDeque},
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use celui_collections::{BinaryHeap, RingBuffer, VecDeque};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Returns the elements of `deque` joined from its two slices.
+fn joined(deque: &VecDeque<u32>) -> Vec<u32> {
+    let (front, back) = deque.as_slices();
+
+    [front, back].concat()
+}
+
+#[test]
+fn vec_deque_matches_std() {
+    for seed in 1..=20u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut deque = VecDeque::with_capacity(4);
+        let mut reference = StdDeque::new();
+
+        for step in 0..3000 {
+            let value = rng.next() as u32;
+            let len = reference.len();
+
+            match rng.below(16) {
+                0..=2 => {
+                    deque.push_back(value);
+                    reference.push_back(value);
+                }
+                3..=5 => {
+                    deque.push_front(value);
+                    reference.push_front(value);
+                }
+                6 => assert_eq!(deque.pop_front(), reference.pop_front()),
+                7 => assert_eq!(deque.pop_back(), reference.pop_back()),
+                8 => {
+                    let index = rng.below(len as u64 + 1) as usize;
+
+                    deque.insert(index, value);
+                    reference.insert(index, value);
+                }
+                9 => {
+                    let index = rng.below(len as u64 + 1) as usize;
+
+                    assert_eq!(deque.remove(index), reference.remove(index));
+                }
+                10 if len > 0 => {
+                    let (a, b) = (
+                        rng.below(len as u64) as usize,
+                        rng.below(len as u64) as usize,
+                    );
+
+                    deque.swap(a, b);
+                    reference.swap(a, b);
+                }
+                11 => {
+                    let modulus = rng.below(4) as u32 + 2;
+
+                    deque.retain(|value| value % modulus != 0);
+                    reference.retain(|value| value % modulus != 0);
+                }
+                12 => {
+                    let start = rng.below(len as u64 + 1) as usize;
+                    let end = start + rng.below((len - start) as u64 + 1) as usize;
+
+                    // Only the front of the range is taken, the rest is dropped with the iterator
+                    let taken = rng.below((end - start) as u64 + 1) as usize;
+                    let drained: Vec<_> = deque.drain(start..end).take(taken).collect();
+
+                    assert!(drained.iter().eq(reference.range(start..start + taken)));
+                    reference.drain(start..end);
+                }
+                13 => {
+                    let new_len = rng.below(len as u64 + 2) as usize;
+
+                    deque.truncate(new_len);
+                    reference.truncate(new_len);
+                }
+                14 => {
+                    deque.make_contiguous();
+
+                    assert!(deque.as_slices().1.is_empty());
+                }
+                _ => {
+                    deque.shrink_to_fit();
+
+                    assert_eq!(deque.capacity(), deque.len());
+                }
+            }
+
+            assert_eq!(deque.len(), reference.len());
+            assert_eq!(deque.front(), reference.front());
+            assert_eq!(deque.back(), reference.back());
+
+            if step % 20 == 0 {
+                assert!(joined(&deque).iter().eq(reference.iter()));
+                assert!(deque.iter().rev().eq(reference.iter().rev()));
+                assert!((0..deque.len() + 1).all(|index| deque.get(index) == reference.get(index)));
+            }
+        }
+
+        assert!(deque.clone() == deque);
+        assert!(deque.into_iter().eq(reference));
+    }
+}
+
+#[test]
+fn ring_buffer_evicts_like_a_bounded_std_deque() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    let mut ring: RingBuffer<u32, 7> = RingBuffer::new();
+    let mut reference = StdDeque::new();
+
+    for step in 0..20_000 {
+        let value = rng.next() as u32;
+
+        match rng.below(6) {
+            0..=1 => {
+                let evicted = if reference.len() == 7 {
+                    reference.pop_front()
+                } else {
+                    None
+                };
+
+                reference.push_back(value);
+                assert_eq!(ring.push_back(value), evicted);
+            }
+            2..=3 => {
+                let evicted = if reference.len() == 7 {
+                    reference.pop_back()
+                } else {
+                    None
+                };
+
+                reference.push_front(value);
+                assert_eq!(ring.push_front(value), evicted);
+            }
+            4 => assert_eq!(ring.pop_front(), reference.pop_front()),
+            _ => assert_eq!(ring.pop_back(), reference.pop_back()),
+        }
+
+        assert_eq!(ring.len(), reference.len());
+        assert_eq!(ring.is_full(), reference.len() == 7);
+        assert_eq!(ring.front(), reference.front());
+        assert_eq!(ring.back(), reference.back());
+
+        if step % 20 == 0 {
+            let (front, back) = ring.as_slices();
+
+            assert!(front.iter().chain(back).eq(reference.iter()));
+            assert!(ring.iter().rev().eq(reference.iter().rev()));
+            assert!((0..8).all(|index| ring.get(index) == reference.get(index)));
+        }
+    }
+
+    assert!(ring.into_iter().rev().eq(reference.into_iter().rev()));
+}
+
+#[test]
+fn binary_heap_matches_std() {
+    for seed in 1..=10u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut heap = BinaryHeap::new();
+        let mut reference = StdHeap::new();
+
+        for _ in 0..3000 {
+            // Few distinct values, so equal elements meet often
+            let value = rng.below(50) as u32;
+
+            match rng.below(10) {
+                0..=4 => {
+                    heap.push(value);
+                    reference.push(value);
+                }
+                5..=6 => assert_eq!(heap.pop(), reference.pop()),
+                7 => {
+                    // Lowering the greatest element moves it back down into place
+                    if let Some(mut top) = heap.peek_mut() {
+                        *top = value;
+                    }
+
+                    if let Some(mut top) = reference.peek_mut() {
+                        *top = value;
+                    }
+                }
+                8 => {
+                    let modulus = rng.below(5) as u32 + 2;
+
+                    heap.retain(|value| value % modulus != 0);
+                    reference.retain(|value| value % modulus != 0);
+                }
+                _ => {
+                    let mut other: BinaryHeap<u32> = (0..rng.below(8) as u32).collect();
+
+                    reference.extend(other.iter());
+                    heap.append(&mut other);
+
+                    assert!(other.is_empty());
+                }
+            }
+
+            assert_eq!(heap.len(), reference.len());
+            assert_eq!(heap.peek(), reference.peek());
+        }
+
+        assert!(heap.clone().into_sorted_vec() == reference.clone().into_sorted_vec()[..]);
+
+        let mut drained: Vec<_> = heap.drain().collect();
+
+        drained.sort_unstable();
+        assert_eq!(drained, reference.into_sorted_vec());
+        assert!(heap.is_empty());
+    }
+}
+
+/// A value that counts how many of its kind are alive, and panics when `clones_left` runs out.
+struct Counted {
+    live: Rc<Cell<i64>>,
+    clones_left: Rc<Cell<u32>>,
+}
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>, clones_left: &Rc<Cell<u32>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self {
+            live: live.clone(),
+            clones_left: clones_left.clone(),
+        }
+    }
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        let clones_left = self.clones_left.get();
+
+        assert!(clones_left > 0, "Clone failed");
+
+        self.clones_left.set(clones_left - 1);
+
+        Self::new(&self.live, &self.clones_left)
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+/// A `Counted` ordered by its key alone, for the heap.
+#[derive(Clone)]
+struct Keyed {
+    key: u32,
+    _value: Counted,
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut rng = Rng(0x0bad_5eed_1234_4321);
+
+    for round in 0..12 {
+        let mut deque = VecDeque::with_capacity(4);
+        let mut ring: RingBuffer<Counted, 5> = RingBuffer::new();
+
+        for _ in 0..500 {
+            let len = deque.len();
+
+            match rng.below(8) {
+                0..=1 => deque.push_back(Counted::new(&live, &clones_left)),
+                2..=3 => deque.push_front(Counted::new(&live, &clones_left)),
+                4 => drop(deque.pop_front()),
+                5 => drop(deque.remove(rng.below(len as u64 + 1) as usize)),
+                6 => {
+                    let mut drain = deque.drain(len / 3..len / 2);
+
+                    drain.next();
+                }
+                _ => deque.truncate(rng.below(len as u64 + 1) as usize),
+            }
+
+            // Whatever the ring evicts is dropped here
+            drop(ring.push_front(Counted::new(&live, &clones_left)));
+
+            if rng.below(3) == 0 {
+                drop(ring.pop_back());
+            }
+
+            assert_eq!(live.get(), (deque.len() + ring.len()) as i64);
+        }
+
+        let clone = deque.clone();
+
+        assert_eq!(live.get(), (2 * deque.len() + ring.len()) as i64);
+
+        match round % 3 {
+            0 => {
+                deque.clear();
+                ring.clear();
+            }
+            1 => {
+                let mut iter = deque.into_iter();
+                let mut ring_iter = ring.into_iter();
+
+                iter.next_back();
+                ring_iter.next();
+            }
+            _ => drop((deque, ring)),
+        }
+
+        assert_eq!(live.get(), clone.len() as i64);
+    }
+
+    assert_eq!(live.get(), 0);
+
+    let mut heap = BinaryHeap::new();
+
+    for _ in 0..1000 {
+        heap.push(Keyed {
+            key: rng.below(100) as u32,
+            _value: Counted::new(&live, &clones_left),
+        });
+
+        if rng.below(3) == 0 {
+            drop(heap.pop());
+        }
+    }
+
+    heap.retain(|keyed| keyed.key % 3 != 0);
+
+    assert_eq!(live.get(), heap.len() as i64);
+
+    drop(heap.drain().take(5));
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn a_panicking_clone_drops_what_was_cloned() {
+    let live = Rc::new(Cell::new(0));
+    let clones_left = Rc::new(Cell::new(u32::MAX));
+    let mut deque = VecDeque::with_capacity(8);
+
+    // Pushed on both ends, so the elements wrap around the buffer
+    for _ in 0..40 {
+        deque.push_back(Counted::new(&live, &clones_left));
+        deque.push_front(Counted::new(&live, &clones_left));
+    }
+
+    clones_left.set(50);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| deque.clone())).is_err());
+    assert_eq!(live.get(), 80);
+
+    let mut ring: RingBuffer<Counted, 6> = RingBuffer::new();
+
+    for _ in 0..9 {
+        drop(ring.push_back(Counted::new(&live, &clones_left)));
+    }
+
+    clones_left.set(3);
+
+    assert!(catch_unwind(AssertUnwindSafe(|| ring.clone())).is_err());
+    assert_eq!(live.get(), 86);
+
+    drop((deque, ring));
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,245 @@
+// ------------------------------- blend.rs -------------------------------- //
+
+//! Compositing operators and blend modes.
+//!
+//! Defines the Porter-Duff compositing operators (`CompositeOp`) and the
+//! separable blend modes (`BlendMode`) of the W3C Compositing and Blending spec.
+//! `ColorF` methods work on premultiplied components, while `Color` methods take
+//! straight-alpha sRGB colors and do the math in linear light.
+
+use crate::{float, Color, ColorF};
+
+// ----------------------------- CompositeOp ------------------------------- //
+
+/// A Porter-Duff compositing operator, describing how a source is combined with a destination.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompositeOp {
+    /// Neither the source nor the destination is shown.
+    Clear,
+    /// Only the source is shown.
+    Src,
+    /// Only the destination is shown.
+    Dst,
+    /// The source is placed over the destination.
+    #[default]
+    SrcOver,
+    /// The destination is placed over the source.
+    DstOver,
+    /// The part of the source inside the destination replaces the destination.
+    SrcIn,
+    /// The part of the destination inside the source replaces the source.
+    DstIn,
+    /// The part of the source outside the destination replaces the destination.
+    SrcOut,
+    /// The part of the destination outside the source replaces the source.
+    DstOut,
+    /// The part of the source inside the destination is placed over the destination.
+    SrcAtop,
+    /// The part of the destination inside the source is placed over the source.
+    DstAtop,
+    /// The non-overlapping parts of the source and the destination are shown.
+    Xor,
+    /// The source and the destination are added together.
+    Plus,
+}
+
+impl CompositeOp {
+    /// Returns the `(source, destination)` coverage factors for the given alphas.
+    #[inline]
+    pub fn factors(self, src_alpha: f32, dst_alpha: f32) -> (f32, f32) {
+        match self {
+            Self::Clear => (0.0, 0.0),
+            Self::Src => (1.0, 0.0),
+            Self::Dst => (0.0, 1.0),
+            Self::SrcOver => (1.0, 1.0 - src_alpha),
+            Self::DstOver => (1.0 - dst_alpha, 1.0),
+            Self::SrcIn => (dst_alpha, 0.0),
+            Self::DstIn => (0.0, src_alpha),
+            Self::SrcOut => (1.0 - dst_alpha, 0.0),
+            Self::DstOut => (0.0, 1.0 - src_alpha),
+            Self::SrcAtop => (dst_alpha, 1.0 - src_alpha),
+            Self::DstAtop => (1.0 - dst_alpha, src_alpha),
+            Self::Xor => (1.0 - dst_alpha, 1.0 - src_alpha),
+            Self::Plus => (1.0, 1.0),
+        }
+    }
+}
+
+// ------------------------------ BlendMode -------------------------------- //
+
+/// A separable blend mode, describing how source and backdrop colors are mixed where they overlap.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The source color replaces the backdrop.
+    #[default]
+    Normal,
+    /// Multiplies the colors, always darkening.
+    Multiply,
+    /// Inverse of multiplying the inverted colors, always lightening.
+    Screen,
+    /// Multiplies or screens depending on the backdrop.
+    Overlay,
+    /// Keeps the darker of the colors.
+    Darken,
+    /// Keeps the lighter of the colors.
+    Lighten,
+    /// Brightens the backdrop to reflect the source.
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source.
+    ColorBurn,
+    /// Multiplies or screens depending on the source.
+    HardLight,
+    /// Darkens or lightens depending on the source, like a diffused spotlight.
+    SoftLight,
+    /// Subtracts the darker of the colors from the lighter one.
+    Difference,
+    /// Like `Difference`, but with lower contrast.
+    Exclusion,
+}
+
+impl BlendMode {
+    /// Blends a single straight (non-premultiplied) component of the backdrop and the source.
+    #[inline]
+    pub fn apply(self, backdrop: f32, source: f32) -> f32 {
+        let (b, s) = (backdrop, source);
+
+        match self {
+            Self::Normal => s,
+            Self::Multiply => b * s,
+            Self::Screen => b + s - b * s,
+            Self::Overlay => Self::HardLight.apply(s, b),
+            Self::Darken => b.min(s),
+            Self::Lighten => b.max(s),
+            Self::ColorDodge => {
+                if b == 0.0 {
+                    0.0
+                } else if s >= 1.0 {
+                    1.0
+                } else {
+                    (b / (1.0 - s)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if b >= 1.0 {
+                    1.0
+                } else if s <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - b) / s).min(1.0)
+                }
+            }
+            Self::HardLight => {
+                if s <= 0.5 {
+                    b * 2.0 * s
+                } else {
+                    Self::Screen.apply(b, 2.0 * s - 1.0)
+                }
+            }
+            Self::SoftLight => {
+                if s <= 0.5 {
+                    b - (1.0 - 2.0 * s) * b * (1.0 - b)
+                } else {
+                    let d = if b <= 0.25 {
+                        ((16.0 * b - 12.0) * b + 4.0) * b
+                    } else {
+                        float::sqrt(b)
+                    };
+
+                    b + (2.0 * s - 1.0) * (d - b)
+                }
+            }
+            Self::Difference => (b - s).abs(),
+            Self::Exclusion => b + s - 2.0 * b * s,
+        }
+    }
+}
+
+// -------------------------------- ColorF --------------------------------- //
+
+impl ColorF {
+    /// Composites `self` (the source) with `dst` using a Porter-Duff operator.
+    ///
+    /// Both colors and the result are premultiplied.
+    #[inline]
+    pub fn composite(self, dst: Self, op: CompositeOp) -> Self {
+        let (fs, fd) = op.factors(self.a, dst.a);
+
+        let result = Self::new(
+            self.r * fs + dst.r * fd,
+            self.g * fs + dst.g * fd,
+            self.b * fs + dst.b * fd,
+            self.a * fs + dst.a * fd,
+        );
+
+        if op == CompositeOp::Plus {
+            result.clamp()
+        } else {
+            result
+        }
+    }
+
+    /// Blends `self` (the source) over `backdrop` using a blend mode, then composites with `SrcOver`.
+    ///
+    /// Both colors and the result are premultiplied.
+    #[inline]
+    pub fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        if mode == BlendMode::Normal {
+            return self.composite(backdrop, CompositeOp::SrcOver);
+        }
+
+        let (sa, ba) = (self.a, backdrop.a);
+        let s = self.unpremultiply();
+        let b = backdrop.unpremultiply();
+
+        // Premultiplied form of `cs' = (1 - ab) * cs + ab * B(cb, cs)` composited with `SrcOver`
+        let mix = |cs: f32, cb: f32, bs: f32, bb: f32| {
+            cs * (1.0 - ba) + cb * (1.0 - sa) + sa * ba * mode.apply(bb, bs)
+        };
+
+        Self::new(
+            mix(self.r, backdrop.r, s.r, b.r),
+            mix(self.g, backdrop.g, s.g, b.g),
+            mix(self.b, backdrop.b, s.b, b.b),
+            sa + ba * (1.0 - sa),
+        )
+    }
+}
+
+// -------------------------------- Color ---------------------------------- //
+
+impl Color {
+    /// Composites `self` (the source) with `dst` using a Porter-Duff operator, in linear light.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_math::{Color, CompositeOp};
+    ///
+    /// let src = Color::RED.with_alpha(0);
+    /// assert!(src.composite(Color::BLUE, CompositeOp::SrcOver) == Color::BLUE);
+    /// assert!(Color::RED.composite(Color::BLUE, CompositeOp::DstOut) == Color::TRANSPARENT);
+    /// ```
+    #[inline]
+    pub fn composite(self, dst: Self, op: CompositeOp) -> Self {
+        let src = self.to_linear().premultiply();
+        let dst = dst.to_linear().premultiply();
+
+        Self::from_linear(src.composite(dst, op).unpremultiply())
+    }
+
+    /// Blends `self` (the source) over `backdrop` using a blend mode, in linear light.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_math::{BlendMode, Color};
+    ///
+    /// assert!(Color::WHITE.blend(Color::RED, BlendMode::Multiply) == Color::RED);
+    /// assert!(Color::BLACK.blend(Color::RED, BlendMode::Screen) == Color::RED);
+    /// ```
+    #[inline]
+    pub fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        let src = self.to_linear().premultiply();
+        let backdrop = backdrop.to_linear().premultiply();
+
+        Self::from_linear(src.blend(backdrop, mode).unpremultiply())
+    }
+}
@@ -4,6 +4,12 @@
 //!
 //! This module defines structures and functions for working with colors,
 //! including different color formats (e.g., RGB, HSL, HEX) and related operations.
+//!
+//! `Color` is an 8-bit sRGB color with straight (non-premultiplied) alpha, and
+//! `ColorF` is its floating-point counterpart for blending math. `Hsl`, `Hsv`,
+//! `Oklab` and `Oklch` convert to and from `Color` through `From`.
+
+use crate::float;
 
 // -------------------------------- Color ---------------------------------- //
 
@@ -25,12 +31,554 @@ impl Color {
     pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b, a: 255 }
     }
+
+    /// Creates an opaque color from a `0xRRGGBB` value.
+    #[inline(always)]
+    pub const fn from_rgb_u32(rgb: u32) -> Self {
+        Self::from_rgba_u32((rgb << 8) | 0xFF)
+    }
+
+    /// Creates a color from a `0xRRGGBBAA` value.
+    #[inline(always)]
+    pub const fn from_rgba_u32(rgba: u32) -> Self {
+        Self {
+            r: (rgba >> 24) as u8,
+            g: (rgba >> 16) as u8,
+            b: (rgba >> 8) as u8,
+            a: rgba as u8,
+        }
+    }
+
+    /// Returns the color packed as a `0xRRGGBBAA` value.
+    #[inline(always)]
+    pub const fn to_rgba_u32(self) -> u32 {
+        ((self.r as u32) << 24) | ((self.g as u32) << 16) | ((self.b as u32) << 8) | self.a as u32
+    }
+
+    /// Parses a hex color string in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` format.
+    ///
+    /// The leading `#` is optional. Returns `None` if the string is not a valid hex color.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_math::Color;
+    ///
+    /// assert!(Color::from_hex("#f80") == Some(Color::from_rgb(0xFF, 0x88, 0x00)));
+    /// assert!(Color::from_hex("10203040") == Some(Color::new(0x10, 0x20, 0x30, 0x40)));
+    /// assert!(Color::from_hex("#12345").is_none());
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex).as_bytes();
+
+        let mut value: u32 = 0;
+
+        for &digit in digits {
+            let nibble = match digit {
+                b'0'..=b'9' => digit - b'0',
+                b'a'..=b'f' => digit - b'a' + 10,
+                b'A'..=b'F' => digit - b'A' + 10,
+                _ => return None,
+            };
+
+            value = (value << 4) | nibble as u32;
+        }
+
+        // Expands a 4-bit channel to 8 bits (`0xA` -> `0xAA`)
+        let expand = |nibble: u32| (nibble & 0xF) as u8 * 0x11;
+
+        match digits.len() {
+            3 => Some(Self::from_rgb(
+                expand(value >> 8),
+                expand(value >> 4),
+                expand(value),
+            )),
+            4 => Some(Self::new(
+                expand(value >> 12),
+                expand(value >> 8),
+                expand(value >> 4),
+                expand(value),
+            )),
+            6 => Some(Self::from_rgb_u32(value)),
+            8 => Some(Self::from_rgba_u32(value)),
+            _ => None,
+        }
+    }
+
+    /// Returns the same color with its alpha replaced by `a`.
+    #[inline(always)]
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Converts the sRGB-encoded color into a `ColorF` with linear-light components.
+    #[inline]
+    pub fn to_linear(self) -> ColorF {
+        ColorF::from(self).to_linear()
+    }
+
+    /// Creates an sRGB-encoded color from a `ColorF` with linear-light components.
+    #[inline]
+    pub fn from_linear(color: ColorF) -> Self {
+        Self::from(color.to_srgb())
+    }
+
+    /// Returns the color with its RGB components multiplied by its alpha.
+    #[inline]
+    pub fn premultiply(self) -> Self {
+        let a = self.a as u16;
+
+        // Exact `x * a / 255` with rounding, without a division
+        let mul = |x: u8| {
+            let t = x as u16 * a + 128;
+
+            ((t + (t >> 8)) >> 8) as u8
+        };
+
+        Self::new(mul(self.r), mul(self.g), mul(self.b), self.a)
+    }
+
+    /// Returns the straight-alpha color of a premultiplied color.
+    #[inline]
+    pub fn unpremultiply(self) -> Self {
+        if self.a == 0 {
+            return Self::TRANSPARENT;
+        }
+
+        let a = self.a as u16;
+        let div = |x: u8| ((x as u16 * 255 + a / 2) / a).min(255) as u8;
+
+        Self::new(div(self.r), div(self.g), div(self.b), self.a)
+    }
+
+    /// Linearly interpolates each sRGB component between `self` and `other`.
+    ///
+    /// This is the cheapest interpolation, but not perceptually uniform; see `mix`.
+    #[inline]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let lerp = |a: u8, b: u8| float::round(a as f32 + (b as f32 - a as f32) * t) as u8;
+
+        Self::new(
+            lerp(self.r, other.r),
+            lerp(self.g, other.g),
+            lerp(self.b, other.b),
+            lerp(self.a, other.a),
+        )
+    }
+
+    /// Perceptually interpolates between `self` and `other` in the Oklab color space.
+    ///
+    /// Produces smooth gradients without the muddy midpoints of `lerp`.
+    #[inline]
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let from = Oklab::from(self);
+        let to = Oklab::from(other);
+
+        Self::from(Oklab::new(
+            from.l + (to.l - from.l) * t,
+            from.a + (to.a - from.a) * t,
+            from.b + (to.b - from.b) * t,
+            from.alpha + (to.alpha - from.alpha) * t,
+        ))
+    }
 }
 
 impl Color {
+    pub const TRANSPARENT: Self = Self::new(0, 0, 0, 0);
     pub const BLACK: Self = Self::from_rgb(0, 0, 0);
     pub const WHITE: Self = Self::from_rgb(255, 255, 255);
     pub const RED: Self = Self::from_rgb(255, 0, 0);
     pub const GREEN: Self = Self::from_rgb(0, 255, 0);
     pub const BLUE: Self = Self::from_rgb(0, 0, 255);
 }
+
+impl core::fmt::LowerHex for Color {
+    /// Formats the color as `#rrggbbaa`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "#{:08x}", self.to_rgba_u32())
+    }
+}
+
+impl core::fmt::UpperHex for Color {
+    /// Formats the color as `#RRGGBBAA`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "#{:08X}", self.to_rgba_u32())
+    }
+}
+
+impl From<ColorF> for Color {
+    /// Converts each component from `[0.0, 1.0]` to `[0, 255]`, clamping out-of-range values.
+    #[inline]
+    fn from(color: ColorF) -> Self {
+        let quantize = |x: f32| float::round(x.clamp(0.0, 1.0) * 255.0) as u8;
+
+        Self::new(
+            quantize(color.r),
+            quantize(color.g),
+            quantize(color.b),
+            quantize(color.a),
+        )
+    }
+}
+
+// -------------------------------- ColorF --------------------------------- //
+
+/// A floating-point RGBA color, with components nominally in `[0.0, 1.0]`.
+///
+/// `ColorF` doesn't track its encoding: converting from `Color` yields sRGB-encoded
+/// components, and `to_linear`/`to_srgb` switch between encodings. Blending and
+/// compositing math should be done on linear-light components.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ColorF {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl ColorF {
+    #[inline(always)]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    #[inline(always)]
+    pub const fn from_rgb(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// Converts sRGB-encoded components to linear-light components; alpha is unchanged.
+    #[inline]
+    pub fn to_linear(self) -> Self {
+        Self::new(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+            self.a,
+        )
+    }
+
+    /// Converts linear-light components to sRGB-encoded components; alpha is unchanged.
+    #[inline]
+    pub fn to_srgb(self) -> Self {
+        Self::new(
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+            self.a,
+        )
+    }
+
+    /// Returns the color with its RGB components multiplied by its alpha.
+    #[inline]
+    pub fn premultiply(self) -> Self {
+        Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Returns the straight-alpha color of a premultiplied color.
+    #[inline]
+    pub fn unpremultiply(self) -> Self {
+        if self.a == 0.0 {
+            return Self::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        Self::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+    /// Clamps every component to `[0.0, 1.0]`.
+    #[inline]
+    pub fn clamp(self) -> Self {
+        Self::new(
+            self.r.clamp(0.0, 1.0),
+            self.g.clamp(0.0, 1.0),
+            self.b.clamp(0.0, 1.0),
+            self.a.clamp(0.0, 1.0),
+        )
+    }
+
+    /// Linearly interpolates each component between `self` and `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+            self.a + (other.a - self.a) * t,
+        )
+    }
+}
+
+impl From<Color> for ColorF {
+    /// Converts each component from `[0, 255]` to `[0.0, 1.0]`, keeping the sRGB encoding.
+    #[inline]
+    fn from(color: Color) -> Self {
+        Self::new(
+            color.r as f32 / 255.0,
+            color.g as f32 / 255.0,
+            color.b as f32 / 255.0,
+            color.a as f32 / 255.0,
+        )
+    }
+}
+
+// ------------------------------- Transfer -------------------------------- //
+
+/// Converts an sRGB-encoded component in `[0.0, 1.0]` to linear light.
+#[inline]
+pub fn srgb_to_linear(x: f32) -> f32 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        float::powf((x + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a linear-light component in `[0.0, 1.0]` to the sRGB encoding.
+#[inline]
+pub fn linear_to_srgb(x: f32) -> f32 {
+    if x <= 0.003_130_8 {
+        x * 12.92
+    } else {
+        1.055 * float::powf(x, 1.0 / 2.4) - 0.055
+    }
+}
+
+// --------------------------------- Hsl ----------------------------------- //
+
+/// A color in the HSL (hue, saturation, lightness) model of the sRGB space.
+///
+/// `h` is in degrees `[0.0, 360.0)`; `s`, `l` and `a` are in `[0.0, 1.0]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub a: f32,
+}
+
+impl Hsl {
+    #[inline(always)]
+    pub const fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let ColorF { r, g, b, a } = ColorF::from(color);
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) * 0.5;
+
+        if delta == 0.0 {
+            return Self::new(0.0, 0.0, l, a);
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+
+        Self::new(hue(r, g, b, max, delta), s, l, a)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        let s = hsl.s.clamp(0.0, 1.0);
+        let l = hsl.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+
+        let (r, g, b) = from_hue(hsl.h, chroma, l - chroma * 0.5);
+
+        Self::from(ColorF::new(r, g, b, hsl.a))
+    }
+}
+
+// --------------------------------- Hsv ----------------------------------- //
+
+/// A color in the HSV (hue, saturation, value) model of the sRGB space.
+///
+/// `h` is in degrees `[0.0, 360.0)`; `s`, `v` and `a` are in `[0.0, 1.0]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+impl Hsv {
+    #[inline(always)]
+    pub const fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self { h, s, v, a }
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        let ColorF { r, g, b, a } = ColorF::from(color);
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        if delta == 0.0 {
+            return Self::new(0.0, 0.0, max, a);
+        }
+
+        Self::new(hue(r, g, b, max, delta), delta / max, max, a)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        let s = hsv.s.clamp(0.0, 1.0);
+        let v = hsv.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+
+        let (r, g, b) = from_hue(hsv.h, chroma, v - chroma);
+
+        Self::from(ColorF::new(r, g, b, hsv.a))
+    }
+}
+
+/// Returns the hue in degrees of an RGB color, given its maximum component and chroma.
+#[inline]
+fn hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+    let sector = if max == r {
+        float::rem_euclid((g - b) / delta, 6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    sector * 60.0
+}
+
+/// Returns the RGB components of a hue in degrees with the given chroma and lightness offset.
+#[inline]
+fn from_hue(h: f32, chroma: f32, offset: f32) -> (f32, f32, f32) {
+    let sector = float::rem_euclid(h, 360.0) / 60.0;
+    let x = chroma * (1.0 - (float::rem_euclid(sector, 2.0) - 1.0).abs());
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    (r + offset, g + offset, b + offset)
+}
+
+// -------------------------------- Oklab ---------------------------------- //
+
+/// A color in the perceptually uniform Oklab space.
+///
+/// `l` (lightness) is in `[0.0, 1.0]`; `a` and `b` are roughly in `[-0.4, 0.4]`.
+/// `alpha` is the straight alpha in `[0.0, 1.0]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl Oklab {
+    #[inline(always)]
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        let ColorF { r, g, b, a: alpha } = color.to_linear();
+
+        let l = float::cbrt(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+        let m = float::cbrt(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = float::cbrt(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+
+        Self::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        )
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(lab: Oklab) -> Self {
+        let l = lab.l + 0.396_337_78 * lab.a + 0.215_803_76 * lab.b;
+        let m = lab.l - 0.105_561_346 * lab.a - 0.063_854_17 * lab.b;
+        let s = lab.l - 0.089_484_18 * lab.a - 1.291_485_5 * lab.b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        Self::from_linear(
+            ColorF::new(
+                4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+                -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+                -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+                lab.alpha,
+            )
+            .clamp(),
+        )
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let (sin, cos) = float::sin_cos(lch.h.to_radians());
+
+        Self::new(lch.l, lch.c * cos, lch.c * sin, lch.alpha)
+    }
+}
+
+// -------------------------------- Oklch ---------------------------------- //
+
+/// A color in the cylindrical form of the Oklab space (lightness, chroma, hue).
+///
+/// `l` is in `[0.0, 1.0]`, `c` is roughly in `[0.0, 0.4]` and `h` is in degrees `[0.0, 360.0)`.
+/// `alpha` is the straight alpha in `[0.0, 1.0]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: f32,
+}
+
+impl Oklch {
+    #[inline(always)]
+    pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let c = float::sqrt(lab.a * lab.a + lab.b * lab.b);
+        let h = float::rem_euclid(float::atan2(lab.b, lab.a).to_degrees(), 360.0);
+
+        Self::new(lab.l, c, h, lab.alpha)
+    }
+}
+
+impl From<Color> for Oklch {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Self::from(Oklab::from(color))
+    }
+}
+
+impl From<Oklch> for Color {
+    #[inline]
+    fn from(lch: Oklch) -> Self {
+        Self::from(Oklab::from(lch))
+    }
+}
@@ -0,0 +1,403 @@
+// ------------------------------- curve.rs -------------------------------- //
+
+//! Bézier curve geometry.
+//!
+//! Defines quadratic (`QuadBezier`) and cubic (`CubicBezier`) Bézier curves with
+//! evaluation, derivatives, splitting, tight bounding boxes, arc-length
+//! approximation, nearest-point queries and adaptive flattening to polylines.
+//! Curves are parametrized by `t` in `[0.0, 1.0]`.
+
+use crate::{easing::Lerp, float, Rect, Vec2};
+
+/// Maximum subdivision depth for the adaptive algorithms.
+const MAX_DEPTH: u32 = 16;
+
+/// Number of samples used to seed the nearest-point search.
+const NEAREST_SAMPLES: usize = 16;
+
+// ------------------------------ QuadBezier ------------------------------- //
+
+/// A quadratic Bézier curve from `p0` to `p2`, with control point `p1`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct QuadBezier {
+    pub p0: Vec2,
+    pub p1: Vec2,
+    pub p2: Vec2,
+}
+
+impl QuadBezier {
+    #[inline(always)]
+    pub const fn new(p0: Vec2, p1: Vec2, p2: Vec2) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    /// Returns the point on the curve at `t`.
+    #[inline]
+    pub fn eval(&self, t: f32) -> Vec2 {
+        let mt = 1.0 - t;
+
+        self.p0 * (mt * mt) + self.p1 * (2.0 * mt * t) + self.p2 * (t * t)
+    }
+
+    /// Returns the first derivative (the unnormalized tangent) at `t`.
+    #[inline]
+    pub fn derivative(&self, t: f32) -> Vec2 {
+        ((self.p1 - self.p0) * (1.0 - t) + (self.p2 - self.p1) * t) * 2.0
+    }
+
+    /// Returns the second derivative, which is constant for quadratic curves.
+    #[inline]
+    pub fn second_derivative(&self) -> Vec2 {
+        (self.p0 - self.p1 * 2.0 + self.p2) * 2.0
+    }
+
+    /// Splits the curve at `t` into two curves covering `[0, t]` and `[t, 1]`.
+    #[inline]
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = self.p0.lerp(self.p1, t);
+        let b = self.p1.lerp(self.p2, t);
+        let mid = a.lerp(b, t);
+
+        (Self::new(self.p0, a, mid), Self::new(mid, b, self.p2))
+    }
+
+    /// Returns the smallest rectangle containing the curve.
+    pub fn bounds(&self) -> Rect {
+        let mut min = self.p0.min(self.p2);
+        let mut max = self.p0.max(self.p2);
+
+        // The extrema lie where the derivative of a coordinate is zero
+        let denominator = self.p0 - self.p1 * 2.0 + self.p2;
+
+        for t in [
+            (self.p0.x - self.p1.x) / denominator.x,
+            (self.p0.y - self.p1.y) / denominator.y,
+        ] {
+            if t > 0.0 && t < 1.0 {
+                let point = self.eval(t);
+
+                min = min.min(point);
+                max = max.max(point);
+            }
+        }
+
+        Rect::from_min_max(min, max)
+    }
+
+    /// Approximates the length of the curve, to within roughly `tolerance`.
+    pub fn arc_length(&self, tolerance: f32) -> f32 {
+        fn length(curve: &QuadBezier, tolerance: f32, depth: u32) -> f32 {
+            let chord = curve.p0.distance(curve.p2);
+            let polygon = curve.p0.distance(curve.p1) + curve.p1.distance(curve.p2);
+
+            // The arc lies between the chord and the control polygon (Gravesen's estimate)
+            if polygon - chord <= tolerance || depth >= MAX_DEPTH {
+                return (2.0 * chord + polygon) / 3.0;
+            }
+
+            let (left, right) = curve.split(0.5);
+
+            length(&left, tolerance * 0.5, depth + 1) + length(&right, tolerance * 0.5, depth + 1)
+        }
+
+        length(self, tolerance, 0)
+    }
+
+    /// Returns the parameter `t` and the point on the curve nearest to `point`.
+    #[inline]
+    pub fn nearest(&self, point: Vec2) -> (f32, Vec2) {
+        nearest(
+            point,
+            |t| self.eval(t),
+            |t| self.derivative(t),
+            |_| self.second_derivative(),
+        )
+    }
+
+    /// Approximates the curve with line segments, deviating from it by at most `tolerance`.
+    ///
+    /// `callback` receives each polyline vertex after `p0`, ending with `p2`.
+    pub fn flatten(&self, tolerance: f32, mut callback: impl FnMut(Vec2)) {
+        fn subdivide(
+            curve: &QuadBezier,
+            tolerance: f32,
+            depth: u32,
+            callback: &mut impl FnMut(Vec2),
+        ) {
+            // The largest distance between the curve and its chord
+            let deviation = (curve.p0 - curve.p1 * 2.0 + curve.p2).length() * 0.25;
+
+            if deviation <= tolerance || depth >= MAX_DEPTH {
+                callback(curve.p2);
+
+                return;
+            }
+
+            let (left, right) = curve.split(0.5);
+
+            subdivide(&left, tolerance, depth + 1, callback);
+            subdivide(&right, tolerance, depth + 1, callback);
+        }
+
+        subdivide(self, tolerance, 0, &mut callback);
+    }
+
+    /// Returns the equivalent cubic curve.
+    #[inline]
+    pub fn to_cubic(&self) -> CubicBezier {
+        CubicBezier::new(
+            self.p0,
+            self.p0.lerp(self.p1, 2.0 / 3.0),
+            self.p2.lerp(self.p1, 2.0 / 3.0),
+            self.p2,
+        )
+    }
+}
+
+// ------------------------------ CubicBezier ------------------------------ //
+
+/// A cubic Bézier curve from `p0` to `p3`, with control points `p1` and `p2`.
+///
+/// # Example
+/// ```
+/// use celui_math::{CubicBezier, Vec2};
+///
+/// let curve = CubicBezier::new(
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(0.0, 100.0),
+///     Vec2::new(100.0, 100.0),
+///     Vec2::new(100.0, 0.0),
+/// );
+///
+/// assert!(curve.eval(0.5) == Vec2::new(50.0, 75.0));
+///
+/// let mut points = 0;
+/// curve.flatten(0.25, |_| points += 1);
+/// assert!(points > 4);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub p0: Vec2,
+    pub p1: Vec2,
+    pub p2: Vec2,
+    pub p3: Vec2,
+}
+
+impl CubicBezier {
+    #[inline(always)]
+    pub const fn new(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    /// Returns the point on the curve at `t`.
+    #[inline]
+    pub fn eval(&self, t: f32) -> Vec2 {
+        let mt = 1.0 - t;
+        let (mt2, t2) = (mt * mt, t * t);
+
+        self.p0 * (mt2 * mt)
+            + self.p1 * (3.0 * mt2 * t)
+            + self.p2 * (3.0 * mt * t2)
+            + self.p3 * (t2 * t)
+    }
+
+    /// Returns the first derivative (the unnormalized tangent) at `t`.
+    #[inline]
+    pub fn derivative(&self, t: f32) -> Vec2 {
+        let mt = 1.0 - t;
+
+        ((self.p1 - self.p0) * (mt * mt)
+            + (self.p2 - self.p1) * (2.0 * mt * t)
+            + (self.p3 - self.p2) * (t * t))
+            * 3.0
+    }
+
+    /// Returns the second derivative at `t`.
+    #[inline]
+    pub fn second_derivative(&self, t: f32) -> Vec2 {
+        ((self.p0 - self.p1 * 2.0 + self.p2) * (1.0 - t) + (self.p1 - self.p2 * 2.0 + self.p3) * t)
+            * 6.0
+    }
+
+    /// Splits the curve at `t` into two curves covering `[0, t]` and `[t, 1]`.
+    #[inline]
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = self.p0.lerp(self.p1, t);
+        let b = self.p1.lerp(self.p2, t);
+        let c = self.p2.lerp(self.p3, t);
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let mid = ab.lerp(bc, t);
+
+        (
+            Self::new(self.p0, a, ab, mid),
+            Self::new(mid, bc, c, self.p3),
+        )
+    }
+
+    /// Returns the smallest rectangle containing the curve.
+    pub fn bounds(&self) -> Rect {
+        let mut min = self.p0.min(self.p3);
+        let mut max = self.p0.max(self.p3);
+
+        // The derivative of each coordinate is the quadratic `a * t^2 + b * t + c`
+        let a = self.p3 - self.p0 + (self.p1 - self.p2) * 3.0;
+        let b = (self.p0 - self.p1 * 2.0 + self.p2) * 2.0;
+        let c = self.p1 - self.p0;
+
+        for (a, b, c) in [(a.x, b.x, c.x), (a.y, b.y, c.y)] {
+            for t in quadratic_roots(a, b, c).into_iter().flatten() {
+                if t > 0.0 && t < 1.0 {
+                    let point = self.eval(t);
+
+                    min = min.min(point);
+                    max = max.max(point);
+                }
+            }
+        }
+
+        Rect::from_min_max(min, max)
+    }
+
+    /// Approximates the length of the curve, to within roughly `tolerance`.
+    pub fn arc_length(&self, tolerance: f32) -> f32 {
+        fn length(curve: &CubicBezier, tolerance: f32, depth: u32) -> f32 {
+            let chord = curve.p0.distance(curve.p3);
+            let polygon = curve.p0.distance(curve.p1)
+                + curve.p1.distance(curve.p2)
+                + curve.p2.distance(curve.p3);
+
+            // The arc lies between the chord and the control polygon (Gravesen's estimate)
+            if polygon - chord <= tolerance || depth >= MAX_DEPTH {
+                return (chord + polygon) * 0.5;
+            }
+
+            let (left, right) = curve.split(0.5);
+
+            length(&left, tolerance * 0.5, depth + 1) + length(&right, tolerance * 0.5, depth + 1)
+        }
+
+        length(self, tolerance, 0)
+    }
+
+    /// Returns the parameter `t` and the point on the curve nearest to `point`.
+    #[inline]
+    pub fn nearest(&self, point: Vec2) -> (f32, Vec2) {
+        nearest(
+            point,
+            |t| self.eval(t),
+            |t| self.derivative(t),
+            |t| self.second_derivative(t),
+        )
+    }
+
+    /// Approximates the curve with line segments, deviating from it by at most `tolerance`.
+    ///
+    /// `callback` receives each polyline vertex after `p0`, ending with `p3`.
+    pub fn flatten(&self, tolerance: f32, mut callback: impl FnMut(Vec2)) {
+        fn subdivide(
+            curve: &CubicBezier,
+            tolerance: f32,
+            depth: u32,
+            callback: &mut impl FnMut(Vec2),
+        ) {
+            // Bounds the distance between the curve and its chord (Roger Willcocks' flatness test)
+            let u = curve.p1 * 3.0 - curve.p0 * 2.0 - curve.p3;
+            let v = curve.p2 * 3.0 - curve.p3 * 2.0 - curve.p0;
+            let flatness = (u.x * u.x).max(v.x * v.x) + (u.y * u.y).max(v.y * v.y);
+
+            if flatness <= 16.0 * tolerance * tolerance || depth >= MAX_DEPTH {
+                callback(curve.p3);
+
+                return;
+            }
+
+            let (left, right) = curve.split(0.5);
+
+            subdivide(&left, tolerance, depth + 1, callback);
+            subdivide(&right, tolerance, depth + 1, callback);
+        }
+
+        subdivide(self, tolerance, 0, &mut callback);
+    }
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Returns the real roots of `a * t^2 + b * t + c`, degrading to a linear equation if `a` is zero.
+#[inline]
+fn quadratic_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    const EPSILON: f32 = 1e-12;
+
+    if a.abs() < EPSILON {
+        if b.abs() < EPSILON {
+            return [None, None];
+        }
+
+        return [Some(-c / b), None];
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+
+    let root = float::sqrt(discriminant);
+
+    [Some((-b + root) / (2.0 * a)), Some((-b - root) / (2.0 * a))]
+}
+
+/// Finds the parameter of the point nearest to `point` on a curve, by sampling and then refining
+/// with Newton-Raphson on the derivative of the squared distance.
+fn nearest(
+    point: Vec2,
+    eval: impl Fn(f32) -> Vec2,
+    derivative: impl Fn(f32) -> Vec2,
+    second_derivative: impl Fn(f32) -> Vec2,
+) -> (f32, Vec2) {
+    let mut best_t = 0.0;
+    let mut best_distance = f32::INFINITY;
+
+    for i in 0..=NEAREST_SAMPLES {
+        let t = i as f32 / NEAREST_SAMPLES as f32;
+        let distance = eval(t).distance_squared(point);
+
+        if distance < best_distance {
+            best_t = t;
+            best_distance = distance;
+        }
+    }
+
+    let mut t = best_t;
+
+    for _ in 0..8 {
+        let offset = eval(t) - point;
+        let d1 = derivative(t);
+
+        // First and second derivatives of `|B(t) - point|^2 / 2`
+        let numerator = offset.dot(d1);
+        let denominator = d1.dot(d1) + offset.dot(second_derivative(t));
+
+        if denominator.abs() < 1e-12 {
+            break;
+        }
+
+        let next = (t - numerator / denominator).clamp(0.0, 1.0);
+
+        if (next - t).abs() < 1e-7 {
+            t = next;
+            break;
+        }
+
+        t = next;
+    }
+
+    let refined = eval(t);
+
+    if refined.distance_squared(point) <= best_distance {
+        (t, refined)
+    } else {
+        (best_t, eval(best_t))
+    }
+}
@@ -0,0 +1,669 @@
+// ------------------------------ easing.rs -------------------------------- //
+
+//! Easing curves and interpolation.
+//!
+//! Provides the standard Penner easing functions, CSS-compatible cubic Bézier
+//! timing functions (`BezierEasing`), damped spring physics (`Spring`), and the
+//! `Lerp` trait for interpolating values. Together these are the building blocks
+//! of animations: an easing maps linear progress `t` in `[0.0, 1.0]` to eased
+//! progress, which is then fed to `Lerp::lerp`.
+//!
+//! # Example
+//! ```
+//! use celui_math::easing::{self, Lerp};
+//! use celui_math::Vec2;
+//!
+//! let from = Vec2::new(0.0, 0.0);
+//! let to = Vec2::new(100.0, 50.0);
+//!
+//! let t = easing::cubic_in_out(0.5);
+//! assert!(from.lerp(to, t) == Vec2::new(50.0, 25.0));
+//! ```
+
+use core::f32::consts::{FRAC_PI_2, PI};
+
+use crate::{float, Color, ColorF, Mat2, Mat3, Mat4, Rect, Vec2, Vec3, Vec4};
+
+// --------------------------------- Lerp ---------------------------------- //
+
+/// Linear interpolation between two values.
+pub trait Lerp {
+    /// Interpolates between `self` (at `t = 0.0`) and `other` (at `t = 1.0`).
+    ///
+    /// Values of `t` outside `[0.0, 1.0]` extrapolate, which is what overshooting
+    /// easings (e.g. `back_out`, `elastic_out`) rely on.
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Vec2 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Vec3 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Vec4 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Color {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Color::lerp(self, other, t)
+    }
+}
+
+impl Lerp for ColorF {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        ColorF::lerp(self, other, t)
+    }
+}
+
+impl Lerp for Rect {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self::new(
+            self.x.lerp(other.x, t),
+            self.y.lerp(other.y, t),
+            self.width.lerp(other.width, t),
+            self.height.lerp(other.height, t),
+        )
+    }
+}
+
+impl<const N: usize> Lerp for [f32; N] {
+    #[inline]
+    fn lerp(mut self, other: Self, t: f32) -> Self {
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.lerp(b, t);
+        }
+
+        self
+    }
+}
+
+impl Lerp for Mat2 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            elements: self.elements.lerp(other.elements, t),
+        }
+    }
+}
+
+impl Lerp for Mat3 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            elements: self.elements.lerp(other.elements, t),
+        }
+    }
+}
+
+impl Lerp for Mat4 {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            elements: self.elements.lerp(other.elements, t),
+        }
+    }
+}
+
+// -------------------------------- Easing --------------------------------- //
+
+/// A timing function, mapping linear progress in `[0.0, 1.0]` to eased progress.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// A CSS `cubic-bezier()` timing function.
+    Bezier(BezierEasing),
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t`.
+    ///
+    /// `t` is clamped to `[0.0, 1.0]`; the result may leave that range for
+    /// overshooting curves.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => linear(t),
+            Self::QuadIn => quad_in(t),
+            Self::QuadOut => quad_out(t),
+            Self::QuadInOut => quad_in_out(t),
+            Self::CubicIn => cubic_in(t),
+            Self::CubicOut => cubic_out(t),
+            Self::CubicInOut => cubic_in_out(t),
+            Self::QuartIn => quart_in(t),
+            Self::QuartOut => quart_out(t),
+            Self::QuartInOut => quart_in_out(t),
+            Self::QuintIn => quint_in(t),
+            Self::QuintOut => quint_out(t),
+            Self::QuintInOut => quint_in_out(t),
+            Self::SineIn => sine_in(t),
+            Self::SineOut => sine_out(t),
+            Self::SineInOut => sine_in_out(t),
+            Self::ExpoIn => expo_in(t),
+            Self::ExpoOut => expo_out(t),
+            Self::ExpoInOut => expo_in_out(t),
+            Self::CircIn => circ_in(t),
+            Self::CircOut => circ_out(t),
+            Self::CircInOut => circ_in_out(t),
+            Self::BackIn => back_in(t),
+            Self::BackOut => back_out(t),
+            Self::BackInOut => back_in_out(t),
+            Self::ElasticIn => elastic_in(t),
+            Self::ElasticOut => elastic_out(t),
+            Self::ElasticInOut => elastic_in_out(t),
+            Self::BounceIn => bounce_in(t),
+            Self::BounceOut => bounce_out(t),
+            Self::BounceInOut => bounce_in_out(t),
+            Self::Bezier(bezier) => bezier.apply(t),
+        }
+    }
+}
+
+// ---------------------------- Penner Easings ----------------------------- //
+
+/// Overshoot amount of the `back` easings (about 10%).
+const BACK_OVERSHOOT: f32 = 1.701_58;
+
+#[inline(always)]
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+#[inline(always)]
+pub fn quad_in(t: f32) -> f32 {
+    t * t
+}
+
+#[inline(always)]
+pub fn quad_out(t: f32) -> f32 {
+    1.0 - quad_in(1.0 - t)
+}
+
+#[inline]
+pub fn quad_in_out(t: f32) -> f32 {
+    in_out(t, quad_in)
+}
+
+#[inline(always)]
+pub fn cubic_in(t: f32) -> f32 {
+    t * t * t
+}
+
+#[inline(always)]
+pub fn cubic_out(t: f32) -> f32 {
+    1.0 - cubic_in(1.0 - t)
+}
+
+#[inline]
+pub fn cubic_in_out(t: f32) -> f32 {
+    in_out(t, cubic_in)
+}
+
+#[inline(always)]
+pub fn quart_in(t: f32) -> f32 {
+    t * t * t * t
+}
+
+#[inline(always)]
+pub fn quart_out(t: f32) -> f32 {
+    1.0 - quart_in(1.0 - t)
+}
+
+#[inline]
+pub fn quart_in_out(t: f32) -> f32 {
+    in_out(t, quart_in)
+}
+
+#[inline(always)]
+pub fn quint_in(t: f32) -> f32 {
+    t * t * t * t * t
+}
+
+#[inline(always)]
+pub fn quint_out(t: f32) -> f32 {
+    1.0 - quint_in(1.0 - t)
+}
+
+#[inline]
+pub fn quint_in_out(t: f32) -> f32 {
+    in_out(t, quint_in)
+}
+
+#[inline]
+pub fn sine_in(t: f32) -> f32 {
+    1.0 - float::cos(t * FRAC_PI_2)
+}
+
+#[inline]
+pub fn sine_out(t: f32) -> f32 {
+    float::sin(t * FRAC_PI_2)
+}
+
+#[inline]
+pub fn sine_in_out(t: f32) -> f32 {
+    (1.0 - float::cos(t * PI)) * 0.5
+}
+
+#[inline]
+pub fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        return 0.0;
+    }
+
+    float::powf(2.0, 10.0 * t - 10.0)
+}
+
+#[inline]
+pub fn expo_out(t: f32) -> f32 {
+    1.0 - expo_in(1.0 - t)
+}
+
+#[inline]
+pub fn expo_in_out(t: f32) -> f32 {
+    in_out(t, expo_in)
+}
+
+#[inline]
+pub fn circ_in(t: f32) -> f32 {
+    1.0 - float::sqrt((1.0 - t * t).max(0.0))
+}
+
+#[inline]
+pub fn circ_out(t: f32) -> f32 {
+    1.0 - circ_in(1.0 - t)
+}
+
+#[inline]
+pub fn circ_in_out(t: f32) -> f32 {
+    in_out(t, circ_in)
+}
+
+#[inline]
+pub fn back_in(t: f32) -> f32 {
+    t * t * ((BACK_OVERSHOOT + 1.0) * t - BACK_OVERSHOOT)
+}
+
+#[inline]
+pub fn back_out(t: f32) -> f32 {
+    1.0 - back_in(1.0 - t)
+}
+
+#[inline]
+pub fn back_in_out(t: f32) -> f32 {
+    // The in-out variant uses a larger overshoot, matching Penner's original curves
+    const OVERSHOOT: f32 = BACK_OVERSHOOT * 1.525;
+
+    let back = |t: f32| t * t * ((OVERSHOOT + 1.0) * t - OVERSHOOT);
+
+    in_out(t, back)
+}
+
+#[inline]
+pub fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+
+    -float::powf(2.0, 10.0 * t - 10.0) * float::sin((t * 10.0 - 10.75) * (2.0 * PI / 3.0))
+}
+
+#[inline]
+pub fn elastic_out(t: f32) -> f32 {
+    1.0 - elastic_in(1.0 - t)
+}
+
+#[inline]
+pub fn elastic_in_out(t: f32) -> f32 {
+    in_out(t, elastic_in)
+}
+
+#[inline]
+pub fn bounce_in(t: f32) -> f32 {
+    1.0 - bounce_out(1.0 - t)
+}
+
+#[inline]
+pub fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+
+        N * t * t + 0.984_375
+    }
+}
+
+#[inline]
+pub fn bounce_in_out(t: f32) -> f32 {
+    in_out(t, bounce_in)
+}
+
+/// Builds an in-out easing from an in easing, mirroring it around `t = 0.5`.
+#[inline(always)]
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) * 0.5
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) * 0.5
+    }
+}
+
+// ----------------------------- BezierEasing ------------------------------ //
+
+/// A cubic Bézier timing function, equivalent to CSS `cubic-bezier(x1, y1, x2, y2)`.
+///
+/// The curve runs from `(0, 0)` to `(1, 1)` with control points `(x1, y1)` and
+/// `(x2, y2)`; `x1` and `x2` must be in `[0.0, 1.0]` for the curve to be a function.
+///
+/// # Example
+/// ```
+/// use celui_math::easing::BezierEasing;
+///
+/// let ease = BezierEasing::EASE_IN_OUT;
+/// assert_eq!(ease.apply(0.0), 0.0);
+/// assert!((ease.apply(0.5) - 0.5).abs() < 1e-5);
+/// assert_eq!(ease.apply(1.0), 1.0);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct BezierEasing {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl BezierEasing {
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    #[inline(always)]
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Returns the eased progress (the curve's `y`) for the linear progress `x`.
+    pub fn apply(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        let s = self.solve_parameter(x);
+
+        bezier(s, self.y1, self.y2)
+    }
+
+    /// Finds the curve parameter whose `x` coordinate is `x`.
+    fn solve_parameter(&self, x: f32) -> f32 {
+        const EPSILON: f32 = 1e-6;
+
+        // Newton-Raphson converges quickly for most curves...
+        let mut s = x;
+
+        for _ in 0..8 {
+            let error = bezier(s, self.x1, self.x2) - x;
+
+            if error.abs() < EPSILON {
+                return s;
+            }
+
+            let slope = bezier_slope(s, self.x1, self.x2);
+
+            if slope.abs() < EPSILON {
+                break;
+            }
+
+            s -= error / slope;
+        }
+
+        // ...and bisection handles the flat ones, since `x(s)` is monotonic
+        let (mut low, mut high) = (0.0, 1.0);
+
+        s = x;
+
+        for _ in 0..32 {
+            let value = bezier(s, self.x1, self.x2);
+
+            if (value - x).abs() < EPSILON {
+                break;
+            }
+
+            if value < x {
+                low = s;
+            } else {
+                high = s;
+            }
+
+            s = (low + high) * 0.5;
+        }
+
+        s
+    }
+}
+
+/// Evaluates one coordinate of a cubic Bézier with endpoints `0` and `1`.
+#[inline(always)]
+fn bezier(s: f32, p1: f32, p2: f32) -> f32 {
+    let a = 1.0 - 3.0 * p2 + 3.0 * p1;
+    let b = 3.0 * p2 - 6.0 * p1;
+    let c = 3.0 * p1;
+
+    ((a * s + b) * s + c) * s
+}
+
+/// Evaluates the derivative of one coordinate of a cubic Bézier with endpoints `0` and `1`.
+#[inline(always)]
+fn bezier_slope(s: f32, p1: f32, p2: f32) -> f32 {
+    let a = 1.0 - 3.0 * p2 + 3.0 * p1;
+    let b = 3.0 * p2 - 6.0 * p1;
+    let c = 3.0 * p1;
+
+    (3.0 * a * s + 2.0 * b) * s + c
+}
+
+// -------------------------------- Spring --------------------------------- //
+
+/// How far the damping ratio may be from `1.0` for a spring to be treated as critically
+/// damped. The other solutions divide by `1 - zeta^2`, which loses precision close to it.
+const CRITICAL_DAMPING_TOLERANCE: f32 = 1e-3;
+
+/// A damped harmonic oscillator, animating from `0.0` towards a rest position of `1.0`.
+///
+/// Unlike easings, a spring has no fixed duration: it is evaluated at a time in
+/// seconds and settles at its own pace, depending on its parameters.
+///
+/// # Example
+/// ```
+/// use celui_math::easing::Spring;
+///
+/// let spring = Spring::new(170.0, 26.0, 1.0);
+/// assert_eq!(spring.evaluate(0.0), 0.0);
+/// assert!((spring.evaluate(2.0) - 1.0).abs() < 1e-3);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// Spring constant; higher values make the spring faster.
+    pub stiffness: f32,
+    /// Friction; higher values reduce the oscillation.
+    pub damping: f32,
+    /// Mass of the animated object; higher values make the spring slower and bouncier.
+    ///
+    /// Both `stiffness` and `mass` must be positive, or the spring evaluates to NaN.
+    pub mass: f32,
+    /// Velocity at time `0.0`, in units per second.
+    pub initial_velocity: f32,
+}
+
+impl Spring {
+    /// Creates a spring at rest, starting with no velocity.
+    ///
+    /// # Panics
+    /// Panics if `stiffness` or `mass` is not positive, or if `damping` is negative.
+    #[inline]
+    pub const fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        assert!(
+            stiffness > 0.0 && mass > 0.0 && damping >= 0.0,
+            "Invalid spring parameters"
+        );
+
+        Self {
+            stiffness,
+            damping,
+            mass,
+            initial_velocity: 0.0,
+        }
+    }
+
+    /// Returns the same spring, starting with the given velocity.
+    #[inline(always)]
+    pub const fn with_initial_velocity(self, initial_velocity: f32) -> Self {
+        Self {
+            initial_velocity,
+            ..self
+        }
+    }
+
+    /// Returns the damping ratio; `1.0` is critically damped, lower values oscillate.
+    ///
+    /// Ratios within `0.001` of `1.0` are evaluated as critically damped.
+    #[inline]
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * float::sqrt(self.stiffness * self.mass))
+    }
+
+    /// Returns the position of the spring at `time` seconds.
+    #[inline]
+    pub fn evaluate(&self, time: f32) -> f32 {
+        1.0 + self.state(time).0
+    }
+
+    /// Returns the velocity of the spring at `time` seconds, in units per second.
+    #[inline]
+    pub fn velocity(&self, time: f32) -> f32 {
+        self.state(time).1
+    }
+
+    /// Returns `true` if both the displacement and velocity at `time` are within `epsilon`.
+    #[inline]
+    pub fn is_settled(&self, time: f32, epsilon: f32) -> bool {
+        let (displacement, velocity) = self.state(time);
+
+        displacement.abs() <= epsilon && velocity.abs() <= epsilon
+    }
+
+    /// Returns the displacement from the rest position and the velocity at `time` seconds.
+    fn state(&self, time: f32) -> (f32, f32) {
+        let t = time.max(0.0);
+        let x0 = -1.0;
+        let v0 = self.initial_velocity;
+
+        let omega = float::sqrt(self.stiffness / self.mass);
+        let zeta = self.damping_ratio();
+
+        if (zeta - 1.0).abs() <= CRITICAL_DAMPING_TOLERANCE {
+            // Critically damped, the fastest approach without overshooting
+            let decay = float::exp(-omega * t);
+            let b = v0 + omega * x0;
+
+            let x = decay * (x0 + b * t);
+            let v = decay * (b - omega * (x0 + b * t));
+
+            (x, v)
+        } else if zeta < 1.0 {
+            // Underdamped, oscillates around the rest position
+            let omega_d = omega * float::sqrt(1.0 - zeta * zeta);
+            let decay = float::exp(-zeta * omega * t);
+            let (sin, cos) = float::sin_cos(omega_d * t);
+
+            let a = x0;
+            let b = (v0 + zeta * omega * x0) / omega_d;
+
+            let x = decay * (a * cos + b * sin);
+            let v = -zeta * omega * x + decay * omega_d * (b * cos - a * sin);
+
+            (x, v)
+        } else {
+            // Overdamped, approaches the rest position slowly
+            let root = omega * float::sqrt(zeta * zeta - 1.0);
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+
+            let (e1, e2) = (float::exp(r1 * t), float::exp(r2 * t));
+
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+}
@@ -0,0 +1,569 @@
+// ------------------------------- fixed.rs -------------------------------- //
+
+//! Fixed-point arithmetic for targets without an FPU.
+//!
+//! Defines `Fixed`, a signed Q16.16 number: 16 integer bits and 16 fractional
+//! bits stored in an `i32`, giving a range of about `±32768` with a precision of
+//! `1 / 65536`. All operations, including square roots and trigonometry, use
+//! integer instructions only.
+//!
+//! Like the primitive integers, arithmetic overflow panics in debug builds and
+//! wraps in release builds; use the `saturating_*` methods where that matters.
+
+use crate::{Real, Scalar};
+
+/// Number of fractional bits.
+const FRAC_BITS: u32 = 16;
+
+/// Number of fractional bits of the internal high-precision (Q30) format.
+const WIDE_BITS: u32 = 30;
+
+const WIDE_ONE: i64 = 1 << WIDE_BITS;
+const WIDE_PI: i64 = 3_373_259_426;
+const WIDE_FRAC_PI_2: i64 = 1_686_629_713;
+const WIDE_FRAC_PI_6: i64 = 562_209_904;
+const WIDE_TAU: i64 = 6_746_518_852;
+const WIDE_SQRT_3: i64 = 1_859_775_393;
+const WIDE_TAN_PI_12: i64 = 287_708_255;
+
+// -------------------------------- Fixed ---------------------------------- //
+
+/// A signed Q16.16 fixed-point number.
+///
+/// # Example
+/// ```
+/// use celui_math::Fixed;
+///
+/// let a = Fixed::from_int(3);
+/// let b = Fixed::from_f32(0.5);
+///
+/// assert!(a * b == Fixed::from_f32(1.5));
+/// assert!(Fixed::from_int(16).sqrt() == Fixed::from_int(4));
+/// assert!((Fixed::FRAC_PI_2.sin() - Fixed::ONE).abs() <= Fixed::EPSILON);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << FRAC_BITS);
+    pub const HALF: Self = Self(1 << (FRAC_BITS - 1));
+    pub const MIN: Self = Self(i32::MIN);
+    pub const MAX: Self = Self(i32::MAX);
+    /// The smallest positive value, `1 / 65536`.
+    pub const EPSILON: Self = Self(1);
+    pub const PI: Self = Self(205_887);
+    pub const FRAC_PI_2: Self = Self(102_944);
+    pub const TAU: Self = Self(411_775);
+
+    /// Creates a value from its raw Q16.16 representation.
+    #[inline(always)]
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw Q16.16 representation.
+    #[inline(always)]
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Creates a value from an integer, saturating outside of `[-32768, 32767]`.
+    #[inline(always)]
+    pub const fn from_int(value: i32) -> Self {
+        if value > i16::MAX as i32 {
+            Self::MAX
+        } else if value < i16::MIN as i32 {
+            Self::MIN
+        } else {
+            Self(value << FRAC_BITS)
+        }
+    }
+
+    /// Returns the integer part, rounding towards negative infinity.
+    #[inline(always)]
+    pub const fn to_int(self) -> i32 {
+        self.0 >> FRAC_BITS
+    }
+
+    /// Creates a value from an `f32`, rounding to the nearest and saturating out of range.
+    ///
+    /// This is the only operation that uses floating-point arithmetic; prefer
+    /// `from_int`, `from_bits` or `from_ratio` on targets without an FPU.
+    #[inline(always)]
+    pub fn from_f32(value: f32) -> Self {
+        let scaled = value * (1u32 << FRAC_BITS) as f32;
+
+        // Round half away from zero; `as` saturates and maps NaN to zero
+        Self((scaled + 0.5f32.copysign(scaled)) as i32)
+    }
+
+    /// Converts the value to the nearest `f32`.
+    #[inline(always)]
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / (1u32 << FRAC_BITS) as f32
+    }
+
+    /// Creates the value `numerator / denominator` using integer arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero.
+    #[inline(always)]
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        Self((((numerator as i64) << FRAC_BITS) / denominator as i64) as i32)
+    }
+
+    /// Returns the absolute value.
+    #[inline(always)]
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[inline(always)]
+    pub const fn floor(self) -> Self {
+        Self(self.0 & !((1 << FRAC_BITS) - 1))
+    }
+
+    /// Returns the smallest integer greater than or equal to `self`.
+    #[inline(always)]
+    pub const fn ceil(self) -> Self {
+        Self((self.0 + ((1 << FRAC_BITS) - 1)) & !((1 << FRAC_BITS) - 1))
+    }
+
+    /// Returns the nearest integer, rounding half-way cases towards positive infinity.
+    #[inline(always)]
+    pub const fn round(self) -> Self {
+        Self((self.0 + (1 << (FRAC_BITS - 1))) & !((1 << FRAC_BITS) - 1))
+    }
+
+    /// Returns the fractional part, always in `[0, 1)`.
+    #[inline(always)]
+    pub const fn fract(self) -> Self {
+        Self(self.0 & ((1 << FRAC_BITS) - 1))
+    }
+
+    /// Adds two values, saturating at the numeric bounds.
+    #[inline(always)]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Subtracts two values, saturating at the numeric bounds.
+    #[inline(always)]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Multiplies two values, saturating at the numeric bounds.
+    #[inline(always)]
+    pub const fn saturating_mul(self, other: Self) -> Self {
+        Self(saturate(mul_wide(self.0, other.0)))
+    }
+
+    /// Divides two values, returning `None` if `other` is zero or the result overflows.
+    #[inline(always)]
+    pub const fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+
+        let result = ((self.0 as i64) << FRAC_BITS) / other.0 as i64;
+
+        if result > i32::MAX as i64 || result < i32::MIN as i64 {
+            return None;
+        }
+
+        Some(Self(result as i32))
+    }
+
+    /// Returns the square root, or zero for negative values.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+
+        // `sqrt(x * 2^16) * 2^8 == sqrt(x * 2^32)`, so the root of the widened bits is exact Q16.16
+        Self(isqrt((self.0 as u64) << FRAC_BITS) as i32)
+    }
+
+    /// Returns the sine of an angle in radians.
+    #[inline]
+    pub fn sin(self) -> Self {
+        Self::from_wide(sin_wide((self.0 as i64) << (WIDE_BITS - FRAC_BITS)))
+    }
+
+    /// Returns the cosine of an angle in radians.
+    #[inline]
+    pub fn cos(self) -> Self {
+        Self::from_wide(sin_wide(
+            ((self.0 as i64) << (WIDE_BITS - FRAC_BITS)) + WIDE_FRAC_PI_2,
+        ))
+    }
+
+    /// Returns the tangent of an angle in radians, saturating near the asymptotes.
+    #[inline]
+    pub fn tan(self) -> Self {
+        let angle = (self.0 as i64) << (WIDE_BITS - FRAC_BITS);
+        let sin = sin_wide(angle);
+        let cos = sin_wide(angle + WIDE_FRAC_PI_2);
+
+        if cos == 0 {
+            return if sin >= 0 { Self::MAX } else { Self::MIN };
+        }
+
+        Self(saturate((sin << FRAC_BITS) / cos))
+    }
+
+    /// Returns the arctangent in radians, in the range `[-PI/2, PI/2]`.
+    #[inline]
+    pub fn atan(self) -> Self {
+        self.atan2(Self::ONE)
+    }
+
+    /// Returns the four-quadrant arctangent of `self / x` in radians, in the range `[-PI, PI]`.
+    pub fn atan2(self, x: Self) -> Self {
+        let (y, x) = (self.0 as i64, x.0 as i64);
+
+        if x == 0 && y == 0 {
+            return Self::ZERO;
+        }
+
+        let (ay, ax) = (y.abs(), x.abs());
+
+        // Evaluate on the octant where the ratio is in `[0, 1]`
+        let mut angle = if ay <= ax {
+            atan_wide((ay << WIDE_BITS) / ax)
+        } else {
+            WIDE_FRAC_PI_2 - atan_wide((ax << WIDE_BITS) / ay)
+        };
+
+        if x < 0 {
+            angle = WIDE_PI - angle;
+        }
+
+        if y < 0 {
+            angle = -angle;
+        }
+
+        Self::from_wide(angle)
+    }
+
+    /// Converts a Q30 value to Q16.16, rounding to the nearest.
+    #[inline(always)]
+    fn from_wide(value: i64) -> Self {
+        let shift = WIDE_BITS - FRAC_BITS;
+
+        Self(((value + (1 << (shift - 1))) >> shift) as i32)
+    }
+}
+
+impl core::ops::Add for Fixed {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl core::ops::Sub for Fixed {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl core::ops::Mul for Fixed {
+    type Output = Self;
+
+    /// Multiplies two values.
+    ///
+    /// Like the primitive integers, overflow panics in debug builds and wraps in release
+    /// builds; use `saturating_mul` to clamp to the numeric bounds instead.
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        let result = mul_wide(self.0, other.0);
+
+        debug_assert!(
+            result >= i32::MIN as i64 && result <= i32::MAX as i64,
+            "attempt to multiply with overflow"
+        );
+
+        Self(result as i32)
+    }
+}
+
+impl core::ops::Div for Fixed {
+    type Output = Self;
+
+    /// Divides two values.
+    ///
+    /// Panics if `other` is zero. Like the primitive integers, overflow panics in debug builds
+    /// and wraps in release builds; use `checked_div` to detect it instead.
+    #[inline(always)]
+    fn div(self, other: Self) -> Self {
+        let result = ((self.0 as i64) << FRAC_BITS) / other.0 as i64;
+
+        debug_assert!(
+            result >= i32::MIN as i64 && result <= i32::MAX as i64,
+            "attempt to divide with overflow"
+        );
+
+        Self(result as i32)
+    }
+}
+
+impl core::ops::Rem for Fixed {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, other: Self) -> Self {
+        Self(self.0 % other.0)
+    }
+}
+
+impl core::ops::Neg for Fixed {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl core::ops::AddAssign for Fixed {
+    #[inline(always)]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::SubAssign for Fixed {
+    #[inline(always)]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::MulAssign for Fixed {
+    #[inline(always)]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl core::ops::DivAssign for Fixed {
+    #[inline(always)]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl From<i16> for Fixed {
+    #[inline(always)]
+    fn from(value: i16) -> Self {
+        Self((value as i32) << FRAC_BITS)
+    }
+}
+
+impl core::fmt::Display for Fixed {
+    /// Formats the value in decimal, with up to 5 fractional digits unless a precision is given.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bits = self.0 as i64;
+        let magnitude = bits.unsigned_abs();
+        let precision = f.precision().unwrap_or(5).min(9);
+        let scale = 10u64.pow(precision as u32);
+
+        // Round the fraction to the requested number of decimal digits
+        let mut integer = magnitude >> FRAC_BITS;
+        let mut fraction = ((magnitude & 0xFFFF) * scale + (1 << (FRAC_BITS - 1))) >> FRAC_BITS;
+
+        if fraction >= scale {
+            integer += 1;
+            fraction -= scale;
+        }
+
+        if bits < 0 {
+            f.write_str("-")?;
+        }
+
+        write!(f, "{integer}")?;
+
+        if f.precision().is_some() {
+            if precision > 0 {
+                write!(f, ".{fraction:0precision$}")?;
+            }
+
+            return Ok(());
+        }
+
+        // Without an explicit precision, trim trailing zeros like `f32` does
+        let mut digits = precision;
+
+        #[allow(clippy::manual_is_multiple_of)] // `is_multiple_of` needs Rust 1.87
+        while digits > 0 && fraction % 10 == 0 {
+            fraction /= 10;
+            digits -= 1;
+        }
+
+        if digits > 0 {
+            write!(f, ".{fraction:0digits$}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Scalar for Fixed {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    #[inline(always)]
+    fn saturating_add(self, other: Self) -> Self {
+        Fixed::saturating_add(self, other)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self {
+        Fixed::saturating_sub(self, other)
+    }
+}
+
+impl Real for Fixed {
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        Fixed::from_f32(value)
+    }
+
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        Fixed::to_f32(self)
+    }
+
+    #[inline(always)]
+    fn abs(self) -> Self {
+        Fixed::abs(self)
+    }
+
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        Fixed::sqrt(self)
+    }
+
+    #[inline(always)]
+    fn sin(self) -> Self {
+        Fixed::sin(self)
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Self {
+        Fixed::cos(self)
+    }
+
+    #[inline(always)]
+    fn atan2(self, x: Self) -> Self {
+        Fixed::atan2(self, x)
+    }
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Multiplies two Q16.16 values into a rounded, not yet narrowed, Q16.16 result.
+#[inline(always)]
+const fn mul_wide(a: i32, b: i32) -> i64 {
+    (a as i64 * b as i64 + (1 << (FRAC_BITS - 1))) >> FRAC_BITS
+}
+
+/// Narrows a value to `i32`, saturating at the bounds.
+#[inline(always)]
+const fn saturate(value: i64) -> i32 {
+    if value > i32::MAX as i64 {
+        i32::MAX
+    } else if value < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        value as i32
+    }
+}
+
+/// Returns the integer square root of `value`, rounded down.
+#[inline]
+fn isqrt(value: u64) -> u64 {
+    let mut remainder = value;
+    let mut root = 0;
+    let mut bit = 1u64 << ((63 - value.leading_zeros()) & !1);
+
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+
+        bit >>= 2;
+    }
+
+    root
+}
+
+/// Multiplies two Q30 values.
+#[inline(always)]
+fn mul_q30(a: i64, b: i64) -> i64 {
+    (a * b) >> WIDE_BITS
+}
+
+/// Returns the sine of a Q30 angle as a Q30 value.
+fn sin_wide(angle: i64) -> i64 {
+    // Reduce to `[-PI, PI]`, then mirror into `[-PI/2, PI/2]`
+    let mut x = angle % WIDE_TAU;
+
+    if x > WIDE_PI {
+        x -= WIDE_TAU;
+    } else if x < -WIDE_PI {
+        x += WIDE_TAU;
+    }
+
+    if x > WIDE_FRAC_PI_2 {
+        x = WIDE_PI - x;
+    } else if x < -WIDE_FRAC_PI_2 {
+        x = -WIDE_PI - x;
+    }
+
+    // Taylor series of `sin(x)`, evaluated with Horner's method
+    let x2 = mul_q30(x, x);
+    let mut sum = WIDE_ONE;
+
+    for n in (1..=6).rev() {
+        sum = WIDE_ONE - mul_q30(sum, x2) / ((2 * n) * (2 * n + 1));
+    }
+
+    mul_q30(x, sum)
+}
+
+/// Returns the arctangent of a Q30 value in `[0, 1]` as a Q30 angle.
+fn atan_wide(value: i64) -> i64 {
+    let mut a = value;
+    let mut offset = 0;
+
+    // `atan(x) = PI/6 + atan((x * sqrt(3) - 1) / (sqrt(3) + x))` for `x > tan(PI/12)`
+    if a > WIDE_TAN_PI_12 {
+        a = ((mul_q30(a, WIDE_SQRT_3) - WIDE_ONE) << WIDE_BITS) / (WIDE_SQRT_3 + a);
+        offset = WIDE_FRAC_PI_6;
+    }
+
+    // Taylor series of `atan(a)` for `|a| <= tan(PI/12)`
+    let a2 = mul_q30(a, a);
+    let mut sum = 0;
+
+    for n in (0..8).rev() {
+        let term = WIDE_ONE / (2 * n + 1);
+
+        sum = if n % 2 == 0 { term } else { -term } + mul_q30(a2, sum);
+    }
+
+    offset + mul_q30(a, sum)
+}
@@ -0,0 +1,490 @@
+// ------------------------------- float.rs -------------------------------- //
+
+//! Floating-point functions for `no_std` environments.
+//!
+//! `core` does not provide transcendental functions (e.g. `sqrt`, `sin`, `exp`)
+//! for `f32`. This module provides portable software implementations of them.
+//! When the `std` feature is enabled, the standard library versions are used instead.
+
+#[cfg(not(feature = "std"))]
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_6, LN_2, PI};
+
+// ------------------------------- Rounding -------------------------------- //
+
+/// Returns the integer part of `x`, rounding towards zero.
+#[inline]
+pub(crate) fn trunc(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.trunc();
+
+    #[cfg(not(feature = "std"))]
+    {
+        // Values with a magnitude of at least 2^23 (and NaN/infinity) are already integral
+        if x.is_nan() || x.abs() >= 8_388_608.0 {
+            return x;
+        }
+
+        (x as i32) as f32
+    }
+}
+
+/// Returns the largest integer less than or equal to `x`.
+#[inline]
+pub(crate) fn floor(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.floor();
+
+    #[cfg(not(feature = "std"))]
+    {
+        let t = trunc(x);
+
+        if t > x {
+            t - 1.0
+        } else {
+            t
+        }
+    }
+}
+
+/// Returns the smallest integer greater than or equal to `x`.
+#[inline]
+pub(crate) fn ceil(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.ceil();
+
+    #[cfg(not(feature = "std"))]
+    {
+        let t = trunc(x);
+
+        if t < x {
+            t + 1.0
+        } else {
+            t
+        }
+    }
+}
+
+/// Returns the nearest integer to `x`, rounding half-way cases away from zero.
+#[inline]
+pub(crate) fn round(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.round();
+
+    #[cfg(not(feature = "std"))]
+    {
+        let t = trunc(x);
+
+        if (x - t).abs() >= 0.5 {
+            t + x.signum()
+        } else {
+            t
+        }
+    }
+}
+
+/// Returns the least non-negative remainder of `x / y`.
+#[inline]
+pub(crate) fn rem_euclid(x: f32, y: f32) -> f32 {
+    let r = x - y * trunc(x / y);
+
+    if r < 0.0 {
+        r + y.abs()
+    } else {
+        r
+    }
+}
+
+// ----------------------------- Power & Root ------------------------------ //
+
+/// Returns the square root of `x`, or NaN if `x` is negative.
+#[inline]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.sqrt();
+
+    #[cfg(not(feature = "std"))]
+    {
+        if x < 0.0 || x.is_nan() {
+            return f32::NAN;
+        }
+
+        if x == 0.0 || x.is_infinite() {
+            return x;
+        }
+
+        let x = x as f64;
+
+        // Initial estimate from halving the exponent, refined with Newton-Raphson
+        let mut y = f64::from_bits((x.to_bits() >> 1) + 0x1FF7_A3BE_A91D_9B1B);
+
+        for _ in 0..5 {
+            y = 0.5 * (y + x / y);
+        }
+
+        y as f32
+    }
+}
+
+/// Returns the cube root of `x`.
+#[inline]
+pub(crate) fn cbrt(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.cbrt();
+
+    #[cfg(not(feature = "std"))]
+    {
+        if x == 0.0 || x.is_nan() || x.is_infinite() {
+            return x;
+        }
+
+        let a = x.abs() as f64;
+        let mut y = exp_f64(ln_f64(a) / 3.0);
+
+        // One Newton-Raphson step recovers the precision lost in `exp`/`ln`
+        y -= (y * y * y - a) / (3.0 * y * y);
+
+        (y as f32).copysign(x)
+    }
+}
+
+/// Returns `x` raised to the power of `y`.
+#[inline]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.powf(y);
+
+    #[cfg(not(feature = "std"))]
+    {
+        if y == 0.0 || x == 1.0 {
+            return 1.0;
+        }
+
+        if x.is_nan() || y.is_nan() {
+            return f32::NAN;
+        }
+
+        if x == 0.0 {
+            return if y > 0.0 { 0.0 } else { f32::INFINITY };
+        }
+
+        let magnitude = exp_f64(y as f64 * ln_f64(x.abs() as f64)) as f32;
+
+        if x > 0.0 {
+            return magnitude;
+        }
+
+        // Negative bases are only defined for integral exponents
+        if trunc(y) != y {
+            return f32::NAN;
+        }
+
+        if rem_euclid(y, 2.0) == 1.0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// Returns `e^x`.
+#[inline]
+pub(crate) fn exp(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.exp();
+
+    #[cfg(not(feature = "std"))]
+    {
+        exp_f64(x as f64) as f32
+    }
+}
+
+// ----------------------------- Trigonometry ------------------------------ //
+
+/// Returns the sine of `x` (in radians).
+#[inline]
+pub(crate) fn sin(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.sin();
+
+    #[cfg(not(feature = "std"))]
+    {
+        sin_f64(x as f64) as f32
+    }
+}
+
+/// Returns the cosine of `x` (in radians).
+#[inline]
+pub(crate) fn cos(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.cos();
+
+    #[cfg(not(feature = "std"))]
+    {
+        sin_f64(x as f64 + FRAC_PI_2) as f32
+    }
+}
+
+/// Returns the sine and cosine of `x` (in radians).
+#[inline]
+pub(crate) fn sin_cos(x: f32) -> (f32, f32) {
+    (sin(x), cos(x))
+}
+
+/// Returns the tangent of `x` (in radians).
+#[inline]
+pub(crate) fn tan(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.tan();
+
+    #[cfg(not(feature = "std"))]
+    {
+        let x = x as f64;
+
+        (sin_f64(x) / sin_f64(x + FRAC_PI_2)) as f32
+    }
+}
+
+/// Returns the four-quadrant arctangent of `y / x`, in the range `[-PI, PI]`.
+#[inline]
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return y.atan2(x);
+
+    #[cfg(not(feature = "std"))]
+    {
+        if x.is_nan() || y.is_nan() {
+            return f32::NAN;
+        }
+
+        let (xf, yf) = (x as f64, y as f64);
+
+        let angle = if x > 0.0 {
+            atan_f64(yf / xf)
+        } else if x < 0.0 {
+            if y.is_sign_negative() {
+                atan_f64(yf / xf) - PI
+            } else {
+                atan_f64(yf / xf) + PI
+            }
+        } else if y > 0.0 {
+            FRAC_PI_2
+        } else if y < 0.0 {
+            -FRAC_PI_2
+        } else if x.is_sign_negative() {
+            PI.copysign(yf)
+        } else {
+            yf
+        };
+
+        angle as f32
+    }
+}
+
+/// Returns the arcsine of `x`, in the range `[-PI/2, PI/2]`.
+#[inline]
+pub(crate) fn asin(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.asin();
+
+    #[cfg(not(feature = "std"))]
+    {
+        if x.is_nan() || x.abs() > 1.0 {
+            return f32::NAN;
+        }
+
+        atan2(x, sqrt((1.0 - x) * (1.0 + x)))
+    }
+}
+
+/// Returns the arccosine of `x`, in the range `[0, PI]`.
+#[inline]
+pub(crate) fn acos(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return x.acos();
+
+    #[cfg(not(feature = "std"))]
+    {
+        if x.is_nan() || x.abs() > 1.0 {
+            return f32::NAN;
+        }
+
+        atan2(sqrt((1.0 - x) * (1.0 + x)), x)
+    }
+}
+
+// ------------------------------- Software -------------------------------- //
+
+#[cfg(not(feature = "std"))]
+fn exp_f64(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    if x > 709.0 {
+        return f64::INFINITY;
+    }
+
+    if x < -745.0 {
+        return 0.0;
+    }
+
+    // Reduce to `x = k * ln(2) + r` with `|r| <= ln(2) / 2`
+    let k = round_f64(x / LN_2);
+    let r = x - k * LN_2;
+
+    // Taylor series of `e^r`, evaluated with Horner's method
+    let mut sum = 1.0;
+
+    for n in (1..=13).rev() {
+        sum = 1.0 + sum * r / n as f64;
+    }
+
+    scale_f64(sum, k as i32)
+}
+
+#[cfg(not(feature = "std"))]
+fn ln_f64(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    if x.is_infinite() {
+        return x;
+    }
+
+    // Normalize subnormals so the exponent field is meaningful
+    let (x, bias) = if x < f64::MIN_POSITIVE {
+        (x * (1u64 << 54) as f64, -54)
+    } else {
+        (x, 0)
+    };
+
+    // Decompose into `x = m * 2^e` with `m` in `[sqrt(2) / 2, sqrt(2)]`
+    let bits = x.to_bits();
+    let mut e = ((bits >> 52) & 0x7FF) as i32 - 1023 + bias;
+    let mut m = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | 0x3FF0_0000_0000_0000);
+
+    if m > core::f64::consts::SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+
+    // `ln(m) = 2 * atanh(s)` where `s = (m - 1) / (m + 1)`
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut sum = 0.0;
+
+    for n in (0..10).rev() {
+        sum = 1.0 / (2 * n + 1) as f64 + s2 * sum;
+    }
+
+    e as f64 * LN_2 + 2.0 * s * sum
+}
+
+#[cfg(not(feature = "std"))]
+fn sin_f64(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+
+    // Reduce to `[-PI, PI]`, then mirror into `[-PI/2, PI/2]`
+    let mut r = x - round_f64(x / (2.0 * PI)) * (2.0 * PI);
+
+    if r > FRAC_PI_2 {
+        r = PI - r;
+    } else if r < -FRAC_PI_2 {
+        r = -PI - r;
+    }
+
+    // Taylor series of `sin(r)`, evaluated with Horner's method
+    let r2 = r * r;
+    let mut sum = 1.0;
+
+    for n in (1..=8).rev() {
+        sum = 1.0 - sum * r2 / ((2 * n) * (2 * n + 1)) as f64;
+    }
+
+    r * sum
+}
+
+#[cfg(not(feature = "std"))]
+fn atan_f64(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    let sign = x.signum();
+    let mut a = x.abs();
+    let mut offset = 0.0;
+    let mut invert = false;
+
+    // `atan(x) = PI/2 - atan(1/x)` for `x > 1`
+    if a > 1.0 {
+        a = 1.0 / a;
+        invert = true;
+    }
+
+    // `atan(x) = PI/6 + atan((x * sqrt(3) - 1) / (sqrt(3) + x))` for `x > tan(PI/12)`
+    if a > 0.267_949_192_431_122_7 {
+        const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+        a = (a * SQRT_3 - 1.0) / (SQRT_3 + a);
+        offset = FRAC_PI_6;
+    }
+
+    // Taylor series of `atan(a)` for `|a| <= tan(PI/12)`
+    let a2 = a * a;
+    let mut sum = 0.0;
+
+    for n in (0..12).rev() {
+        let term = 1.0 / (2 * n + 1) as f64;
+
+        sum = if n % 2 == 0 { term } else { -term } + a2 * sum;
+    }
+
+    let mut angle = offset + a * sum;
+
+    if invert {
+        angle = FRAC_PI_2 - angle;
+    }
+
+    sign * angle
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+fn round_f64(x: f64) -> f64 {
+    // Values with a magnitude of at least 2^52 are already integral
+    if x.is_nan() || x.abs() >= 4_503_599_627_370_496.0 {
+        return x;
+    }
+
+    let t = (x as i64) as f64;
+
+    if (x - t).abs() >= 0.5 {
+        t + x.signum()
+    } else {
+        t
+    }
+}
+
+/// Multiplies `x` by `2^n`.
+#[cfg(not(feature = "std"))]
+#[inline]
+fn scale_f64(mut x: f64, mut n: i32) -> f64 {
+    while n > 1023 {
+        x *= f64::from_bits(0x7FE0_0000_0000_0000); // 2^1023
+        n -= 1023;
+    }
+
+    while n < -1022 {
+        x *= f64::MIN_POSITIVE; // 2^-1022
+        n += 1022;
+    }
+
+    x * f64::from_bits(((n + 1023) as u64) << 52)
+}
@@ -1,14 +1,35 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod blend;
 mod color;
+mod curve;
+mod fixed;
+mod float;
 mod matrix;
+mod quat;
+mod random;
 mod rect;
+mod scalar;
+mod shape;
+mod size;
 mod vector;
 
+pub mod easing;
+
+pub use blend::*;
 pub use color::*;
+pub use curve::*;
+pub use fixed::*;
 pub use matrix::*;
+pub use quat::*;
+pub use random::*;
 pub use rect::*;
+pub use scalar::*;
+pub use shape::*;
+pub use size::*;
 pub use vector::*;
 
+pub use easing::Lerp;
+
 #[cfg(feature = "std")]
 mod std;
@@ -5,50 +5,417 @@
 //! This module defines matrix structures and provides functions for matrix
 //! operations, such as multiplication, transformation, and other common
 //! linear algebra tasks.  Support for various matrix sizes (e.g., 2x2, 3x3, 4x4).
+//!
+//! Matrices are generic over their `Scalar` element type; `Mat2`, `Mat3` and `Mat4`
+//! are the `f32` versions, and `FixedMat2`, `FixedMat3` and `FixedMat4` are the
+//! `Fixed` versions for targets without an FPU.
+//!
+//! Elements are stored in column-major order, matching GPU conventions, and
+//! vectors are treated as columns: `a * b` applies `b` first, then `a`. The 3D
+//! helpers use a right-handed coordinate system with clip-space depth in
+//! `[-1, 1]`, and screen coordinates with y pointing down.
+
+use crate::{float, Fixed, Rect, Scalar, Vec2, Vec3, Vector3, Vector4};
 
 // --------------------------------- Mat2 ---------------------------------- //
 
-pub struct Mat2 {
-    pub elements: [f32; 4],
+/// A 2x2 matrix of `f32` elements.
+pub type Mat2 = Matrix2<f32>;
+
+/// A 2x2 matrix of `Fixed` elements.
+pub type FixedMat2 = Matrix2<Fixed>;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Matrix2<T> {
+    pub elements: [T; 4],
 }
 
-impl Mat2 {
+impl<T: Scalar> Matrix2<T> {
     #[inline(always)]
     pub const fn identity() -> Self {
         Self {
-            elements: [1.0, 0.0, 0.0, 1.0],
+            elements: [T::ONE, T::ZERO, T::ZERO, T::ONE],
         }
     }
 }
 
 // --------------------------------- Mat3 ---------------------------------- //
 
-pub struct Mat3 {
-    pub elements: [f32; 9],
+/// A 3x3 matrix of `f32` elements.
+pub type Mat3 = Matrix3<f32>;
+
+/// A 3x3 matrix of `Fixed` elements.
+pub type FixedMat3 = Matrix3<Fixed>;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Matrix3<T> {
+    pub elements: [T; 9],
 }
 
-impl Mat3 {
+impl<T: Scalar> Matrix3<T> {
     #[inline(always)]
     pub const fn identity() -> Self {
         Self {
-            elements: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            elements: [
+                T::ONE,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ONE,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ONE,
+            ],
         }
     }
 }
 
 // --------------------------------- Mat4 ---------------------------------- //
 
-pub struct Mat4 {
-    pub elements: [f32; 16],
+/// A 4x4 matrix of `f32` elements.
+pub type Mat4 = Matrix4<f32>;
+
+/// A 4x4 matrix of `Fixed` elements.
+pub type FixedMat4 = Matrix4<Fixed>;
+
+/// A 4x4 matrix; element `elements[column * 4 + row]` is at the given column and row.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Matrix4<T> {
+    pub elements: [T; 16],
 }
 
-impl Mat4 {
+impl<T: Scalar> Matrix4<T> {
     #[inline(always)]
     pub const fn identity() -> Self {
         Self {
             elements: [
-                1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+                T::ONE,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ONE,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ONE,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ZERO,
+                T::ONE,
             ],
         }
     }
+
+    /// Creates a matrix that translates by `offset`.
+    #[inline]
+    pub const fn from_translation(offset: Vector3<T>) -> Self {
+        let mut matrix = Self::identity();
+
+        matrix.elements[12] = offset.x;
+        matrix.elements[13] = offset.y;
+        matrix.elements[14] = offset.z;
+
+        matrix
+    }
+
+    /// Creates a matrix that scales each axis by the matching component of `scale`.
+    #[inline]
+    pub const fn from_scale(scale: Vector3<T>) -> Self {
+        let mut matrix = Self::identity();
+
+        matrix.elements[0] = scale.x;
+        matrix.elements[5] = scale.y;
+        matrix.elements[10] = scale.z;
+
+        matrix
+    }
+
+    /// Returns the column at `index`.
+    #[inline(always)]
+    pub fn column(&self, index: usize) -> Vector4<T> {
+        let e = &self.elements[index * 4..index * 4 + 4];
+
+        Vector4::new(e[0], e[1], e[2], e[3])
+    }
+
+    /// Returns the transposed matrix.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        let mut elements = self.elements;
+
+        for column in 0..4 {
+            for row in 0..4 {
+                elements[row * 4 + column] = self.elements[column * 4 + row];
+            }
+        }
+
+        Self { elements }
+    }
+
+    /// Transforms `point`, including translation and the perspective divide.
+    #[inline]
+    pub fn transform_point3(&self, point: Vector3<T>) -> Vector3<T> {
+        let result = *self * point.extend(T::ONE);
+
+        result.truncate() / result.w
+    }
+
+    /// Transforms the direction `vector`, ignoring translation.
+    #[inline]
+    pub fn transform_vector3(&self, vector: Vector3<T>) -> Vector3<T> {
+        (*self * vector.extend(T::ZERO)).truncate()
+    }
+
+    /// Returns the determinant.
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let m = Minors::new(&self.elements);
+
+        m.determinant()
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let a = &self.elements;
+        let m = Minors::new(a);
+        let det = m.determinant();
+
+        if det == T::ZERO {
+            return None;
+        }
+
+        let b = &m.0;
+
+        Some(Self {
+            elements: [
+                (a[5] * b[11] - a[6] * b[10] + a[7] * b[9]) / det,
+                (a[2] * b[10] - a[1] * b[11] - a[3] * b[9]) / det,
+                (a[13] * b[5] - a[14] * b[4] + a[15] * b[3]) / det,
+                (a[10] * b[4] - a[9] * b[5] - a[11] * b[3]) / det,
+                (a[6] * b[8] - a[4] * b[11] - a[7] * b[7]) / det,
+                (a[0] * b[11] - a[2] * b[8] + a[3] * b[7]) / det,
+                (a[14] * b[2] - a[12] * b[5] - a[15] * b[1]) / det,
+                (a[8] * b[5] - a[10] * b[2] + a[11] * b[1]) / det,
+                (a[4] * b[10] - a[5] * b[8] + a[7] * b[6]) / det,
+                (a[1] * b[8] - a[0] * b[10] - a[3] * b[6]) / det,
+                (a[12] * b[4] - a[13] * b[2] + a[15] * b[0]) / det,
+                (a[9] * b[2] - a[8] * b[4] - a[11] * b[0]) / det,
+                (a[5] * b[7] - a[4] * b[9] - a[6] * b[6]) / det,
+                (a[0] * b[9] - a[1] * b[7] + a[2] * b[6]) / det,
+                (a[13] * b[1] - a[12] * b[3] - a[14] * b[0]) / det,
+                (a[8] * b[3] - a[9] * b[1] + a[10] * b[0]) / det,
+            ],
+        })
+    }
+}
+
+impl Mat4 {
+    /// Creates a right-handed perspective projection.
+    ///
+    /// `fov_y` is the vertical field of view in radians, and `aspect` the viewport
+    /// width divided by its height. Depth between `near` and `far` maps to `[-1, 1]`.
+    #[inline]
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / float::tan(fov_y * 0.5);
+        let range = 1.0 / (near - far);
+
+        let mut elements = [0.0; 16];
+
+        elements[0] = f / aspect;
+        elements[5] = f;
+        elements[10] = (far + near) * range;
+        elements[11] = -1.0;
+        elements[14] = 2.0 * far * near * range;
+
+        Self { elements }
+    }
+
+    /// Creates a right-handed orthographic projection of the given view volume.
+    #[inline]
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let mut matrix = Self::identity();
+
+        matrix.elements[0] = 2.0 / (right - left);
+        matrix.elements[5] = 2.0 / (top - bottom);
+        matrix.elements[10] = 2.0 / (near - far);
+        matrix.elements[12] = (left + right) / (left - right);
+        matrix.elements[13] = (bottom + top) / (bottom - top);
+        matrix.elements[14] = (near + far) / (near - far);
+
+        matrix
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking at `target`.
+    #[inline]
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let up = side.cross(forward);
+
+        Self {
+            elements: [
+                side.x,
+                up.x,
+                -forward.x,
+                0.0,
+                side.y,
+                up.y,
+                -forward.y,
+                0.0,
+                side.z,
+                up.z,
+                -forward.z,
+                0.0,
+                -side.dot(eye),
+                -up.dot(eye),
+                forward.dot(eye),
+                1.0,
+            ],
+        }
+    }
+
+    /// Projects a world `point` through this view-projection matrix to `viewport` coordinates.
+    ///
+    /// The returned `z` is the depth in `[0, 1]`, or `None` is returned if the
+    /// point lies on the camera plane.
+    #[inline]
+    pub fn project(&self, point: Vec3, viewport: &Rect) -> Option<Vec3> {
+        let clip = *self * point.extend(1.0);
+
+        if clip.w == 0.0 {
+            return None;
+        }
+
+        let ndc = clip.truncate() / clip.w;
+
+        Some(Vec3::new(
+            viewport.x + (ndc.x + 1.0) * 0.5 * viewport.width,
+            viewport.y + (1.0 - ndc.y) * 0.5 * viewport.height,
+            (ndc.z + 1.0) * 0.5,
+        ))
+    }
+
+    /// Maps a `viewport` point with depth in `[0, 1]` back to world space, the inverse of `project`.
+    ///
+    /// Returns `None` if this view-projection matrix isn't invertible.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_math::{Mat4, Rect, Vec3};
+    ///
+    /// let projection = Mat4::perspective(1.0, 16.0 / 9.0, 0.1, 100.0);
+    /// let view = Mat4::look_at(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO, Vec3::Y);
+    /// let view_projection = projection * view;
+    /// let viewport = Rect::new(0.0, 0.0, 1600.0, 900.0);
+    ///
+    /// let world = Vec3::new(1.0, 0.5, -1.0);
+    /// let screen = view_projection.project(world, &viewport).unwrap();
+    /// let back = view_projection.unproject(screen, &viewport).unwrap();
+    ///
+    /// assert!(back.distance(world) < 1e-3);
+    /// ```
+    #[inline]
+    pub fn unproject(&self, point: Vec3, viewport: &Rect) -> Option<Vec3> {
+        unproject(&self.inverse()?, point, viewport)
+    }
+
+    /// Returns the world-space ray through a `viewport` point, as an origin on the near
+    /// plane and a normalized direction, for picking.
+    #[inline]
+    pub fn pick_ray(&self, point: Vec2, viewport: &Rect) -> Option<(Vec3, Vec3)> {
+        let inverse = self.inverse()?;
+        let near = unproject(&inverse, Vec3::new(point.x, point.y, 0.0), viewport)?;
+        let far = unproject(&inverse, Vec3::new(point.x, point.y, 1.0), viewport)?;
+
+        Some((near, (far - near).normalize()))
+    }
+}
+
+impl<T: Scalar> core::ops::Mul for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        let mut elements = [T::ZERO; 16];
+
+        for column in 0..4 {
+            for row in 0..4 {
+                let mut sum = T::ZERO;
+
+                for k in 0..4 {
+                    sum = sum + self.elements[k * 4 + row] * other.elements[column * 4 + k];
+                }
+
+                elements[column * 4 + row] = sum;
+            }
+        }
+
+        Self { elements }
+    }
+}
+
+impl<T: Scalar> core::ops::Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    #[inline]
+    fn mul(self, vector: Vector4<T>) -> Vector4<T> {
+        self.column(0) * vector.x
+            + self.column(1) * vector.y
+            + self.column(2) * vector.z
+            + self.column(3) * vector.w
+    }
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// The 2x2 minors of the column pairs of a 4x4 matrix, shared by the determinant and inverse.
+struct Minors<T>([T; 12]);
+
+impl<T: Scalar> Minors<T> {
+    #[inline(always)]
+    fn new(a: &[T; 16]) -> Self {
+        Self([
+            a[0] * a[5] - a[1] * a[4],
+            a[0] * a[6] - a[2] * a[4],
+            a[0] * a[7] - a[3] * a[4],
+            a[1] * a[6] - a[2] * a[5],
+            a[1] * a[7] - a[3] * a[5],
+            a[2] * a[7] - a[3] * a[6],
+            a[8] * a[13] - a[9] * a[12],
+            a[8] * a[14] - a[10] * a[12],
+            a[8] * a[15] - a[11] * a[12],
+            a[9] * a[14] - a[10] * a[13],
+            a[9] * a[15] - a[11] * a[13],
+            a[10] * a[15] - a[11] * a[14],
+        ])
+    }
+
+    #[inline(always)]
+    fn determinant(&self) -> T {
+        let b = &self.0;
+
+        b[0] * b[11] - b[1] * b[10] + b[2] * b[9] + b[3] * b[8] - b[4] * b[7] + b[5] * b[6]
+    }
+}
+
+/// Maps a viewport point back to world space through an inverse view-projection matrix.
+#[inline]
+fn unproject(inverse: &Mat4, point: Vec3, viewport: &Rect) -> Option<Vec3> {
+    let ndc = Vec3::new(
+        (point.x - viewport.x) / viewport.width * 2.0 - 1.0,
+        1.0 - (point.y - viewport.y) / viewport.height * 2.0,
+        point.z * 2.0 - 1.0,
+    );
+
+    let world = *inverse * ndc.extend(1.0);
+
+    if world.w == 0.0 {
+        return None;
+    }
+
+    Some(world.truncate() / world.w)
 }
@@ -0,0 +1,357 @@
+// -------------------------------- quat.rs -------------------------------- //
+
+//! Quaternions for 3D rotations.
+//!
+//! Defines `Quat`, a rotation in 3D space that composes and interpolates
+//! without gimbal lock. Rotations can be built from an axis and angle, from
+//! Euler angles or from a rotation matrix, converted back to each, and blended
+//! with spherical linear interpolation (`slerp`).
+//!
+//! Euler angles use the yaw-pitch-roll order common for cameras: a rotation of
+//! `z` about the Z axis (roll) first, then `x` about the X axis (pitch), then
+//! `y` about the Y axis (yaw). All angles are in radians, and positive angles
+//! rotate counter-clockwise when looking down the axis towards the origin.
+
+use crate::{easing::Lerp, float, Mat3, Mat4, Vec3};
+
+/// Dot product above which `slerp` falls back to normalized linear interpolation.
+const SLERP_THRESHOLD: f32 = 0.9995;
+
+// --------------------------------- Quat ---------------------------------- //
+
+/// A rotation quaternion `w + xi + yj + zk`; rotations are expected to be unit length.
+///
+/// # Example
+/// ```
+/// use celui_math::{Quat, Vec3};
+/// use core::f32::consts::FRAC_PI_2;
+///
+/// let rotation = Quat::from_axis_angle(Vec3::Z, FRAC_PI_2);
+/// let rotated = rotation * Vec3::X;
+///
+/// assert!(rotated.distance(Vec3::Y) < 1e-6);
+///
+/// let halfway = Quat::IDENTITY.slerp(rotation, 0.5);
+/// let (axis, angle) = halfway.to_axis_angle();
+///
+/// assert!(axis.distance(Vec3::Z) < 1e-6);
+/// assert!((angle - FRAC_PI_2 / 2.0).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    /// The rotation that does nothing.
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    #[inline(always)]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, which is normalized first.
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        let (sin, cos) = float::sin_cos(angle * 0.5);
+        let axis = axis.normalize() * sin;
+
+        Self::new(axis.x, axis.y, axis.z, cos)
+    }
+
+    /// Returns the rotation axis and the angle in radians, in `[0, 2 * PI]`.
+    ///
+    /// The identity rotation returns the X axis and an angle of zero.
+    #[inline]
+    pub fn to_axis_angle(self) -> (Vec3, f32) {
+        let q = self.normalize();
+        let sin = float::sqrt(1.0 - (q.w * q.w).min(1.0));
+
+        if sin <= f32::EPSILON {
+            return (Vec3::X, 0.0);
+        }
+
+        (
+            Vec3::new(q.x, q.y, q.z) / sin,
+            2.0 * float::acos(q.w.clamp(-1.0, 1.0)),
+        )
+    }
+
+    /// Creates a rotation from Euler angles: roll `z` first, then pitch `x`, then yaw `y`.
+    #[inline]
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Self {
+        Self::from_axis_angle(Vec3::Y, y)
+            * Self::from_axis_angle(Vec3::X, x)
+            * Self::from_axis_angle(Vec3::Z, z)
+    }
+
+    /// Returns the Euler angles `(x, y, z)` of the rotation, the inverse of `from_euler`.
+    ///
+    /// Pitch is in `[-PI / 2, PI / 2]`; at those poles the roll is reported as zero.
+    pub fn to_euler(self) -> (f32, f32, f32) {
+        let m = Mat3::from_quat(self).elements;
+
+        // Column-major: element `m[column * 3 + row]`
+        let sin_x = -m[7];
+
+        if sin_x.abs() >= 1.0 - 1e-6 {
+            let x = core::f32::consts::FRAC_PI_2.copysign(sin_x);
+            let y = float::atan2(-m[2], m[0]);
+
+            return (x, y, 0.0);
+        }
+
+        let x = float::asin(sin_x);
+        let y = float::atan2(m[6], m[8]);
+        let z = float::atan2(m[1], m[4]);
+
+        (x, y, z)
+    }
+
+    /// Creates the rotation of a pure rotation matrix.
+    pub fn from_mat3(matrix: &Mat3) -> Self {
+        let m = &matrix.elements;
+        let (m00, m11, m22) = (m[0], m[4], m[8]);
+        let trace = m00 + m11 + m22;
+
+        // Pick the largest of `w`, `x`, `y` and `z` to divide by, for stability
+        let q = if trace > 0.0 {
+            let s = float::sqrt(trace + 1.0) * 2.0;
+
+            Self::new(
+                (m[5] - m[7]) / s,
+                (m[6] - m[2]) / s,
+                (m[1] - m[3]) / s,
+                s * 0.25,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = float::sqrt(1.0 + m00 - m11 - m22) * 2.0;
+
+            Self::new(
+                s * 0.25,
+                (m[3] + m[1]) / s,
+                (m[6] + m[2]) / s,
+                (m[5] - m[7]) / s,
+            )
+        } else if m11 > m22 {
+            let s = float::sqrt(1.0 + m11 - m00 - m22) * 2.0;
+
+            Self::new(
+                (m[3] + m[1]) / s,
+                s * 0.25,
+                (m[7] + m[5]) / s,
+                (m[6] - m[2]) / s,
+            )
+        } else {
+            let s = float::sqrt(1.0 + m22 - m00 - m11) * 2.0;
+
+            Self::new(
+                (m[6] + m[2]) / s,
+                (m[7] + m[5]) / s,
+                s * 0.25,
+                (m[1] - m[3]) / s,
+            )
+        };
+
+        q.normalize()
+    }
+
+    /// Creates the rotation of the upper-left 3x3 part of a matrix without scale or shear.
+    #[inline]
+    pub fn from_mat4(matrix: &Mat4) -> Self {
+        Self::from_mat3(&Mat3::from_mat4(matrix))
+    }
+
+    /// Returns the dot product of `self` and `other`.
+    #[inline(always)]
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns the length of the quaternion.
+    #[inline(always)]
+    pub fn length(self) -> f32 {
+        float::sqrt(self.dot(self))
+    }
+
+    /// Returns the quaternion scaled to unit length, or the identity if its length is zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+
+        if length > 0.0 {
+            Self::new(
+                self.x / length,
+                self.y / length,
+                self.z / length,
+                self.w / length,
+            )
+        } else {
+            Self::IDENTITY
+        }
+    }
+
+    /// Returns the conjugate, which is the inverse rotation for unit quaternions.
+    #[inline(always)]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse, or the identity if the length is zero.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let length_squared = self.dot(self);
+
+        if length_squared == 0.0 {
+            return Self::IDENTITY;
+        }
+
+        let c = self.conjugate();
+
+        Self::new(
+            c.x / length_squared,
+            c.y / length_squared,
+            c.z / length_squared,
+            c.w / length_squared,
+        )
+    }
+
+    /// Interpolates along the shortest arc between the rotations `self` and `other`.
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let mut other = other;
+        let mut dot = self.dot(other);
+
+        // `q` and `-q` are the same rotation; take the shorter way around
+        if dot < 0.0 {
+            other = Self::new(-other.x, -other.y, -other.z, -other.w);
+            dot = -dot;
+        }
+
+        if dot > SLERP_THRESHOLD {
+            return Self::new(
+                self.x + (other.x - self.x) * t,
+                self.y + (other.y - self.y) * t,
+                self.z + (other.z - self.z) * t,
+                self.w + (other.w - self.w) * t,
+            )
+            .normalize();
+        }
+
+        let theta = float::acos(dot);
+        let sin = float::sin(theta);
+        let a = float::sin((1.0 - t) * theta) / sin;
+        let b = float::sin(t * theta) / sin;
+
+        Self::new(
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+            self.w * a + other.w * b,
+        )
+    }
+}
+
+impl Default for Quat {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl core::ops::Mul for Quat {
+    type Output = Self;
+
+    /// Composes two rotations; the result applies `other` first, then `self`.
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
+}
+
+impl core::ops::Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    /// Rotates `vector`.
+    #[inline]
+    fn mul(self, vector: Vec3) -> Vec3 {
+        let u = Vec3::new(self.x, self.y, self.z);
+        let t = u.cross(vector) * 2.0;
+
+        vector + t * self.w + u.cross(t)
+    }
+}
+
+impl Lerp for Quat {
+    #[inline(always)]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.slerp(other, t)
+    }
+}
+
+// ----------------------------- Conversions ------------------------------- //
+
+impl Mat3 {
+    /// Creates the rotation matrix of a unit quaternion.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        let Quat { x, y, z, w } = rotation;
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, yy, zz) = (x * x2, y * y2, z * z2);
+        let (xy, xz, yz) = (x * y2, x * z2, y * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+
+        Self {
+            elements: [
+                1.0 - yy - zz,
+                xy + wz,
+                xz - wy,
+                xy - wz,
+                1.0 - xx - zz,
+                yz + wx,
+                xz + wy,
+                yz - wx,
+                1.0 - xx - yy,
+            ],
+        }
+    }
+
+    /// Returns the upper-left 3x3 part of `matrix`.
+    #[inline]
+    pub fn from_mat4(matrix: &Mat4) -> Self {
+        let m = &matrix.elements;
+
+        Self {
+            elements: [m[0], m[1], m[2], m[4], m[5], m[6], m[8], m[9], m[10]],
+        }
+    }
+}
+
+impl Mat4 {
+    /// Creates the rotation matrix of a unit quaternion.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        let m = Mat3::from_quat(rotation).elements;
+
+        Self {
+            elements: [
+                m[0], m[1], m[2], 0.0, m[3], m[4], m[5], 0.0, m[6], m[7], m[8], 0.0, 0.0, 0.0, 0.0,
+                1.0,
+            ],
+        }
+    }
+
+    /// Creates a matrix that scales, then rotates, then translates.
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+        Self::from_translation(translation) * Self::from_quat(rotation) * Self::from_scale(scale)
+    }
+}
@@ -0,0 +1,363 @@
+// ------------------------------- random.rs ------------------------------- //
+
+//! Deterministic pseudo-random number generation.
+//!
+//! Provides the `Rng` trait with uniform integers, floats and ranges, plus
+//! helpers for random points, colors and shuffling, and two small seedable
+//! generators implementing it: `Pcg32` and `Xoshiro256PlusPlus`. The same seed
+//! always produces the same sequence on every platform, which keeps tests and
+//! procedural effects reproducible.
+//!
+//! These generators are fast but predictable; never use them for anything
+//! security-sensitive.
+
+use crate::{Color, Rect, Vec2};
+
+// --------------------------------- Rng ----------------------------------- //
+
+/// A source of uniformly distributed pseudo-random numbers.
+///
+/// Implementors only provide `next_u32` and `next_u64`; everything else is derived.
+///
+/// # Example
+/// ```
+/// use celui_math::{Pcg32, Rect, Rng};
+///
+/// let mut rng = Pcg32::new(42);
+///
+/// let die = rng.range_u32(1..7);
+/// let jitter = rng.range_f32(-0.5..0.5);
+/// let point = rng.in_rect(&Rect::new(10.0, 10.0, 100.0, 50.0));
+///
+/// assert!((1..7).contains(&die));
+/// assert!((-0.5..0.5).contains(&jitter));
+/// assert!(point.x >= 10.0 && point.x < 110.0 && point.y >= 10.0 && point.y < 60.0);
+///
+/// // The same seed always yields the same sequence
+/// assert_eq!(Pcg32::new(7).next_u32(), Pcg32::new(7).next_u32());
+///
+/// let mut items = [1, 2, 3, 4, 5];
+///
+/// rng.shuffle(&mut items);
+/// items.sort();
+///
+/// assert_eq!(items, [1, 2, 3, 4, 5]);
+/// ```
+pub trait Rng {
+    /// Returns a uniformly distributed `u32`.
+    fn next_u32(&mut self) -> u32;
+
+    /// Returns a uniformly distributed `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed `f32` in `[0.0, 1.0)`.
+    #[inline(always)]
+    fn next_f32(&mut self) -> f32 {
+        // The top 24 bits fill the mantissa exactly
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Returns a uniformly distributed `f64` in `[0.0, 1.0)`.
+    #[inline(always)]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns `true` or `false` with equal probability.
+    #[inline(always)]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >> 31 == 1
+    }
+
+    /// Returns `true` with the given probability; values outside `[0.0, 1.0]` act as the nearest bound.
+    #[inline(always)]
+    fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// Returns a uniformly distributed `u32` in `range`, without modulo bias.
+    ///
+    /// # Panics
+    /// Panics if `range` is empty.
+    #[inline]
+    fn range_u32(&mut self, range: core::ops::Range<u32>) -> u32 {
+        assert!(range.start < range.end, "cannot sample an empty range");
+
+        range.start + bounded_u32(self, range.end - range.start)
+    }
+
+    /// Returns a uniformly distributed `i32` in `range`, without modulo bias.
+    ///
+    /// # Panics
+    /// Panics if `range` is empty.
+    #[inline]
+    fn range_i32(&mut self, range: core::ops::Range<i32>) -> i32 {
+        assert!(range.start < range.end, "cannot sample an empty range");
+
+        let span = range.end.abs_diff(range.start);
+
+        range.start.wrapping_add(bounded_u32(self, span) as i32)
+    }
+
+    /// Returns a uniformly distributed `usize` in `range`, without modulo bias.
+    ///
+    /// # Panics
+    /// Panics if `range` is empty.
+    #[inline]
+    fn range_usize(&mut self, range: core::ops::Range<usize>) -> usize {
+        assert!(range.start < range.end, "cannot sample an empty range");
+
+        range.start + bounded_u64(self, (range.end - range.start) as u64) as usize
+    }
+
+    /// Returns a uniformly distributed `f32` in `range`.
+    ///
+    /// Rounding may rarely produce `range.end` itself when the bounds are far apart.
+    #[inline(always)]
+    fn range_f32(&mut self, range: core::ops::Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.next_f32()
+    }
+
+    /// Returns a uniformly distributed point inside `rect`.
+    #[inline]
+    fn in_rect(&mut self, rect: &Rect) -> Vec2 {
+        Vec2::new(
+            rect.x + rect.width * self.next_f32(),
+            rect.y + rect.height * self.next_f32(),
+        )
+    }
+
+    /// Returns a uniformly distributed opaque color.
+    #[inline(always)]
+    fn color(&mut self) -> Color {
+        Color::from_rgb_u32(self.next_u32() >> 8)
+    }
+
+    /// Returns a uniformly distributed color, alpha included.
+    #[inline(always)]
+    fn color_with_alpha(&mut self) -> Color {
+        Color::from_rgba_u32(self.next_u32())
+    }
+
+    /// Returns a random element of `slice`, or `None` if it is empty.
+    #[inline]
+    fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        slice.get(self.range_usize(0..slice.len()))
+    }
+
+    /// Shuffles `slice` in place, with every permutation equally likely.
+    ///
+    /// A `celui_collections::Vec` can be shuffled through its slice.
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        // Fisher-Yates, from the back
+        for i in (1..slice.len()).rev() {
+            slice.swap(i, self.range_usize(0..i + 1));
+        }
+    }
+}
+
+// -------------------------------- Pcg32 ---------------------------------- //
+
+/// The PCG-XSH-RR 64/32 generator: 64 bits of state and 32-bit output.
+///
+/// Small and fast, with independent sequences selected by `with_stream`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+    const DEFAULT_STREAM: u64 = 0xDA3E_39CB_94B9_5BDB;
+
+    /// Creates a generator from `seed`, on the default stream.
+    #[inline(always)]
+    pub const fn new(seed: u64) -> Self {
+        Self::with_stream(seed, Self::DEFAULT_STREAM)
+    }
+
+    /// Creates a generator from `seed` on one of `2^63` independent streams.
+    #[inline]
+    pub const fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+
+        rng
+    }
+
+    #[inline(always)]
+    const fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl Rng for Pcg32 {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+
+        self.step();
+
+        // XSH-RR output permutation
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rotation = (state >> 59) as u32;
+
+        xorshifted.rotate_right(rotation)
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let high = self.next_u32() as u64;
+        let low = self.next_u32() as u64;
+
+        (high << 32) | low
+    }
+}
+
+// -------------------------- Xoshiro256PlusPlus --------------------------- //
+
+/// The xoshiro256++ generator: 256 bits of state and 64-bit output.
+///
+/// Faster than `Pcg32` for 64-bit output and with a much longer period.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Xoshiro256PlusPlus {
+    state: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Creates a generator from `seed`, expanded to the full state with SplitMix64.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        let mut splitmix = seed;
+
+        Self {
+            state: [
+                splitmix64(&mut splitmix),
+                splitmix64(&mut splitmix),
+                splitmix64(&mut splitmix),
+                splitmix64(&mut splitmix),
+            ],
+        }
+    }
+
+    /// Creates a generator from its full state, which must not be all zeros.
+    #[inline(always)]
+    pub const fn from_state(state: [u64; 4]) -> Self {
+        assert!(
+            state[0] | state[1] | state[2] | state[3] != 0,
+            "the state must not be all zeros"
+        );
+
+        Self { state }
+    }
+
+    /// Advances the state by `2^128` steps, to split off non-overlapping sequences.
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180E_C6D3_3CFD_0ABA,
+            0xD5A6_1266_F0C9_392C,
+            0xA958_2618_E03F_C9AA,
+            0x39AB_DC45_29B1_661C,
+        ];
+
+        let mut state = [0; 4];
+
+        for word in JUMP {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (s, current) in state.iter_mut().zip(self.state) {
+                        *s ^= current;
+                    }
+                }
+
+                self.next_u64();
+            }
+        }
+
+        self.state = state;
+    }
+}
+
+impl Rng for Xoshiro256PlusPlus {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Advances a SplitMix64 state and returns its next output.
+#[inline(always)]
+const fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *state;
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+/// Returns a uniform `u32` in `[0, bound)` using Lemire's multiply-and-reject method.
+#[inline]
+fn bounded_u32<R: Rng + ?Sized>(rng: &mut R, bound: u32) -> u32 {
+    let mut product = rng.next_u32() as u64 * bound as u64;
+
+    if (product as u32) < bound {
+        let threshold = bound.wrapping_neg() % bound;
+
+        while (product as u32) < threshold {
+            product = rng.next_u32() as u64 * bound as u64;
+        }
+    }
+
+    (product >> 32) as u32
+}
+
+/// Returns a uniform `u64` in `[0, bound)` using Lemire's multiply-and-reject method.
+#[inline]
+fn bounded_u64<R: Rng + ?Sized>(rng: &mut R, bound: u64) -> u64 {
+    let mut product = rng.next_u64() as u128 * bound as u128;
+
+    if (product as u64) < bound {
+        let threshold = bound.wrapping_neg() % bound;
+
+        while (product as u64) < threshold {
+            product = rng.next_u64() as u128 * bound as u128;
+        }
+    }
+
+    (product >> 64) as u64
+}
@@ -5,20 +5,40 @@
 //! Defines the `Rect` struct, providing a foundation for calculating various
 //! geometric properties and spatial relationships of rectangles, such as
 //! intersection, containment, and more.
+//!
+//! `Rect` is the `f32` version of the generic `Rectangle`, and `FixedRect` the
+//! `Fixed` version for targets without an FPU. `IRect` is the integer version
+//! for pixel-exact regions such as framebuffer areas, texture sub-rectangles and
+//! dirty rectangles; it supports clipping and iterating over the covered pixels.
+//!
+//! Edges are computed with saturating arithmetic: a rectangle reaching past the
+//! numeric range of its coordinates is treated as ending at the bound, so an
+//! `IRect` with `x + width > i32::MAX` covers pixels up to `i32::MAX`, excluded.
+
+use crate::{float, scalar, Fixed, IVec2, Point, Scalar, Size, Vector2};
 
 // --------------------------------- Rect ---------------------------------- //
 
-#[derive(Clone, Copy, PartialEq)]
-pub struct Rect {
-    pub x: f32,
-    pub y: f32,
-    pub width: f32,
-    pub height: f32,
+/// A rectangle with `f32` coordinates.
+pub type Rect = Rectangle<f32>;
+
+/// A rectangle with `Fixed` coordinates.
+pub type FixedRect = Rectangle<Fixed>;
+
+/// A rectangle with `i32` coordinates, covering whole pixels.
+pub type IRect = Rectangle<i32>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rectangle<T> {
+    pub x: T,
+    pub y: T,
+    pub width: T,
+    pub height: T,
 }
 
-impl Rect {
+impl<T: Scalar> Rectangle<T> {
     #[inline(always)]
-    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+    pub const fn new(x: T, y: T, width: T, height: T) -> Self {
         Self {
             x,
             y,
@@ -26,4 +46,282 @@ impl Rect {
             height,
         }
     }
+
+    /// Creates the smallest rectangle spanning the two corners `min` and `max`.
+    ///
+    /// The size saturates if the corners are further apart than the numeric range allows.
+    #[inline(always)]
+    pub fn from_min_max(min: Vector2<T>, max: Vector2<T>) -> Self {
+        Self::new(
+            min.x,
+            min.y,
+            max.x.saturating_sub(min.x),
+            max.y.saturating_sub(min.y),
+        )
+    }
+
+    /// Returns the top-left corner of the rectangle.
+    #[inline(always)]
+    pub fn min(&self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Returns the bottom-right corner of the rectangle, saturating at the numeric bounds.
+    #[inline(always)]
+    pub fn max(&self) -> Vector2<T> {
+        Vector2::new(
+            self.x.saturating_add(self.width),
+            self.y.saturating_add(self.height),
+        )
+    }
+
+    /// Returns the center of the rectangle.
+    #[inline]
+    pub fn center(&self) -> Vector2<T> {
+        let two = T::ONE + T::ONE;
+
+        Vector2::new(
+            self.x.saturating_add(self.width / two),
+            self.y.saturating_add(self.height / two),
+        )
+    }
+
+    /// Returns the four corners, clockwise from the top-left in a y-down coordinate system.
+    #[inline(always)]
+    pub fn corners(&self) -> [Vector2<T>; 4] {
+        let (min, max) = (self.min(), self.max());
+
+        [
+            min,
+            Vector2::new(max.x, min.y),
+            max,
+            Vector2::new(min.x, max.y),
+        ]
+    }
+
+    /// Returns `true` if the rectangle has no area.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        !(self.width > T::ZERO && self.height > T::ZERO)
+    }
+
+    /// Returns `true` if `point` lies inside the rectangle (the right and bottom edges excluded).
+    #[inline(always)]
+    pub fn contains(&self, point: Vector2<T>) -> bool {
+        let max = self.max();
+
+        point.x >= self.x && point.y >= self.y && point.x < max.x && point.y < max.y
+    }
+
+    /// Returns the point of the rectangle nearest to `point`, the edges included.
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        point.max(self.min()).min(self.max())
+    }
+
+    /// Returns `true` if the two rectangles overlap.
+    #[inline(always)]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let (max, other_max) = (self.max(), other.max());
+
+        self.x < other_max.x && other.x < max.x && self.y < other_max.y && other.y < max.y
+    }
+
+    /// Returns the overlapping area of the two rectangles, or `None` if they don't overlap.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        Some(Self::from_min_max(
+            self.min().max(other.min()),
+            self.max().min(other.max()),
+        ))
+    }
+
+    /// Returns the smallest rectangle containing both rectangles.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_min_max(self.min().min(other.min()), self.max().max(other.max()))
+    }
+}
+
+impl Rect {
+    /// Returns the smallest `IRect` covering the rectangle, or `None` if it is NaN or out of range.
+    #[inline]
+    pub fn try_to_irect(&self) -> Option<IRect> {
+        let min = self.min();
+        let max = self.max();
+
+        let x = scalar::f32_to_i32(float::floor(min.x))?;
+        let y = scalar::f32_to_i32(float::floor(min.y))?;
+        let right = scalar::f32_to_i32(float::ceil(max.x))?;
+        let bottom = scalar::f32_to_i32(float::ceil(max.y))?;
+
+        Some(IRect::new(
+            x,
+            y,
+            right.checked_sub(x)?,
+            bottom.checked_sub(y)?,
+        ))
+    }
+}
+
+impl IRect {
+    /// Creates a rectangle from its top-left corner and size, or `None` if it exceeds the `i32` range.
+    #[inline]
+    pub fn from_point_size(point: Point, size: Size) -> Option<Self> {
+        let width = i32::try_from(size.width).ok()?;
+        let height = i32::try_from(size.height).ok()?;
+
+        point.x.checked_add(width)?;
+        point.y.checked_add(height)?;
+
+        Some(Self::new(point.x, point.y, width, height))
+    }
+
+    /// Returns the size of the rectangle, with negative extents treated as zero.
+    #[inline(always)]
+    pub fn size(&self) -> Size {
+        Size::new(self.width.max(0) as u32, self.height.max(0) as u32)
+    }
+
+    /// Converts to a `Rect`; values beyond `±2^24` are rounded to the nearest `f32`.
+    #[inline(always)]
+    pub fn to_rect(&self) -> Rect {
+        Rect::new(
+            self.x as f32,
+            self.y as f32,
+            self.width as f32,
+            self.height as f32,
+        )
+    }
+
+    /// Returns the rectangle moved by `offset`.
+    ///
+    /// The top-left corner saturates at the `i32` range, so a rectangle moved past it stops at
+    /// the bound instead of wrapping around.
+    #[inline(always)]
+    pub fn translate(&self, offset: IVec2) -> Self {
+        Self::new(
+            self.x.saturating_add(offset.x),
+            self.y.saturating_add(offset.y),
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Clips the rectangle to a region of `size` at the origin, or returns `None` if nothing remains.
+    ///
+    /// # Example
+    /// ```
+    /// use celui_math::{IRect, Size};
+    ///
+    /// let dirty = IRect::new(-10, 20, 50, 50);
+    /// let clipped = dirty.clip_to_size(Size::new(100, 60)).unwrap();
+    ///
+    /// assert!(clipped == IRect::new(0, 20, 40, 40));
+    /// assert_eq!(clipped.pixels().len(), 1600);
+    /// ```
+    #[inline]
+    pub fn clip_to_size(&self, size: Size) -> Option<Self> {
+        let bounds = Self::new(
+            0,
+            0,
+            size.width.min(i32::MAX as u32) as i32,
+            size.height.min(i32::MAX as u32) as i32,
+        );
+
+        self.intersection(&bounds)
+    }
+
+    /// Returns an iterator over the covered pixels, row by row.
+    #[inline(always)]
+    pub fn pixels(&self) -> Pixels {
+        Pixels::new(*self)
+    }
+}
+
+// -------------------------------- Pixels --------------------------------- //
+
+/// An iterator over the pixels covered by an `IRect`, row by row.
+///
+/// Created by `IRect::pixels`.
+#[derive(Clone)]
+pub struct Pixels {
+    /// Left edge, where each row starts.
+    left: i32,
+    /// Exclusive right and bottom edges.
+    end: Point,
+    /// Next pixel to yield.
+    next: Point,
+}
+
+impl Pixels {
+    #[inline(always)]
+    fn new(rect: IRect) -> Self {
+        let mut end = rect.max();
+
+        // Empty rectangles yield nothing, as do those starting at the `i32::MAX` edge, whose
+        // `max()` saturates back onto their `min()`
+        if end.x <= rect.x || end.y <= rect.y {
+            end = rect.min();
+        }
+
+        Self {
+            left: rect.x,
+            end,
+            next: rect.min(),
+        }
+    }
+
+    /// Returns the number of pixels left to yield.
+    #[inline(always)]
+    fn remaining(&self) -> u64 {
+        if self.next.y >= self.end.y {
+            return 0;
+        }
+
+        let width = (self.end.x - self.left) as u64;
+        let rows = (self.end.y - self.next.y) as u64;
+
+        rows * width - (self.next.x - self.left) as u64
+    }
+}
+
+impl Iterator for Pixels {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Point> {
+        if self.next.y >= self.end.y {
+            return None;
+        }
+
+        let pixel = self.next;
+
+        self.next.x += 1;
+
+        if self.next.x >= self.end.x {
+            self.next.x = self.left;
+            self.next.y += 1;
+        }
+
+        Some(pixel)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
 }
+
+impl ExactSizeIterator for Pixels {}
+
+impl core::iter::FusedIterator for Pixels {}
@@ -0,0 +1,193 @@
+// ------------------------------- scalar.rs ------------------------------- //
+
+//! Numeric traits for the component types of vectors, rectangles and matrices.
+//!
+//! `Scalar` covers the arithmetic every geometric type needs, while `Real` adds
+//! the operations that need fractional values (square roots, trigonometry).
+//! Both are implemented for `f32` and for the fixed-point `Fixed`, so the same
+//! geometry code runs on targets with and without an FPU. `Scalar` is also
+//! implemented for `i32` and `u32`, for pixel-exact integer geometry.
+
+use crate::float;
+
+// -------------------------------- Scalar --------------------------------- //
+
+/// A numeric type usable as the component of vectors, rectangles and matrices.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Adds two values, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// Floats have no bounds to saturate at, and round to infinity instead.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Subtracts two values, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// Floats have no bounds to saturate at, and round to infinity instead.
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+// --------------------------------- Real ---------------------------------- //
+
+/// A signed `Scalar` that approximates real numbers.
+pub trait Real: Scalar + core::ops::Neg<Output = Self> {
+    /// Converts an `f32` to the nearest representable value.
+    fn from_f32(value: f32) -> Self;
+
+    /// Converts the value to the nearest `f32`.
+    fn to_f32(self) -> f32;
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns the square root, or zero for negative values where NaN can't be represented.
+    fn sqrt(self) -> Self;
+
+    /// Returns the sine of an angle in radians.
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of an angle in radians.
+    fn cos(self) -> Self;
+
+    /// Returns the four-quadrant arctangent of `self / x`, in radians.
+    fn atan2(self, x: Self) -> Self;
+}
+
+// ------------------------------ Primitives ------------------------------- //
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[inline(always)]
+    fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self {
+        self - other
+    }
+}
+
+impl Scalar for i32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    #[inline(always)]
+    fn saturating_add(self, other: Self) -> Self {
+        i32::saturating_add(self, other)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self {
+        i32::saturating_sub(self, other)
+    }
+}
+
+impl Scalar for u32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    #[inline(always)]
+    fn saturating_add(self, other: Self) -> Self {
+        u32::saturating_add(self, other)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self {
+        u32::saturating_sub(self, other)
+    }
+}
+
+impl Real for f32 {
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    #[inline(always)]
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        float::sqrt(self)
+    }
+
+    #[inline(always)]
+    fn sin(self) -> Self {
+        float::sin(self)
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Self {
+        float::cos(self)
+    }
+
+    #[inline(always)]
+    fn atan2(self, x: Self) -> Self {
+        float::atan2(self, x)
+    }
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Returns the smaller of `a` and `b`, or `a` if they are unordered.
+#[inline(always)]
+pub(crate) fn min<T: Scalar>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the larger of `a` and `b`, or `a` if they are unordered.
+#[inline(always)]
+pub(crate) fn max<T: Scalar>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Converts an integral `f32` to `i32`, or returns `None` if it is NaN or out of range.
+#[inline(always)]
+pub(crate) fn f32_to_i32(value: f32) -> Option<i32> {
+    // `i32::MIN` is exactly representable, `i32::MAX` rounds up to `2^31`
+    if value >= i32::MIN as f32 && value < i32::MAX as f32 {
+        Some(value as i32)
+    } else {
+        None
+    }
+}
+
+/// Converts an integral `f32` to `u32`, or returns `None` if it is NaN or out of range.
+#[inline(always)]
+pub(crate) fn f32_to_u32(value: f32) -> Option<u32> {
+    // `u32::MAX` rounds up to `2^32`
+    if value >= 0.0 && value < u32::MAX as f32 {
+        Some(value as u32)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,748 @@
+// ------------------------------- shape.rs -------------------------------- //
+
+//! Geometric primitives and intersection tests.
+//!
+//! Defines `Segment`, `Ray`, `Circle`, `Ellipse` and `Polygon`, with point
+//! containment, closest-point and distance queries, and intersection tests
+//! against each other and against `Rect`. These back hit-testing for
+//! non-rectangular widgets, such as knobs and pie menus.
+//!
+//! Filled shapes (circles, ellipses and polygons) include their boundary, and a
+//! point inside them is its own closest point, at distance zero. Rectangles are
+//! treated the same way here, edges included.
+
+use crate::{float, Rect, Vec2};
+
+/// Number of refinement steps of the ellipse closest-point search.
+const ELLIPSE_ITERATIONS: usize = 4;
+
+// ------------------------------- Segment --------------------------------- //
+
+/// A line segment from `start` to `end`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+impl Segment {
+    #[inline(always)]
+    pub const fn new(start: Vec2, end: Vec2) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the point at `t`, where `0.0` is `start` and `1.0` is `end`.
+    #[inline(always)]
+    pub fn at(&self, t: f32) -> Vec2 {
+        self.start + (self.end - self.start) * t
+    }
+
+    /// Returns the length of the segment.
+    #[inline(always)]
+    pub fn length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+
+    /// Returns the smallest rectangle containing the segment.
+    #[inline(always)]
+    pub fn bounds(&self) -> Rect {
+        Rect::from_min_max(self.start.min(self.end), self.start.max(self.end))
+    }
+
+    /// Returns the point on the segment nearest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let direction = self.end - self.start;
+        let length_squared = direction.length_squared();
+
+        if length_squared == 0.0 {
+            return self.start;
+        }
+
+        let t = (point - self.start).dot(direction) / length_squared;
+
+        self.at(t.clamp(0.0, 1.0))
+    }
+
+    /// Returns the distance from `point` to the segment.
+    #[inline(always)]
+    pub fn distance(&self, point: Vec2) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns a point shared by the two segments, or `None` if they don't touch.
+    ///
+    /// Overlapping collinear segments return the shared point nearest to `self.start`.
+    pub fn intersection(&self, other: &Segment) -> Option<Vec2> {
+        let r = self.end - self.start;
+        let s = other.end - other.start;
+        let offset = other.start - self.start;
+        let denominator = r.cross(s);
+
+        if denominator != 0.0 {
+            let t = offset.cross(s) / denominator;
+            let u = offset.cross(r) / denominator;
+
+            if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+                return Some(self.at(t));
+            }
+
+            return None;
+        }
+
+        // Parallel segments only touch if they are collinear
+        if offset.cross(r) != 0.0 || offset.cross(s) != 0.0 {
+            return None;
+        }
+
+        let length_squared = r.length_squared();
+
+        if length_squared == 0.0 {
+            let touches = other.closest_point(self.start) == self.start;
+
+            return touches.then_some(self.start);
+        }
+
+        // Project `other` onto `self` and intersect the parameter ranges
+        let t0 = offset.dot(r) / length_squared;
+        let t1 = t0 + s.dot(r) / length_squared;
+        let low = t0.min(t1).max(0.0);
+        let high = t0.max(t1).min(1.0);
+
+        (low <= high).then(|| self.at(low))
+    }
+
+    /// Returns `true` if the two segments touch.
+    #[inline(always)]
+    pub fn intersects_segment(&self, other: &Segment) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns `true` if the segment touches `rect`.
+    #[inline(always)]
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.clip(rect).is_some()
+    }
+
+    /// Returns the part of the segment inside `rect`, or `None` if it lies outside.
+    pub fn clip(&self, rect: &Rect) -> Option<Segment> {
+        let direction = self.end - self.start;
+        let min = rect.min();
+        let max = rect.max();
+
+        let mut t0 = 0.0f32;
+        let mut t1 = 1.0f32;
+
+        // Liang-Barsky: narrow `[t0, t1]` against each of the four edges
+        for (p, q) in [
+            (-direction.x, self.start.x - min.x),
+            (direction.x, max.x - self.start.x),
+            (-direction.y, self.start.y - min.y),
+            (direction.y, max.y - self.start.y),
+        ] {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let r = q / p;
+
+            if p < 0.0 {
+                t0 = t0.max(r);
+            } else {
+                t1 = t1.min(r);
+            }
+
+            if t0 > t1 {
+                return None;
+            }
+        }
+
+        Some(Segment::new(self.at(t0), self.at(t1)))
+    }
+}
+
+// --------------------------------- Ray ----------------------------------- //
+
+/// A half-line starting at `origin` and extending along `direction`.
+///
+/// `direction` doesn't need to be normalized; the distances returned by the `cast_*`
+/// methods are in multiples of it, so `at(t)` gives the hit point.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec2,
+    pub direction: Vec2,
+}
+
+impl Ray {
+    #[inline(always)]
+    pub const fn new(origin: Vec2, direction: Vec2) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at `t` along the ray.
+    #[inline(always)]
+    pub fn at(&self, t: f32) -> Vec2 {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the point on the ray nearest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let length_squared = self.direction.length_squared();
+
+        if length_squared == 0.0 {
+            return self.origin;
+        }
+
+        let t = (point - self.origin).dot(self.direction) / length_squared;
+
+        self.at(t.max(0.0))
+    }
+
+    /// Returns the distance from `point` to the ray.
+    #[inline(always)]
+    pub fn distance(&self, point: Vec2) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns the `t` of the first hit with `segment`, ignoring collinear overlaps.
+    #[inline]
+    pub fn cast_segment(&self, segment: &Segment) -> Option<f32> {
+        let s = segment.end - segment.start;
+        let offset = segment.start - self.origin;
+        let denominator = self.direction.cross(s);
+
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let t = offset.cross(s) / denominator;
+        let u = offset.cross(self.direction) / denominator;
+
+        (t >= 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+    }
+
+    /// Returns the `t` of the first hit with `circle`, or `0.0` if the ray starts inside it.
+    #[inline]
+    pub fn cast_circle(&self, circle: &Circle) -> Option<f32> {
+        cast_circle(self.origin - circle.center, self.direction, circle.radius)
+    }
+
+    /// Returns the `t` of the first hit with `ellipse`, or `0.0` if the ray starts inside it.
+    #[inline]
+    pub fn cast_ellipse(&self, ellipse: &Ellipse) -> Option<f32> {
+        // Scaling space by the inverse radii keeps `t` and turns the ellipse into a unit circle
+        cast_circle(
+            ellipse.map_to_unit(self.origin),
+            ellipse.scale_map_to_unit(self.direction),
+            1.0,
+        )
+    }
+
+    /// Returns the `t` of the first hit with `rect`, or `0.0` if the ray starts inside it.
+    pub fn cast_rect(&self, rect: &Rect) -> Option<f32> {
+        let min = rect.min();
+        let max = rect.max();
+
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+
+        // Intersect the ray with the horizontal and vertical slabs of the rectangle
+        for (origin, direction, low, high) in [
+            (self.origin.x, self.direction.x, min.x, max.x),
+            (self.origin.y, self.direction.y, min.y, max.y),
+        ] {
+            if direction == 0.0 {
+                if origin < low || origin > high {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let t0 = (low - origin) / direction;
+            let t1 = (high - origin) / direction;
+
+            t_min = t_min.max(t0.min(t1));
+            t_max = t_max.min(t0.max(t1));
+
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        Some(t_min)
+    }
+
+    /// Returns the `t` of the first hit with `polygon`, or `0.0` if the ray starts inside it.
+    pub fn cast_polygon(&self, polygon: &Polygon<'_>) -> Option<f32> {
+        if polygon.contains(self.origin) {
+            return Some(0.0);
+        }
+
+        polygon
+            .edges()
+            .filter_map(|edge| self.cast_segment(&edge))
+            .reduce(f32::min)
+    }
+}
+
+// -------------------------------- Circle --------------------------------- //
+
+/// A filled circle.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl Circle {
+    #[inline(always)]
+    pub const fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the smallest rectangle containing the circle.
+    #[inline(always)]
+    pub fn bounds(&self) -> Rect {
+        let extent = Vec2::new(self.radius, self.radius);
+
+        Rect::from_min_max(self.center - extent, self.center + extent)
+    }
+
+    /// Returns `true` if `point` lies inside the circle.
+    #[inline(always)]
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// Returns the point of the circle nearest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains(point) {
+            return point;
+        }
+
+        self.center + (point - self.center).normalize() * self.radius
+    }
+
+    /// Returns the distance from `point` to the circle.
+    #[inline(always)]
+    pub fn distance(&self, point: Vec2) -> f32 {
+        (self.center.distance(point) - self.radius).max(0.0)
+    }
+
+    /// Returns `true` if the two circles overlap.
+    #[inline(always)]
+    pub fn intersects_circle(&self, other: &Circle) -> bool {
+        let radius = self.radius + other.radius;
+
+        self.center.distance_squared(other.center) <= radius * radius
+    }
+
+    /// Returns `true` if the circle overlaps `rect`.
+    #[inline(always)]
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.contains(rect.closest_point(self.center))
+    }
+
+    /// Returns `true` if the circle touches `segment`.
+    #[inline(always)]
+    pub fn intersects_segment(&self, segment: &Segment) -> bool {
+        self.contains(segment.closest_point(self.center))
+    }
+}
+
+// -------------------------------- Ellipse -------------------------------- //
+
+/// A filled, axis-aligned ellipse with positive `radii`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ellipse {
+    pub center: Vec2,
+    pub radii: Vec2,
+}
+
+impl Ellipse {
+    #[inline(always)]
+    pub const fn new(center: Vec2, radii: Vec2) -> Self {
+        Self { center, radii }
+    }
+
+    /// Returns the smallest rectangle containing the ellipse.
+    #[inline(always)]
+    pub fn bounds(&self) -> Rect {
+        Rect::from_min_max(self.center - self.radii, self.center + self.radii)
+    }
+
+    /// Returns `true` if `point` lies inside the ellipse.
+    #[inline(always)]
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.map_to_unit(point).length_squared() <= 1.0
+    }
+
+    /// Returns the point of the ellipse nearest to `point`.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains(point) {
+            return point;
+        }
+
+        let (a, b) = (self.radii.x, self.radii.y);
+        let offset = point - self.center;
+        let p = Vec2::new(offset.x.abs(), offset.y.abs());
+
+        // Iteratively fit the point on the evolute-approximating circle, working
+        // in the first quadrant with `(tx, ty)` as the normalized parameter
+        let mut tx = core::f32::consts::FRAC_1_SQRT_2;
+        let mut ty = core::f32::consts::FRAC_1_SQRT_2;
+
+        for _ in 0..ELLIPSE_ITERATIONS {
+            let x = a * tx;
+            let y = b * ty;
+
+            let evolute = Vec2::new(
+                (a * a - b * b) * tx * tx * tx / a,
+                (b * b - a * a) * ty * ty * ty / b,
+            );
+
+            let r = Vec2::new(x, y) - evolute;
+            let q = p - evolute;
+            let q_length = q.length();
+
+            if q_length == 0.0 {
+                break;
+            }
+
+            let scale = r.length() / q_length;
+
+            tx = ((q.x * scale + evolute.x) / a).clamp(0.0, 1.0);
+            ty = ((q.y * scale + evolute.y) / b).clamp(0.0, 1.0);
+
+            let length = float::sqrt(tx * tx + ty * ty);
+
+            tx /= length;
+            ty /= length;
+        }
+
+        self.center + Vec2::new((a * tx).copysign(offset.x), (b * ty).copysign(offset.y))
+    }
+
+    /// Returns the distance from `point` to the ellipse.
+    #[inline(always)]
+    pub fn distance(&self, point: Vec2) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns `true` if the ellipse overlaps `circle`.
+    #[inline(always)]
+    pub fn intersects_circle(&self, circle: &Circle) -> bool {
+        self.distance(circle.center) <= circle.radius
+    }
+
+    /// Returns `true` if the ellipse overlaps `rect`.
+    #[inline]
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        let rect = Rect::from_min_max(self.map_to_unit(rect.min()), self.map_to_unit(rect.max()));
+
+        Circle::new(Vec2::ZERO, 1.0).intersects_rect(&rect)
+    }
+
+    /// Returns `true` if the ellipse touches `segment`.
+    #[inline]
+    pub fn intersects_segment(&self, segment: &Segment) -> bool {
+        let segment = Segment::new(
+            self.map_to_unit(segment.start),
+            self.map_to_unit(segment.end),
+        );
+
+        Circle::new(Vec2::ZERO, 1.0).intersects_segment(&segment)
+    }
+
+    /// Maps `point` into the space where the ellipse is the unit circle.
+    #[inline(always)]
+    fn map_to_unit(&self, point: Vec2) -> Vec2 {
+        self.scale_map_to_unit(point - self.center)
+    }
+
+    /// Scales `vector` into the space where the ellipse is the unit circle.
+    #[inline(always)]
+    fn scale_map_to_unit(&self, vector: Vec2) -> Vec2 {
+        Vec2::new(vector.x / self.radii.x, vector.y / self.radii.y)
+    }
+}
+
+// -------------------------------- Polygon -------------------------------- //
+
+/// A filled, simple polygon borrowing its vertices.
+///
+/// The polygon is implicitly closed, its last vertex connecting back to the
+/// first. Either winding works; containment uses the even-odd rule.
+///
+/// # Example
+/// ```
+/// use celui_math::{Circle, Polygon, Vec2};
+///
+/// // A quarter slice of a pie menu
+/// let slice = [Vec2::new(0.0, 0.0), Vec2::new(100.0, 0.0), Vec2::new(0.0, 100.0)];
+/// let slice = Polygon::new(&slice);
+///
+/// assert!(slice.contains(Vec2::new(20.0, 20.0)));
+/// assert!(!slice.contains(Vec2::new(60.0, 60.0)));
+/// assert_eq!(slice.area(), 5000.0);
+/// assert!(slice.intersects_circle(&Circle::new(Vec2::new(60.0, 60.0), 15.0)));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Polygon<'a> {
+    pub points: &'a [Vec2],
+}
+
+impl<'a> Polygon<'a> {
+    #[inline(always)]
+    pub const fn new(points: &'a [Vec2]) -> Self {
+        Self { points }
+    }
+
+    /// Returns an iterator over the edges, including the closing edge.
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + 'a {
+        let points = self.points;
+
+        (0..points.len()).map(move |i| Segment::new(points[i], points[(i + 1) % points.len()]))
+    }
+
+    /// Returns the signed area, positive for counter-clockwise winding in a y-up coordinate system.
+    #[inline]
+    pub fn signed_area(&self) -> f32 {
+        self.edges()
+            .map(|edge| edge.start.cross(edge.end))
+            .sum::<f32>()
+            * 0.5
+    }
+
+    /// Returns the enclosed area.
+    #[inline(always)]
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Returns the smallest rectangle containing the polygon, or an empty rectangle if it has no vertices.
+    #[inline]
+    pub fn bounds(&self) -> Rect {
+        let Some((&first, rest)) = self.points.split_first() else {
+            return Rect::new(0.0, 0.0, 0.0, 0.0);
+        };
+
+        let (min, max) = rest.iter().fold((first, first), |(min, max), &point| {
+            (min.min(point), max.max(point))
+        });
+
+        Rect::from_min_max(min, max)
+    }
+
+    /// Returns `true` if every interior angle is at most 180 degrees.
+    pub fn is_convex(&self) -> bool {
+        let count = self.points.len();
+        let mut sign = 0.0f32;
+
+        for i in 0..count {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % count];
+            let c = self.points[(i + 2) % count];
+            let turn = (b - a).cross(c - b);
+
+            if turn == 0.0 {
+                continue;
+            }
+
+            if sign != 0.0 && turn.signum() != sign {
+                return false;
+            }
+
+            sign = turn.signum();
+        }
+
+        true
+    }
+
+    /// Returns `true` if `point` lies inside the polygon.
+    pub fn contains(&self, point: Vec2) -> bool {
+        let mut inside = false;
+
+        for edge in self.edges() {
+            let (a, b) = (edge.start, edge.end);
+
+            // Count crossings of a horizontal ray towards positive x
+            if (a.y > point.y) != (b.y > point.y)
+                && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+            {
+                inside = !inside;
+            }
+        }
+
+        // Points on the boundary count as inside
+        inside || self.edges().any(|edge| edge.closest_point(point) == point)
+    }
+
+    /// Returns the point of the polygon nearest to `point`, or `point` itself if it has no vertices.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.points.is_empty() || self.contains(point) {
+            return point;
+        }
+
+        self.edges()
+            .map(|edge| edge.closest_point(point))
+            .reduce(|a, b| {
+                if b.distance_squared(point) < a.distance_squared(point) {
+                    b
+                } else {
+                    a
+                }
+            })
+            .unwrap_or(point)
+    }
+
+    /// Returns the distance from `point` to the polygon.
+    #[inline(always)]
+    pub fn distance(&self, point: Vec2) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns `true` if the polygon touches `segment`.
+    #[inline]
+    pub fn intersects_segment(&self, segment: &Segment) -> bool {
+        self.contains(segment.start) || self.edges().any(|edge| edge.intersects_segment(segment))
+    }
+
+    /// Returns `true` if the polygon overlaps `circle`.
+    #[inline]
+    pub fn intersects_circle(&self, circle: &Circle) -> bool {
+        self.contains(circle.center) || self.edges().any(|edge| circle.intersects_segment(&edge))
+    }
+
+    /// Returns `true` if the polygon overlaps `rect`.
+    #[inline]
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.intersects_polygon(&Polygon::new(&rect.corners()))
+    }
+
+    /// Returns `true` if the two polygons overlap.
+    pub fn intersects_polygon(&self, other: &Polygon<'_>) -> bool {
+        let (Some(&first), Some(&other_first)) = (self.points.first(), other.points.first()) else {
+            return false;
+        };
+
+        // Either the boundaries cross, or one polygon lies entirely inside the other
+        self.edges().any(|edge| {
+            other
+                .edges()
+                .any(|other_edge| edge.intersects_segment(&other_edge))
+        }) || self.contains(other_first)
+            || other.contains(first)
+    }
+}
+
+// ------------------------------ Convex Hull ------------------------------ //
+
+/// Reorders `points` so the first `n` form their convex hull, and returns `n`.
+///
+/// The hull is wound counter-clockwise in a y-up coordinate system and excludes
+/// collinear points. The order of the remaining points is unspecified. Points
+/// must be finite.
+///
+/// # Example
+/// ```
+/// use celui_math::{convex_hull, Vec2};
+///
+/// let mut points = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+///     Vec2::new(1.0, 0.0),
+/// ];
+///
+/// let count = convex_hull(&mut points);
+///
+/// assert_eq!(count, 4);
+/// assert!(points[0] == Vec2::new(0.0, 0.0));
+/// assert!(points[1] == Vec2::new(2.0, 0.0));
+/// ```
+pub fn convex_hull(points: &mut [Vec2]) -> usize {
+    if points.len() < 3 {
+        return points.len();
+    }
+
+    // Graham scan around the lowest (then leftmost) point
+    let pivot = (1..points.len()).fold(0, |lowest, i| {
+        let (a, b) = (points[i], points[lowest]);
+
+        if a.y < b.y || (a.y == b.y && a.x < b.x) {
+            i
+        } else {
+            lowest
+        }
+    });
+
+    points.swap(0, pivot);
+
+    let pivot = points[0];
+
+    // Sort by angle around the pivot, nearer points first on ties
+    points[1..].sort_unstable_by(|&a, &b| {
+        let turn = (a - pivot).cross(b - pivot);
+
+        if turn > 0.0 {
+            core::cmp::Ordering::Less
+        } else if turn < 0.0 {
+            core::cmp::Ordering::Greater
+        } else {
+            pivot
+                .distance_squared(a)
+                .total_cmp(&pivot.distance_squared(b))
+        }
+    });
+
+    // The hull is kept as a stack in the front of the slice
+    let mut count = 1;
+
+    for i in 1..points.len() {
+        while count >= 2
+            && (points[count - 1] - points[count - 2]).cross(points[i] - points[count - 1]) <= 0.0
+        {
+            count -= 1;
+        }
+
+        points.swap(count, i);
+        count += 1;
+    }
+
+    count
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Returns the `t` of the first hit of a ray with a circle at the origin, or `0.0` if it starts inside.
+fn cast_circle(origin: Vec2, direction: Vec2, radius: f32) -> Option<f32> {
+    let a = direction.dot(direction);
+    let b = origin.dot(direction);
+    let c = origin.dot(origin) - radius * radius;
+
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    // Solve `|origin + t * direction|^2 = radius^2` for the smaller root
+    let discriminant = b * b - a * c;
+
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+
+    let t = (-b - float::sqrt(discriminant)) / a;
+
+    (t >= 0.0).then_some(t)
+}
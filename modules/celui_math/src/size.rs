@@ -0,0 +1,68 @@
+// -------------------------------- size.rs -------------------------------- //
+
+//! Unsigned pixel extents.
+//!
+//! Defines `Size`, the width and height of a framebuffer, texture or region in
+//! whole pixels. Unlike `IRect`, a `Size` can't be negative, which makes it the
+//! natural type for allocation sizes and clipping bounds.
+
+use crate::{IRect, Point, UVec2};
+
+// --------------------------------- Size ---------------------------------- //
+
+/// A width and height in whole pixels.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Size {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Size {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    #[inline(always)]
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Returns the number of pixels covered.
+    #[inline(always)]
+    pub const fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    /// Returns `true` if the size covers no pixels.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns `true` if `point` lies within a region of this size at the origin.
+    #[inline(always)]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as u32) < self.width
+            && (point.y as u32) < self.height
+    }
+
+    /// Returns the region of this size at the origin, or `None` if it exceeds the `i32` range.
+    #[inline(always)]
+    pub fn try_to_irect(&self) -> Option<IRect> {
+        IRect::from_point_size(Point::ZERO, *self)
+    }
+}
+
+impl From<UVec2> for Size {
+    #[inline(always)]
+    fn from(vector: UVec2) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+impl From<Size> for UVec2 {
+    #[inline(always)]
+    fn from(size: Size) -> Self {
+        Self::new(size.width, size.height)
+    }
+}
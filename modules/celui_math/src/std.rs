@@ -12,6 +12,30 @@
 //! std = []
 //! ```
 
+// ------------------------------- Aliases --------------------------------- //
+
+/// A component type, with the prefix of its type aliases (the `Fixed` of `FixedMat2`), so the
+/// generic types print under the name they are used by.
+trait Component: std::fmt::Display {
+    const PREFIX: &'static str;
+}
+
+impl Component for f32 {
+    const PREFIX: &'static str = "";
+}
+
+impl Component for crate::Fixed {
+    const PREFIX: &'static str = "Fixed";
+}
+
+impl Component for i32 {
+    const PREFIX: &'static str = "I";
+}
+
+impl Component for u32 {
+    const PREFIX: &'static str = "U";
+}
+
 // -------------------------------- Color ---------------------------------- //
 
 impl std::fmt::Debug for crate::Color {
@@ -21,61 +45,220 @@ impl std::fmt::Debug for crate::Color {
     }
 }
 
+impl std::fmt::Debug for crate::ColorF {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ColorF({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+    }
+}
+
+impl std::fmt::Debug for crate::Hsl {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hsl({}, {}, {}, {})", self.h, self.s, self.l, self.a)
+    }
+}
+
+impl std::fmt::Debug for crate::Hsv {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hsv({}, {}, {}, {})", self.h, self.s, self.v, self.a)
+    }
+}
+
+impl std::fmt::Debug for crate::Oklab {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { l, a, b, alpha } = self;
+
+        write!(f, "Oklab({l}, {a}, {b}, {alpha})")
+    }
+}
+
+impl std::fmt::Debug for crate::Oklch {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { l, c, h, alpha } = self;
+
+        write!(f, "Oklch({l}, {c}, {h}, {alpha})")
+    }
+}
+
+// -------------------------------- Curve ---------------------------------- //
+
+impl std::fmt::Debug for crate::QuadBezier {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "QuadBezier({:?}, {:?}, {:?})", self.p0, self.p1, self.p2)
+    }
+}
+
+impl std::fmt::Debug for crate::CubicBezier {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CubicBezier({:?}, {:?}, {:?}, {:?})",
+            self.p0, self.p1, self.p2, self.p3
+        )
+    }
+}
+
+// -------------------------------- Easing --------------------------------- //
+
+impl std::fmt::Debug for crate::easing::BezierEasing {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BezierEasing({}, {}, {}, {})",
+            self.x1, self.y1, self.x2, self.y2
+        )
+    }
+}
+
+impl std::fmt::Debug for crate::easing::Spring {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Spring({}, {}, {}, {})",
+            self.stiffness, self.damping, self.mass, self.initial_velocity
+        )
+    }
+}
+
+// -------------------------------- Fixed ---------------------------------- //
+
+impl std::fmt::Debug for crate::Fixed {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Fixed({self})")
+    }
+}
+
 // -------------------------------- Matrix --------------------------------- //
 
-impl std::fmt::Debug for crate::Mat2 {
+impl<T: Component + std::fmt::Debug> std::fmt::Debug for crate::Matrix2<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}Mat2({:?})", T::PREFIX, self.elements)
+    }
+}
+
+impl<T: Component + std::fmt::Debug> std::fmt::Debug for crate::Matrix3<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Mat2({:?})", self.elements)
+        write!(f, "{}Mat3({:?})", T::PREFIX, self.elements)
     }
 }
 
-impl std::fmt::Debug for crate::Mat3 {
+impl<T: Component + std::fmt::Debug> std::fmt::Debug for crate::Matrix4<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Mat3({:?})", self.elements)
+        write!(f, "{}Mat4({:?})", T::PREFIX, self.elements)
     }
 }
 
-impl std::fmt::Debug for crate::Mat4 {
+// --------------------------------- Quat ---------------------------------- //
+
+impl std::fmt::Debug for crate::Quat {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Mat4({:?})", self.elements)
+        write!(f, "Quat({}, {}, {}, {})", self.x, self.y, self.z, self.w)
     }
 }
 
 // --------------------------------- Rect ---------------------------------- //
 
-impl std::fmt::Debug for crate::Rect {
+impl<T: Component> std::fmt::Debug for crate::Rectangle<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Rect({}, {}, {}, {})",
-            self.x, self.y, self.width, self.height
+            "{}Rect({}, {}, {}, {})",
+            T::PREFIX,
+            self.x,
+            self.y,
+            self.width,
+            self.height
         )
     }
 }
 
+// -------------------------------- Shape ---------------------------------- //
+
+impl std::fmt::Debug for crate::Segment {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Segment({:?}, {:?})", self.start, self.end)
+    }
+}
+
+impl std::fmt::Debug for crate::Ray {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ray({:?}, {:?})", self.origin, self.direction)
+    }
+}
+
+impl std::fmt::Debug for crate::Circle {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Circle({:?}, {})", self.center, self.radius)
+    }
+}
+
+impl std::fmt::Debug for crate::Ellipse {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ellipse({:?}, {:?})", self.center, self.radii)
+    }
+}
+
+impl std::fmt::Debug for crate::Polygon<'_> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Polygon({:?})", self.points)
+    }
+}
+
+// --------------------------------- Size ---------------------------------- //
+
+impl std::fmt::Debug for crate::Size {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Size({}, {})", self.width, self.height)
+    }
+}
+
 // -------------------------------- Vector --------------------------------- //
 
-impl std::fmt::Debug for crate::Vec2 {
+impl<T: Component> std::fmt::Debug for crate::Vector2<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vec2({}, {})", self.x, self.y)
+        write!(f, "{}Vec2({}, {})", T::PREFIX, self.x, self.y)
     }
 }
 
-impl std::fmt::Debug for crate::Vec3 {
+impl<T: Component> std::fmt::Debug for crate::Vector3<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vec3({}, {}, {})", self.x, self.y, self.z)
+        write!(f, "{}Vec3({}, {}, {})", T::PREFIX, self.x, self.y, self.z)
     }
 }
 
-impl std::fmt::Debug for crate::Vec4 {
+impl<T: Component> std::fmt::Debug for crate::Vector4<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vec4({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+        write!(
+            f,
+            "{}Vec4({}, {}, {}, {})",
+            T::PREFIX,
+            self.x,
+            self.y,
+            self.z,
+            self.w
+        )
     }
 }
@@ -5,23 +5,131 @@
 //! This module provides a `Vector` struct for mathematical operations.
 //! It supports creation, access, modification, and common vector arithmetic
 //! like addition, subtraction, scalar multiplication, dot product, and normalization.
+//!
+//! Vectors are generic over their `Scalar` component type; `Vec2`, `Vec3` and `Vec4`
+//! are the `f32` versions, and `FixedVec2`, `FixedVec3` and `FixedVec4` are the
+//! `Fixed` versions for targets without an FPU. `IVec2` and `UVec2` are integer
+//! vectors for pixel-exact positions and extents, with checked conversions from
+//! and to `Vec2`.
+
+use crate::{float, scalar, Fixed, Real, Scalar};
 
 // --------------------------------- Vec2 ---------------------------------- //
 
-#[derive(Clone, Copy, PartialEq)]
-pub struct Vec2 {
-    pub x: f32,
-    pub y: f32,
+/// A 2D vector of `f32` components.
+pub type Vec2 = Vector2<f32>;
+
+/// A 2D vector of `Fixed` components.
+pub type FixedVec2 = Vector2<Fixed>;
+
+/// A 2D vector of `i32` components, for pixel positions and offsets.
+pub type IVec2 = Vector2<i32>;
+
+/// A 2D vector of `u32` components, for pixel extents.
+pub type UVec2 = Vector2<u32>;
+
+/// A pixel position; the same type as `IVec2`.
+pub type Point = Vector2<i32>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Vec2 {
+impl<T: Scalar> Vector2<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ONE);
+
     #[inline(always)]
-    pub const fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
+
+    /// Returns the dot product of `self` and `other`.
+    #[inline(always)]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the z component of the 3D cross product, positive if `other` is counter-clockwise.
+    #[inline(always)]
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the squared length of the vector.
+    #[inline(always)]
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns the squared distance between `self` and `other`.
+    #[inline(always)]
+    pub fn distance_squared(self, other: Self) -> T {
+        (other - self).length_squared()
+    }
+
+    /// Returns the component-wise minimum of `self` and `other`.
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        Self::new(scalar::min(self.x, other.x), scalar::min(self.y, other.y))
+    }
+
+    /// Returns the component-wise maximum of `self` and `other`.
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        Self::new(scalar::max(self.x, other.x), scalar::max(self.y, other.y))
+    }
+}
+
+impl<T: Scalar + core::ops::Neg<Output = T>> Vector2<T> {
+    /// Returns the vector rotated by 90 degrees counter-clockwise.
+    #[inline(always)]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
 }
 
-impl core::ops::Add for Vec2 {
+impl<T: Real> Vector2<T> {
+    /// Returns the length of the vector.
+    #[inline(always)]
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the distance between `self` and `other`.
+    #[inline(always)]
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
+    /// Returns the vector scaled to a length of `1`, or zero if its length is zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+
+        if length > T::ZERO {
+            self / length
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+impl<T: Scalar + core::ops::Neg<Output = T>> core::ops::Neg for Vector2<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Scalar> core::ops::Add for Vector2<T> {
     type Output = Self;
 
     #[inline(always)]
@@ -33,7 +141,7 @@ impl core::ops::Add for Vec2 {
     }
 }
 
-impl core::ops::Sub for Vec2 {
+impl<T: Scalar> core::ops::Sub for Vector2<T> {
     type Output = Self;
 
     #[inline(always)]
@@ -45,11 +153,11 @@ impl core::ops::Sub for Vec2 {
     }
 }
 
-impl core::ops::Mul<f32> for Vec2 {
+impl<T: Scalar> core::ops::Mul<T> for Vector2<T> {
     type Output = Self;
 
     #[inline(always)]
-    fn mul(self, scalar: f32) -> Self {
+    fn mul(self, scalar: T) -> Self {
         Self {
             x: self.x * scalar,
             y: self.y * scalar,
@@ -57,11 +165,11 @@ impl core::ops::Mul<f32> for Vec2 {
     }
 }
 
-impl core::ops::Div<f32> for Vec2 {
+impl<T: Scalar> core::ops::Div<T> for Vector2<T> {
     type Output = Self;
 
     #[inline(always)]
-    fn div(self, scalar: f32) -> Self {
+    fn div(self, scalar: T) -> Self {
         Self {
             x: self.x / scalar,
             y: self.y / scalar,
@@ -69,23 +177,156 @@ impl core::ops::Div<f32> for Vec2 {
     }
 }
 
+impl Vec2 {
+    /// Rounds to the nearest `IVec2`, or returns `None` if a component is NaN or out of range.
+    #[inline]
+    pub fn try_to_ivec2(self) -> Option<IVec2> {
+        Some(IVec2::new(
+            scalar::f32_to_i32(float::round(self.x))?,
+            scalar::f32_to_i32(float::round(self.y))?,
+        ))
+    }
+
+    /// Rounds to the nearest `UVec2`, or returns `None` if a component is NaN or out of range.
+    #[inline]
+    pub fn try_to_uvec2(self) -> Option<UVec2> {
+        Some(UVec2::new(
+            scalar::f32_to_u32(float::round(self.x))?,
+            scalar::f32_to_u32(float::round(self.y))?,
+        ))
+    }
+}
+
+impl IVec2 {
+    /// Converts to a `Vec2`; values beyond `±2^24` are rounded to the nearest `f32`.
+    #[inline(always)]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Converts to a `UVec2`, or returns `None` if a component is negative.
+    #[inline(always)]
+    pub fn try_to_uvec2(self) -> Option<UVec2> {
+        Some(UVec2::new(
+            u32::try_from(self.x).ok()?,
+            u32::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl UVec2 {
+    /// Converts to a `Vec2`; values beyond `2^24` are rounded to the nearest `f32`.
+    #[inline(always)]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Converts to an `IVec2`, or returns `None` if a component exceeds `i32::MAX`.
+    #[inline(always)]
+    pub fn try_to_ivec2(self) -> Option<IVec2> {
+        Some(IVec2::new(
+            i32::try_from(self.x).ok()?,
+            i32::try_from(self.y).ok()?,
+        ))
+    }
+}
+
 // --------------------------------- Vec3 ---------------------------------- //
 
+/// A 3D vector of `f32` components.
+pub type Vec3 = Vector3<f32>;
+
+/// A 3D vector of `Fixed` components.
+pub type FixedVec3 = Vector3<Fixed>;
+
 #[derive(Clone, Copy, PartialEq)]
-pub struct Vec3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+pub struct Vector3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Vec3 {
+impl<T: Scalar> Vector3<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ONE, T::ONE);
+    pub const X: Self = Self::new(T::ONE, T::ZERO, T::ZERO);
+    pub const Y: Self = Self::new(T::ZERO, T::ONE, T::ZERO);
+    pub const Z: Self = Self::new(T::ZERO, T::ZERO, T::ONE);
+
     #[inline(always)]
-    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
+
+    /// Returns the vector with `w` appended.
+    #[inline(always)]
+    pub const fn extend(self, w: T) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Returns the dot product of `self` and `other`.
+    #[inline(always)]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the cross product of `self` and `other`, following the right-hand rule.
+    #[inline(always)]
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the squared length of the vector.
+    #[inline(always)]
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Real> Vector3<T> {
+    /// Returns the length of the vector.
+    #[inline(always)]
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the distance between `self` and `other`.
+    #[inline(always)]
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
+    /// Returns the vector scaled to a length of `1`, or zero if its length is zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+
+        if length > T::ZERO {
+            self / length
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+impl<T: Scalar + core::ops::Neg<Output = T>> core::ops::Neg for Vector3<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
 }
 
-impl core::ops::Add for Vec3 {
+impl<T: Scalar> core::ops::Add for Vector3<T> {
     type Output = Self;
 
     #[inline(always)]
@@ -98,7 +339,7 @@ impl core::ops::Add for Vec3 {
     }
 }
 
-impl core::ops::Sub for Vec3 {
+impl<T: Scalar> core::ops::Sub for Vector3<T> {
     type Output = Self;
 
     #[inline(always)]
@@ -106,16 +347,16 @@ impl core::ops::Sub for Vec3 {
         Self {
             x: self.x - other.x,
             y: self.y - other.y,
-            z: self.z + other.z,
+            z: self.z - other.z,
         }
     }
 }
 
-impl core::ops::Mul<f32> for Vec3 {
+impl<T: Scalar> core::ops::Mul<T> for Vector3<T> {
     type Output = Self;
 
     #[inline(always)]
-    fn mul(self, scalar: f32) -> Self {
+    fn mul(self, scalar: T) -> Self {
         Self {
             x: self.x * scalar,
             y: self.y * scalar,
@@ -124,11 +365,11 @@ impl core::ops::Mul<f32> for Vec3 {
     }
 }
 
-impl core::ops::Div<f32> for Vec3 {
+impl<T: Scalar> core::ops::Div<T> for Vector3<T> {
     type Output = Self;
 
     #[inline(always)]
-    fn div(self, scalar: f32) -> Self {
+    fn div(self, scalar: T) -> Self {
         Self {
             x: self.x / scalar,
             y: self.y / scalar,
@@ -139,22 +380,57 @@ impl core::ops::Div<f32> for Vec3 {
 
 // --------------------------------- Vec4 ---------------------------------- //
 
+/// A 4D vector of `f32` components.
+pub type Vec4 = Vector4<f32>;
+
+/// A 4D vector of `Fixed` components.
+pub type FixedVec4 = Vector4<Fixed>;
+
 #[derive(Clone, Copy, PartialEq)]
-pub struct Vec4 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+pub struct Vector4<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
 }
 
-impl Vec4 {
+impl<T: Scalar> Vector4<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    pub const ONE: Self = Self::new(T::ONE, T::ONE, T::ONE, T::ONE);
+
     #[inline(always)]
-    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
         Self { x, y, z, w }
     }
+
+    /// Returns the vector without its `w` component.
+    #[inline(always)]
+    pub const fn truncate(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the dot product of `self` and `other`.
+    #[inline(always)]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
 }
 
-impl core::ops::Add for Vec4 {
+impl<T: Scalar + core::ops::Neg<Output = T>> core::ops::Neg for Vector4<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl<T: Scalar> core::ops::Add for Vector4<T> {
     type Output = Self;
 
     #[inline(always)]
@@ -168,7 +444,7 @@ impl core::ops::Add for Vec4 {
     }
 }
 
-impl core::ops::Sub for Vec4 {
+impl<T: Scalar> core::ops::Sub for Vector4<T> {
     type Output = Self;
 
     #[inline(always)]
@@ -176,17 +452,17 @@ impl core::ops::Sub for Vec4 {
         Self {
             x: self.x - other.x,
             y: self.y - other.y,
-            z: self.z + other.z,
-            w: self.w + other.w,
+            z: self.z - other.z,
+            w: self.w - other.w,
         }
     }
 }
 
-impl core::ops::Mul<f32> for Vec4 {
+impl<T: Scalar> core::ops::Mul<T> for Vector4<T> {
     type Output = Self;
 
     #[inline(always)]
-    fn mul(self, scalar: f32) -> Self {
+    fn mul(self, scalar: T) -> Self {
         Self {
             x: self.x * scalar,
             y: self.y * scalar,
@@ -196,11 +472,11 @@ impl core::ops::Mul<f32> for Vec4 {
     }
 }
 
-impl core::ops::Div<f32> for Vec4 {
+impl<T: Scalar> core::ops::Div<T> for Vector4<T> {
     type Output = Self;
 
     #[inline(always)]
-    fn div(self, scalar: f32) -> Self {
+    fn div(self, scalar: T) -> Self {
         Self {
             x: self.x / scalar,
             y: self.y / scalar,
@@ -0,0 +1,34 @@
+//! Tests that generic types print under the alias they are used by.
+
+use celui_math::{Fixed, FixedMat2, FixedRect, FixedVec3, IRect, Mat2, Point, UVec2, Vec4};
+
+#[test]
+fn generic_types_print_their_alias() {
+    let half = Fixed::from_ratio(1, 2);
+
+    assert_eq!(
+        format!("{:?}", Mat2::identity()),
+        "Mat2([1.0, 0.0, 0.0, 1.0])"
+    );
+    assert_eq!(
+        format!("{:?}", FixedMat2::identity()),
+        "FixedMat2([Fixed(1), Fixed(0), Fixed(0), Fixed(1)])"
+    );
+
+    assert_eq!(
+        format!("{:?}", Vec4::new(1.0, 2.0, 3.0, 4.0)),
+        "Vec4(1, 2, 3, 4)"
+    );
+    assert_eq!(
+        format!("{:?}", FixedVec3::new(half, half, half)),
+        "FixedVec3(0.5, 0.5, 0.5)"
+    );
+    assert_eq!(format!("{:?}", Point::new(-1, 2)), "IVec2(-1, 2)");
+    assert_eq!(format!("{:?}", UVec2::new(3, 4)), "UVec2(3, 4)");
+
+    assert_eq!(format!("{:?}", IRect::new(1, 2, 3, 4)), "IRect(1, 2, 3, 4)");
+    assert_eq!(
+        format!("{:?}", FixedRect::new(half, half, half, half)),
+        "FixedRect(0.5, 0.5, 0.5, 0.5)"
+    );
+}
@@ -0,0 +1,56 @@
+//! Tests for `IRect` at the edges of the `i32` range.
+
+use celui_math::{IRect, IVec2, Point};
+
+#[test]
+fn edges_saturate_at_the_i32_range() {
+    let rect = IRect::new(i32::MAX - 10, i32::MIN, 100, 100);
+
+    assert!(rect.max() == Point::new(i32::MAX, i32::MIN + 100));
+    assert!(rect.contains(Point::new(i32::MAX - 1, i32::MIN)));
+    assert!(!rect.contains(Point::new(i32::MAX, i32::MIN)));
+
+    let other = IRect::new(i32::MAX - 5, i32::MIN + 50, i32::MAX, i32::MAX);
+
+    assert!(rect.intersects(&other));
+    assert!(rect.intersection(&other) == Some(IRect::new(i32::MAX - 5, i32::MIN + 50, 5, 50)));
+
+    let everything = IRect::new(i32::MIN, i32::MIN, i32::MAX, i32::MAX).union(&IRect::new(
+        0,
+        0,
+        i32::MAX,
+        i32::MAX,
+    ));
+
+    assert!(everything == IRect::new(i32::MIN, i32::MIN, i32::MAX, i32::MAX));
+}
+
+#[test]
+fn translation_saturates_at_the_i32_range() {
+    let rect = IRect::new(i32::MAX - 1, i32::MIN + 1, 4, 4);
+
+    assert!(rect.translate(IVec2::new(10, -10)) == IRect::new(i32::MAX, i32::MIN, 4, 4));
+    assert!(rect.translate(IVec2::new(-1, 1)) == IRect::new(i32::MAX - 2, i32::MIN + 2, 4, 4));
+}
+
+#[test]
+fn pixels_stop_at_the_i32_range() {
+    let pixels: Vec<_> = IRect::new(i32::MAX - 2, i32::MAX - 1, 10, 10)
+        .pixels()
+        .collect();
+
+    assert!(
+        pixels
+            == [
+                Point::new(i32::MAX - 2, i32::MAX - 1),
+                Point::new(i32::MAX - 1, i32::MAX - 1),
+            ]
+    );
+
+    assert_eq!(IRect::new(i32::MAX, 0, 10, 10).pixels().count(), 0);
+    assert_eq!(IRect::new(0, i32::MAX, 10, 10).pixels().len(), 0);
+
+    let wide = IRect::new(10, 0, i32::MAX, 2).pixels();
+
+    assert_eq!(wide.len() as u64, 2 * (i32::MAX as u64 - 10));
+}
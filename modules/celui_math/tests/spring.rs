@@ -0,0 +1,50 @@
+//! Tests for `Spring` around critical damping and with invalid parameters.
+
+use celui_math::easing::Spring;
+
+/// Returns a spring whose damping ratio is `ratio`.
+fn spring_with_ratio(ratio: f32) -> Spring {
+    let (stiffness, mass) = (170.0, 1.0);
+
+    Spring::new(stiffness, ratio * 2.0 * (stiffness * mass).sqrt(), mass)
+}
+
+#[test]
+fn near_critical_damping_matches_critical_damping() {
+    let critical = spring_with_ratio(1.0);
+
+    for ratio in [0.9999, 0.999_999, 1.000_001, 1.0001] {
+        let spring = spring_with_ratio(ratio);
+
+        for step in 0..=100 {
+            let time = step as f32 * 0.02;
+            let (position, velocity) = (spring.evaluate(time), spring.velocity(time));
+
+            assert!(position.is_finite() && velocity.is_finite());
+            assert!((position - critical.evaluate(time)).abs() < 1e-3);
+            assert!((velocity - critical.velocity(time)).abs() < 1e-2);
+        }
+    }
+}
+
+#[test]
+fn damping_regimes_settle_at_rest() {
+    for ratio in [0.2, 0.995, 1.0, 1.005, 3.0] {
+        let spring = spring_with_ratio(ratio);
+
+        assert!(spring.evaluate(0.0).abs() < 1e-6);
+        assert!(spring.is_settled(10.0, 1e-3));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Invalid spring parameters")]
+fn zero_mass_is_rejected() {
+    Spring::new(170.0, 26.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid spring parameters")]
+fn zero_stiffness_is_rejected() {
+    Spring::new(0.0, 26.0, 1.0);
+}
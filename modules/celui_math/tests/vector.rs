@@ -0,0 +1,30 @@
+//! Tests for vector arithmetic that doc examples don't cover.
+
+use celui_math::{Fixed, FixedVec3, FixedVec4, Vec3, Vec4};
+
+#[test]
+fn subtraction_subtracts_every_component() {
+    // `z` used to be added instead of subtracted
+    assert!(Vec3::new(5.0, 7.0, 9.0) - Vec3::new(1.0, 2.0, 3.0) == Vec3::new(4.0, 5.0, 6.0));
+    assert!(
+        Vec4::new(5.0, 7.0, 9.0, 11.0) - Vec4::new(1.0, 2.0, 3.0, 4.0)
+            == Vec4::new(4.0, 5.0, 6.0, 7.0)
+    );
+
+    let fixed = |value: i32| Fixed::from_int(value);
+
+    assert!(
+        FixedVec3::new(fixed(5), fixed(7), fixed(9)) - FixedVec3::new(fixed(1), fixed(2), fixed(3))
+            == FixedVec3::new(fixed(4), fixed(5), fixed(6))
+    );
+    assert!(
+        FixedVec4::new(fixed(5), fixed(7), fixed(9), fixed(11))
+            - FixedVec4::new(fixed(1), fixed(2), fixed(3), fixed(4))
+            == FixedVec4::new(fixed(4), fixed(5), fixed(6), fixed(7))
+    );
+
+    let point = Vec3::new(0.5, -1.5, 2.5);
+
+    assert!(point - point == Vec3::ZERO);
+    assert!(point.extend(1.0) - point.extend(1.0) == Vec4::ZERO);
+}
@@ -1,3 +1,6 @@
 mod primitives;
 mod renderer;
+mod triangulate;
 mod types;
+
+pub use triangulate::triangulate;
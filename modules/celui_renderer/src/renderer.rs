@@ -1,5 +1,6 @@
 use celui_backend::GraphicsBackend;
 use celui_collections::Vec;
+use celui_math::BlendMode;
 
 use crate::types::{TextureRegistry, Vertex};
 
@@ -15,6 +16,8 @@ pub struct Renderer<B: GraphicsBackend> {
     index_cursor: usize,
 
     texture_registry: TextureRegistry,
+
+    blend_mode: BlendMode,
 }
 
 impl<B: GraphicsBackend> Renderer<B> {
@@ -22,6 +25,26 @@ impl<B: GraphicsBackend> Renderer<B> {
         todo!()
     }
 
+    /// Returns the blend mode used for subsequent draws.
+    #[inline(always)]
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Sets the blend mode used for subsequent draws.
+    ///
+    /// Geometry batched with the previous blend mode is flushed first.
+    pub fn set_blend_mode(&mut self, mode: BlendMode) {
+        if mode == self.blend_mode {
+            return;
+        }
+
+        self.flush();
+
+        self.blend_mode = mode;
+        self.backend.set_blend_mode(mode);
+    }
+
     fn init(&mut self) {}
 
     fn reset(&mut self) {}
@@ -0,0 +1,791 @@
+// ---------------------------- triangulate.rs ----------------------------- //
+
+//! Polygon triangulation by ear clipping.
+//!
+//! Converts arbitrary simple polygons, concave ones and ones with holes, into
+//! triangle index lists for the renderer's triangle buffers. Holes are bridged
+//! into the outer contour first, then ears are clipped from the resulting ring.
+//!
+//! Invalid input is handled on a best-effort basis, in the same spirit as the
+//! `earcut` algorithm this follows: duplicate and collinear points are dropped,
+//! self-touching contours and small local self-intersections are cured, and if
+//! no ear can be found the ring is split along a valid diagonal. The output for
+//! a self-intersecting polygon covers it approximately, but never hangs.
+//!
+//! Clipping runs in `O(n^2)` for `n` vertices, which is fine for UI paths.
+
+use celui_collections::Vec;
+use celui_math::Vec2;
+
+// ------------------------------ Triangulate ------------------------------ //
+
+/// Triangulates a polygon with holes, appending the triangle indices to `indices`.
+///
+/// `vertices` holds the outer contour followed by each hole contour, and `holes`
+/// the index in `vertices` where each hole starts, in increasing order. Either
+/// winding works for any contour. Each triangle appends three indices into
+/// `vertices`, wound counter-clockwise in a y-up coordinate system (clockwise on
+/// screen); offset them by the base vertex when writing to a shared buffer.
+///
+/// Returns the number of triangles appended. Degenerate input, such as fewer than
+/// three distinct points or a contour with no area, produces no triangles.
+///
+/// # Example
+/// ```
+/// use celui_collections::Vec;
+/// use celui_math::Vec2;
+/// use celui_renderer::triangulate;
+///
+/// // A square with a square hole
+/// let vertices = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(10.0, 0.0),
+///     Vec2::new(10.0, 10.0),
+///     Vec2::new(0.0, 10.0),
+///     Vec2::new(3.0, 3.0),
+///     Vec2::new(7.0, 3.0),
+///     Vec2::new(7.0, 7.0),
+///     Vec2::new(3.0, 7.0),
+/// ];
+///
+/// let mut indices = Vec::new();
+///
+/// assert_eq!(triangulate(&vertices, &[4], &mut indices), 8);
+/// assert_eq!(indices.len(), 24);
+///
+/// // The triangles cover exactly the area between the squares
+/// let area: f32 = indices
+///     .as_slice()
+///     .chunks(3)
+///     .map(|t| (vertices[t[1]] - vertices[t[0]]).cross(vertices[t[2]] - vertices[t[0]]) * 0.5)
+///     .sum();
+///
+/// assert_eq!(area, 84.0);
+/// ```
+///
+/// Degenerate and self-touching contours:
+/// ```
+/// use celui_collections::Vec;
+/// use celui_math::Vec2;
+/// use celui_renderer::triangulate;
+///
+/// let mut indices = Vec::new();
+///
+/// // Too few points, all collinear, and all coincident
+/// let line = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0)];
+/// let point = [Vec2::new(1.0, 1.0); 4];
+///
+/// assert_eq!(triangulate(&line[..2], &[], &mut indices), 0);
+/// assert_eq!(triangulate(&line, &[], &mut indices), 0);
+/// assert_eq!(triangulate(&point, &[], &mut indices), 0);
+///
+/// // Duplicate and collinear points are skipped
+/// let square = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(5.0, 0.0),
+///     Vec2::new(10.0, 0.0),
+///     Vec2::new(10.0, 0.0),
+///     Vec2::new(10.0, 10.0),
+///     Vec2::new(0.0, 10.0),
+/// ];
+///
+/// assert_eq!(triangulate(&square, &[], &mut indices), 2);
+///
+/// // Two squares touching at a single vertex
+/// let bowtie = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(4.0, 2.0),
+///     Vec2::new(4.0, 4.0),
+///     Vec2::new(2.0, 4.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+/// ];
+///
+/// indices.clear();
+///
+/// assert_eq!(triangulate(&bowtie, &[], &mut indices), 4);
+///
+/// // A hole touching the outer contour
+/// let notched = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(4.0, 0.0),
+///     Vec2::new(4.0, 4.0),
+///     Vec2::new(0.0, 4.0),
+///     Vec2::new(0.0, 1.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 3.0),
+/// ];
+///
+/// assert!(triangulate(&notched, &[4], &mut indices) > 0);
+/// ```
+pub fn triangulate(vertices: &[Vec2], holes: &[usize], indices: &mut Vec<usize>) -> usize {
+    let start = indices.len();
+    let outer_end = holes.first().copied().unwrap_or(vertices.len());
+
+    let mut triangulator = Triangulator {
+        nodes: Vec::with_capacity(vertices.len() + holes.len() * 2),
+        indices,
+    };
+
+    let Some(mut outer) = triangulator.link(vertices, 0, outer_end, true) else {
+        return 0;
+    };
+
+    if triangulator.node(outer).next == triangulator.node(outer).prev {
+        return 0;
+    }
+
+    if !holes.is_empty() {
+        outer = triangulator.eliminate_holes(vertices, holes, outer);
+    }
+
+    // Duplicate and collinear points would only add sliver triangles
+    let outer = triangulator.filter(outer, None);
+
+    triangulator.clip_ears(outer, Pass::Clip);
+
+    (triangulator.indices.len() - start) / 3
+}
+
+// -------------------------------- Internal ------------------------------- //
+
+/// A vertex in one of the circular doubly-linked contour lists.
+#[derive(Clone, Copy)]
+struct Node {
+    /// Index of the vertex in the input.
+    index: usize,
+    point: Vec2,
+    prev: usize,
+    next: usize,
+    /// Whether this is a single-point hole, which must not be filtered out.
+    steiner: bool,
+}
+
+/// The recovery stage of the ear clipping loop.
+#[derive(Clone, Copy, PartialEq)]
+enum Pass {
+    /// Plain ear clipping.
+    Clip,
+    /// Clipping after removing duplicate and collinear points.
+    Filtered,
+    /// Clipping after curing local self-intersections.
+    Cured,
+}
+
+struct Triangulator<'a> {
+    nodes: Vec<Node>,
+    indices: &'a mut Vec<usize>,
+}
+
+impl Triangulator<'_> {
+    #[inline(always)]
+    fn node(&self, id: usize) -> &Node {
+        &self.nodes[id]
+    }
+
+    #[inline(always)]
+    fn point(&self, id: usize) -> Vec2 {
+        self.nodes[id].point
+    }
+
+    #[inline(always)]
+    fn prev(&self, id: usize) -> usize {
+        self.nodes[id].prev
+    }
+
+    #[inline(always)]
+    fn next(&self, id: usize) -> usize {
+        self.nodes[id].next
+    }
+
+    /// Inserts a node after `last`, or starts a new list if there is none.
+    fn insert(&mut self, index: usize, point: Vec2, last: Option<usize>) -> usize {
+        let id = self.nodes.len();
+
+        let (prev, next) = match last {
+            Some(last) => (last, self.next(last)),
+            None => (id, id),
+        };
+
+        self.nodes.push(Node {
+            index,
+            point,
+            prev,
+            next,
+            steiner: false,
+        });
+
+        if let Some(last) = last {
+            self.nodes[next].prev = id;
+            self.nodes[last].next = id;
+        }
+
+        id
+    }
+
+    /// Unlinks a node from its list.
+    #[inline(always)]
+    fn remove(&mut self, id: usize) {
+        let Node { prev, next, .. } = self.nodes[id];
+
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+    }
+
+    /// Builds a list from `vertices[start..end]`, counter-clockwise for the outer contour
+    /// and clockwise for holes (in a y-up coordinate system).
+    fn link(&mut self, vertices: &[Vec2], start: usize, end: usize, outer: bool) -> Option<usize> {
+        let contour = vertices.get(start..end)?;
+
+        let area: f32 = (0..contour.len())
+            .map(|i| contour[i].cross(contour[(i + 1) % contour.len()]))
+            .sum();
+
+        let mut last = None;
+
+        if outer == (area > 0.0) {
+            for (i, &point) in contour.iter().enumerate() {
+                last = Some(self.insert(start + i, point, last));
+            }
+        } else {
+            for (i, &point) in contour.iter().enumerate().rev() {
+                last = Some(self.insert(start + i, point, last));
+            }
+        }
+
+        // Drop an explicit closing point
+        let mut last = last?;
+
+        if self.point(last) == self.point(self.next(last)) {
+            self.remove(last);
+            last = self.next(last);
+        }
+
+        Some(last)
+    }
+
+    /// Removes duplicate and collinear points from `start` around to `end`, returning a node
+    /// still in the list.
+    fn filter(&mut self, start: usize, end: Option<usize>) -> usize {
+        let mut end = end.unwrap_or(start);
+        let mut p = start;
+
+        loop {
+            let mut again = false;
+
+            let next = self.next(p);
+            let redundant = self.point(p) == self.point(next)
+                || area(self.point(self.prev(p)), self.point(p), self.point(next)) == 0.0;
+
+            if !self.node(p).steiner && redundant {
+                self.remove(p);
+
+                p = self.prev(p);
+                end = p;
+
+                if p == self.next(p) {
+                    break;
+                }
+
+                again = true;
+            } else {
+                p = next;
+            }
+
+            if !again && p == end {
+                break;
+            }
+        }
+
+        end
+    }
+
+    /// Clips ears from the ring containing `ear`, recovering from invalid input if stuck.
+    fn clip_ears(&mut self, mut ear: usize, pass: Pass) {
+        let mut stop = ear;
+
+        while self.prev(ear) != self.next(ear) {
+            let prev = self.prev(ear);
+            let next = self.next(ear);
+
+            if self.is_ear(ear) {
+                self.emit(prev, ear, next);
+                self.remove(ear);
+
+                // Skipping the next vertex leads to fewer sliver triangles
+                ear = self.next(next);
+                stop = ear;
+
+                continue;
+            }
+
+            ear = next;
+
+            if ear == stop {
+                match pass {
+                    Pass::Clip => {
+                        let ear = self.filter(ear, None);
+
+                        self.clip_ears(ear, Pass::Filtered);
+                    }
+                    Pass::Filtered => {
+                        let ear = self.filter(ear, None);
+                        let ear = self.cure_local_intersections(ear);
+
+                        self.clip_ears(ear, Pass::Cured);
+                    }
+                    Pass::Cured => self.split(ear),
+                }
+
+                break;
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn emit(&mut self, a: usize, b: usize, c: usize) {
+        self.indices.push(self.node(a).index);
+        self.indices.push(self.node(b).index);
+        self.indices.push(self.node(c).index);
+    }
+
+    /// Returns `true` if the triangle at `ear` is convex and contains no other vertex.
+    fn is_ear(&self, ear: usize) -> bool {
+        let (a, b, c) = (self.prev(ear), ear, self.next(ear));
+        let (pa, pb, pc) = (self.point(a), self.point(b), self.point(c));
+
+        if area(pa, pb, pc) >= 0.0 {
+            return false;
+        }
+
+        let mut p = self.next(c);
+
+        while p != a {
+            let point = self.point(p);
+
+            if point != pa
+                && point_in_triangle(pa, pb, pc, point)
+                && area(self.point(self.prev(p)), point, self.point(self.next(p))) >= 0.0
+            {
+                return false;
+            }
+
+            p = self.next(p);
+        }
+
+        true
+    }
+
+    /// Clips the triangles of small self-intersections, where an edge crosses the edge after next.
+    fn cure_local_intersections(&mut self, start: usize) -> usize {
+        let mut start = start;
+        let mut p = start;
+
+        loop {
+            let a = self.prev(p);
+            let b = self.next(self.next(p));
+
+            if self.point(a) != self.point(b)
+                && intersects(
+                    self.point(a),
+                    self.point(p),
+                    self.point(self.next(p)),
+                    self.point(b),
+                )
+                && self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+            {
+                self.emit(a, p, b);
+
+                let next = self.next(p);
+
+                self.remove(p);
+                self.remove(next);
+
+                p = b;
+                start = b;
+            }
+
+            p = self.next(p);
+
+            if p == start {
+                break;
+            }
+        }
+
+        self.filter(p, None)
+    }
+
+    /// Splits the ring along a valid diagonal and clips both halves.
+    fn split(&mut self, start: usize) {
+        let mut a = start;
+
+        loop {
+            let mut b = self.next(self.next(a));
+
+            while b != self.prev(a) {
+                if self.node(a).index != self.node(b).index && self.is_valid_diagonal(a, b) {
+                    let c = self.split_polygon(a, b);
+
+                    let a = self.filter(a, Some(self.next(a)));
+                    let c = self.filter(c, Some(self.next(c)));
+
+                    self.clip_ears(a, Pass::Clip);
+                    self.clip_ears(c, Pass::Clip);
+
+                    return;
+                }
+
+                b = self.next(b);
+            }
+
+            a = self.next(a);
+
+            if a == start {
+                break;
+            }
+        }
+    }
+
+    /// Links each hole into the outer ring through a bridge, leftmost hole first.
+    fn eliminate_holes(&mut self, vertices: &[Vec2], holes: &[usize], mut outer: usize) -> usize {
+        let mut queue = Vec::with_capacity(holes.len());
+
+        for (i, &start) in holes.iter().enumerate() {
+            let end = holes.get(i + 1).copied().unwrap_or(vertices.len());
+
+            let Some(list) = self.link(vertices, start, end, false) else {
+                continue;
+            };
+
+            if list == self.next(list) {
+                self.nodes[list].steiner = true;
+            }
+
+            queue.push(self.leftmost(list));
+        }
+
+        queue.as_slice_mut().sort_unstable_by(|&a, &b| {
+            let (a, b) = (self.point(a), self.point(b));
+
+            a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+        });
+
+        for &hole in queue.iter() {
+            outer = self.eliminate_hole(hole, outer);
+        }
+
+        outer
+    }
+
+    fn eliminate_hole(&mut self, hole: usize, outer: usize) -> usize {
+        let Some(bridge) = self.find_hole_bridge(hole, outer) else {
+            return outer;
+        };
+
+        let bridge_reverse = self.split_polygon(bridge, hole);
+
+        self.filter(bridge_reverse, Some(self.next(bridge_reverse)));
+        self.filter(bridge, Some(self.next(bridge)))
+    }
+
+    /// Finds an outer vertex visible from the leftmost vertex of `hole`.
+    fn find_hole_bridge(&self, hole: usize, outer: usize) -> Option<usize> {
+        let h = self.point(hole);
+        let mut p = outer;
+        let mut qx = f32::NEG_INFINITY;
+        let mut m = None;
+
+        if h == self.point(p) {
+            return Some(p);
+        }
+
+        // Cast a ray from the hole to the left; the endpoint with the lesser x of the
+        // nearest crossed edge is a candidate, unless the ray hits a vertex directly
+        loop {
+            let (point, next) = (self.point(p), self.point(self.next(p)));
+
+            if h == next {
+                return Some(self.next(p));
+            }
+
+            if h.y <= point.y && h.y >= next.y && next.y != point.y {
+                let x = point.x + (h.y - point.y) * (next.x - point.x) / (next.y - point.y);
+
+                if x <= h.x && x > qx {
+                    qx = x;
+                    m = Some(if point.x < next.x { p } else { self.next(p) });
+
+                    if x == h.x {
+                        return m;
+                    }
+                }
+            }
+
+            p = self.next(p);
+
+            if p == outer {
+                break;
+            }
+        }
+
+        let mut m = m?;
+
+        // Any vertex inside the triangle of the hole point, the ray hit and the candidate
+        // could block the bridge; pick the one at the smallest angle to the ray instead
+        let stop = m;
+        let mp = self.point(m);
+        let mut tan_min = f32::INFINITY;
+
+        p = m;
+
+        loop {
+            let point = self.point(p);
+
+            if h.x >= point.x
+                && point.x >= mp.x
+                && h.x != point.x
+                && point_in_triangle(
+                    Vec2::new(if h.y < mp.y { h.x } else { qx }, h.y),
+                    mp,
+                    Vec2::new(if h.y < mp.y { qx } else { h.x }, h.y),
+                    point,
+                )
+            {
+                let tan = (h.y - point.y).abs() / (h.x - point.x);
+                let best = self.point(m);
+
+                if self.locally_inside(p, hole)
+                    && (tan < tan_min
+                        || (tan == tan_min
+                            && (point.x > best.x
+                                || (point.x == best.x && self.sector_contains_sector(m, p)))))
+                {
+                    m = p;
+                    tan_min = tan;
+                }
+            }
+
+            p = self.next(p);
+
+            if p == stop {
+                break;
+            }
+        }
+
+        Some(m)
+    }
+
+    /// Returns `true` if the sector at `m` contains the sector at `p`, for coincident vertices.
+    #[inline(always)]
+    fn sector_contains_sector(&self, m: usize, p: usize) -> bool {
+        area(
+            self.point(self.prev(m)),
+            self.point(m),
+            self.point(self.prev(p)),
+        ) < 0.0
+            && area(
+                self.point(self.next(p)),
+                self.point(m),
+                self.point(self.next(m)),
+            ) < 0.0
+    }
+
+    fn leftmost(&self, start: usize) -> usize {
+        let mut p = start;
+        let mut leftmost = start;
+
+        loop {
+            let (point, best) = (self.point(p), self.point(leftmost));
+
+            if point.x < best.x || (point.x == best.x && point.y < best.y) {
+                leftmost = p;
+            }
+
+            p = self.next(p);
+
+            if p == start {
+                break;
+            }
+        }
+
+        leftmost
+    }
+
+    /// Returns `true` if the diagonal from `a` to `b` lies inside the ring without crossing it.
+    fn is_valid_diagonal(&self, a: usize, b: usize) -> bool {
+        let (na, nb) = (self.node(a), self.node(b));
+
+        if self.node(na.next).index == nb.index || self.node(na.prev).index == nb.index {
+            return false;
+        }
+
+        if self.intersects_polygon(a, b) {
+            return false;
+        }
+
+        let (pa, pb) = (na.point, nb.point);
+
+        let visible = self.locally_inside(a, b)
+            && self.locally_inside(b, a)
+            && self.middle_inside(a, b)
+            && (area(self.point(na.prev), pa, self.point(nb.prev)) != 0.0
+                || area(pa, self.point(nb.prev), pb) != 0.0);
+
+        // Coincident vertices of a self-touching ring are also a valid split point
+        let touching = pa == pb
+            && area(self.point(na.prev), pa, self.point(na.next)) > 0.0
+            && area(self.point(nb.prev), pb, self.point(nb.next)) > 0.0;
+
+        visible || touching
+    }
+
+    /// Returns `true` if the segment from `a` to `b` crosses any edge of the ring.
+    fn intersects_polygon(&self, a: usize, b: usize) -> bool {
+        let (ia, ib) = (self.node(a).index, self.node(b).index);
+        let mut p = a;
+
+        loop {
+            let next = self.next(p);
+            let (ip, inext) = (self.node(p).index, self.node(next).index);
+
+            if ip != ia
+                && inext != ia
+                && ip != ib
+                && inext != ib
+                && intersects(
+                    self.point(p),
+                    self.point(next),
+                    self.point(a),
+                    self.point(b),
+                )
+            {
+                return true;
+            }
+
+            p = next;
+
+            if p == a {
+                return false;
+            }
+        }
+    }
+
+    /// Returns `true` if the diagonal from `a` towards `b` starts inside the ring.
+    fn locally_inside(&self, a: usize, b: usize) -> bool {
+        let (prev, point, next) = (
+            self.point(self.prev(a)),
+            self.point(a),
+            self.point(self.next(a)),
+        );
+        let target = self.point(b);
+
+        if area(prev, point, next) < 0.0 {
+            area(point, target, next) >= 0.0 && area(point, prev, target) >= 0.0
+        } else {
+            area(point, target, prev) < 0.0 || area(point, next, target) < 0.0
+        }
+    }
+
+    /// Returns `true` if the midpoint of the diagonal from `a` to `b` is inside the ring.
+    fn middle_inside(&self, a: usize, b: usize) -> bool {
+        let middle = (self.point(a) + self.point(b)) * 0.5;
+        let mut inside = false;
+        let mut p = a;
+
+        loop {
+            let (point, next) = (self.point(p), self.point(self.next(p)));
+
+            if (point.y > middle.y) != (next.y > middle.y)
+                && next.y != point.y
+                && middle.x
+                    < (next.x - point.x) * (middle.y - point.y) / (next.y - point.y) + point.x
+            {
+                inside = !inside;
+            }
+
+            p = self.next(p);
+
+            if p == a {
+                return inside;
+            }
+        }
+    }
+
+    /// Connects `a` and `b` with a diagonal, splitting the ring into two, and returns a node
+    /// of the second ring.
+    fn split_polygon(&mut self, a: usize, b: usize) -> usize {
+        let a2 = self.nodes.len();
+        let b2 = a2 + 1;
+        let an = self.next(a);
+        let bp = self.prev(b);
+
+        self.nodes.push(Node {
+            next: an,
+            prev: b2,
+            ..self.nodes[a]
+        });
+
+        self.nodes.push(Node {
+            next: a2,
+            prev: bp,
+            ..self.nodes[b]
+        });
+
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+        self.nodes[an].prev = a2;
+        self.nodes[bp].next = b2;
+
+        b2
+    }
+}
+
+// ------------------------------- Geometry -------------------------------- //
+
+/// Returns twice the signed area of the triangle, negative for a convex turn of the outer ring.
+#[inline(always)]
+fn area(p: Vec2, q: Vec2, r: Vec2) -> f32 {
+    (q.y - p.y) * (r.x - q.x) - (q.x - p.x) * (r.y - q.y)
+}
+
+/// Returns `true` if `p` lies inside the triangle or on its edges.
+#[inline(always)]
+fn point_in_triangle(a: Vec2, b: Vec2, c: Vec2, p: Vec2) -> bool {
+    (c.x - p.x) * (a.y - p.y) >= (a.x - p.x) * (c.y - p.y)
+        && (a.x - p.x) * (b.y - p.y) >= (b.x - p.x) * (a.y - p.y)
+        && (b.x - p.x) * (c.y - p.y) >= (c.x - p.x) * (b.y - p.y)
+}
+
+/// Returns `true` if the segments `p1`-`q1` and `p2`-`q2` touch.
+fn intersects(p1: Vec2, q1: Vec2, p2: Vec2, q2: Vec2) -> bool {
+    let o1 = sign(area(p1, q1, p2));
+    let o2 = sign(area(p1, q1, q2));
+    let o3 = sign(area(p2, q2, p1));
+    let o4 = sign(area(p2, q2, q1));
+
+    (o1 != o2 && o3 != o4)
+        || (o1 == 0 && on_segment(p1, p2, q1))
+        || (o2 == 0 && on_segment(p1, q2, q1))
+        || (o3 == 0 && on_segment(p2, p1, q2))
+        || (o4 == 0 && on_segment(p2, q1, q2))
+}
+
+/// Returns `true` if `q` lies within the bounds of the segment `p`-`r`, given all three are collinear.
+#[inline(always)]
+fn on_segment(p: Vec2, q: Vec2, r: Vec2) -> bool {
+    q.x <= p.x.max(r.x) && q.x >= p.x.min(r.x) && q.y <= p.y.max(r.y) && q.y >= p.y.min(r.y)
+}
+
+#[inline(always)]
+fn sign(value: f32) -> i32 {
+    if value > 0.0 {
+        1
+    } else if value < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,290 @@
+//! Tests for `triangulate` on valid and degenerate polygons, checking that the triangles have
+//! valid indices, the expected count and winding, and cover exactly the polygon's area.
+
+use celui_collections::Vec;
+use celui_math::Vec2;
+use celui_renderer::triangulate;
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    /// Returns a random value in `0.0..1.0`.
+    fn unit(&mut self) -> f32 {
+        (self.next() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+/// Returns the absolute area enclosed by a contour.
+fn contour_area(contour: &[Vec2]) -> f32 {
+    let twice: f32 = (0..contour.len())
+        .map(|i| contour[i].cross(contour[(i + 1) % contour.len()]))
+        .sum();
+
+    twice.abs() * 0.5
+}
+
+/// Triangulates `vertices`, checks the output, and returns the number of triangles and their
+/// total area.
+fn check(vertices: &[Vec2], holes: &[usize]) -> (usize, f32) {
+    // Triangles are appended after whatever the buffer already holds
+    let mut indices = Vec::new();
+
+    indices.extend_from_slice(&[7, 7, 7]);
+
+    let count = triangulate(vertices, holes, &mut indices);
+    let triangles = &indices.as_slice()[3..];
+
+    assert_eq!(triangles.len(), count * 3);
+    assert!(triangles.iter().all(|&index| index < vertices.len()));
+
+    let mut total = 0.0;
+
+    for triangle in triangles.chunks(3) {
+        let (a, b, c) = (
+            vertices[triangle[0]],
+            vertices[triangle[1]],
+            vertices[triangle[2]],
+        );
+        let area = (b - a).cross(c - a) * 0.5;
+
+        // Counter-clockwise in a y-up coordinate system
+        assert!(area >= -1e-4, "Clockwise triangle {triangle:?}");
+
+        total += area;
+    }
+
+    (count, total)
+}
+
+/// Asserts that two areas agree to within a small relative error.
+fn assert_area(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() <= 1e-3 * expected.max(1.0),
+        "Covered {actual}, expected {expected}"
+    );
+}
+
+fn square(x: f32, y: f32, size: f32) -> [Vec2; 4] {
+    [
+        Vec2::new(x, y),
+        Vec2::new(x + size, y),
+        Vec2::new(x + size, y + size),
+        Vec2::new(x, y + size),
+    ]
+}
+
+#[test]
+fn simple_polygons_give_n_minus_two_triangles_in_either_winding() {
+    // An L shape, which is concave
+    let mut shape = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(6.0, 0.0),
+        Vec2::new(6.0, 2.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(2.0, 6.0),
+        Vec2::new(0.0, 6.0),
+    ];
+
+    for _ in 0..2 {
+        let (count, area) = check(&shape, &[]);
+
+        assert_eq!(count, 4);
+        assert_area(area, 20.0);
+
+        shape.reverse();
+    }
+}
+
+#[test]
+fn random_star_polygons_with_and_without_a_hole() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for round in 0..200 {
+        let n = 6 + round % 40;
+
+        // Sorted angles around the origin and radii of at least 4 give a simple polygon. With
+        // six or more vertices, no two are more than 108 degrees apart, so every edge stays
+        // over 2.3 away from the origin.
+        let mut vertices: std::vec::Vec<Vec2> = (0..n)
+            .map(|i| {
+                let angle = (i as f32 + 0.1 + 0.8 * rng.unit()) / n as f32 * std::f32::consts::TAU;
+                let radius = 4.0 + 6.0 * rng.unit();
+
+                Vec2::new(angle.cos() * radius, angle.sin() * radius)
+            })
+            .collect();
+
+        if round % 2 == 1 {
+            vertices.reverse();
+        }
+
+        let outer = contour_area(&vertices);
+        let (count, area) = check(&vertices, &[]);
+
+        assert_eq!(count, n - 2);
+        assert_area(area, outer);
+
+        // A square hole around the origin, well inside the smallest radius
+        let hole = square(-1.5, -1.5, 3.0);
+
+        vertices.extend_from_slice(&hole);
+
+        let (count, area) = check(&vertices, &[n]);
+
+        assert_eq!(count, n + 4 - 2 + 2);
+        assert_area(area, outer - 9.0);
+    }
+}
+
+#[test]
+fn collinear_points_are_dropped() {
+    // A 4x4 square with every integer point along its edges
+    let mut vertices = std::vec::Vec::new();
+
+    for i in 0..4 {
+        vertices.push(Vec2::new(i as f32, 0.0));
+    }
+
+    for i in 0..4 {
+        vertices.push(Vec2::new(4.0, i as f32));
+    }
+
+    for i in 0..4 {
+        vertices.push(Vec2::new(4.0 - i as f32, 4.0));
+    }
+
+    for i in 0..4 {
+        vertices.push(Vec2::new(0.0, 4.0 - i as f32));
+    }
+
+    let (count, area) = check(&vertices, &[]);
+
+    assert_eq!(count, 2);
+    assert_area(area, 16.0);
+
+    // Collinear points on a hole, too
+    let hole = [
+        Vec2::new(1.0, 1.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(1.0, 3.0),
+    ];
+
+    vertices.extend_from_slice(&hole);
+
+    let (count, area) = check(&vertices, &[16]);
+
+    assert_eq!(count, 8);
+    assert_area(area, 12.0);
+}
+
+#[test]
+fn duplicate_points_are_dropped() {
+    let shape = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(6.0, 0.0),
+        Vec2::new(6.0, 2.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(2.0, 6.0),
+        Vec2::new(0.0, 6.0),
+    ];
+
+    // Every point twice, then an explicit closing point
+    let mut vertices: std::vec::Vec<Vec2> =
+        shape.iter().flat_map(|&point| [point, point]).collect();
+
+    vertices.push(shape[0]);
+
+    let (count, area) = check(&vertices, &[]);
+
+    assert_eq!(count, 4);
+    assert_area(area, 20.0);
+}
+
+#[test]
+fn holes_touching_the_outer_contour_or_each_other() {
+    // A triangular hole with two vertices on the left edge
+    let notched = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 3.0),
+    ];
+
+    let (count, area) = check(&notched, &[4]);
+
+    assert!(count > 0);
+    assert_area(area, 14.0);
+
+    // A square hole sharing a corner with the outer contour
+    let mut vertices = square(0.0, 0.0, 6.0).to_vec();
+
+    vertices.extend_from_slice(&square(0.0, 0.0, 2.0));
+
+    let (_, area) = check(&vertices, &[4]);
+
+    assert_area(area, 32.0);
+
+    // Two square holes sharing a corner with each other
+    let mut vertices = square(0.0, 0.0, 10.0).to_vec();
+
+    vertices.extend_from_slice(&square(2.0, 2.0, 3.0));
+    vertices.extend_from_slice(&square(5.0, 5.0, 3.0));
+
+    let (_, area) = check(&vertices, &[4, 8]);
+
+    assert_area(area, 100.0 - 18.0);
+}
+
+#[test]
+fn zero_area_contours_are_ignored() {
+    // Outer contours without area give no triangles
+    let spike = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(5.0, 0.0),
+        Vec2::new(2.0, 0.0),
+    ];
+    let folded = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(8.0, 8.0),
+        Vec2::new(4.0, 4.0),
+    ];
+
+    assert_eq!(check(&spike, &[]), (0, 0.0));
+    assert_eq!(check(&folded, &[]), (0, 0.0));
+    assert_eq!(check(&[], &[]), (0, 0.0));
+
+    // A hole without area leaves the whole outer contour covered
+    let mut vertices = square(0.0, 0.0, 4.0).to_vec();
+
+    vertices.extend_from_slice(&[Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0)]);
+
+    let (_, area) = check(&vertices, &[4]);
+
+    assert_area(area, 16.0);
+
+    // So does a single-point hole, which is kept as a vertex of the triangulation
+    let mut vertices = square(0.0, 0.0, 4.0).to_vec();
+
+    vertices.push(Vec2::new(1.0, 2.0));
+
+    let mut indices = Vec::new();
+
+    assert_eq!(triangulate(&vertices, &[4], &mut indices), 4);
+    assert!(indices.iter().any(|&index| index == 4));
+    assert_area(check(&vertices, &[4]).1, 16.0);
+}
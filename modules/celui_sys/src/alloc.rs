@@ -5,7 +5,26 @@
 //! This module provides an interface for memory allocation, defining functions
 //! for allocating and deallocating memory. When the `std` feature is enabled,
 //! these functions use the standard library's allocation mechanisms. Otherwise,
-//! they provide a set of extern "C" functions that must be implemented by the user.
+//! they provide a set of extern "C" functions that must be implemented by the user:
+//!
+//! ```text
+//! _alloc_raw(size, align) -> *mut u8
+//! _dealloc_raw(ptr, size, align)
+//! ```
+//!
+//! Resizing allocates, copies and deallocates through those two. To resize in
+//! place instead, enable the `realloc-raw` feature and implement a third one:
+//!
+//! ```text
+//! _realloc_raw(ptr, old_size, align, new_size) -> *mut u8
+//! ```
+//!
+//! Allocation failures are reported as an `AllocError` by the `try_*` functions.
+//! Infallible callers pass it to `handle_alloc_error`, which panics by default;
+//! `set_oom_hook` replaces that with a custom handler, such as resetting the device.
+//!
+//! With the `alloc-debug` feature, every call to the interfaces is tracked by
+//! `celui_sys::alloc_debug`.
 
 // ------------------------------- Allocate ------------------------------- //
 
@@ -29,7 +48,7 @@ pub unsafe fn alloc<T>() -> *mut T {
     let size = core::mem::size_of::<T>();
     let align = core::mem::align_of::<T>();
 
-    unsafe { _alloc_raw(size, align) as *mut T }
+    unsafe { raw_alloc(size, align) as *mut T }
 }
 
 /// Allocates memory for `count` items of type T.
@@ -50,13 +69,124 @@ pub unsafe fn alloc_many<T>(count: usize) -> *mut T {
     }
 
     // Check for size overflow
-    let size = match core::mem::size_of::<T>().checked_mul(count) {
-        Some(size) => size,
-        None => return core::ptr::null_mut(),
+    let size = match array_size::<T>(count) {
+        Ok(size) => size,
+        Err(_) => return core::ptr::null_mut(),
     };
     let align = core::mem::align_of::<T>();
 
-    unsafe { _alloc_raw(size, align) as *mut T }
+    unsafe { raw_alloc(size, align) as *mut T }
+}
+
+/// Allocates memory for `count` items of type T, reporting failures as an `AllocError`.
+///
+/// # Safety
+/// - The caller must ensure the allocated memory is properly initialized before use
+/// - The caller is responsible for deallocating the memory using `dealloc_many`
+///
+/// # Returns
+/// - Returns a dangling pointer if count is 0 or T is zero-sized
+/// - Otherwise returns a non-null, aligned pointer to allocated memory
+#[inline(always)]
+pub unsafe fn try_alloc_many<T>(count: usize) -> Result<*mut T, AllocError> {
+    unsafe { try_alloc_many_in(&Global, count) }
+}
+
+/// Allocates memory for `count` items of type T from `allocator`.
+///
+/// # Safety
+/// - The caller must ensure the allocated memory is properly initialized before use
+/// - The caller is responsible for deallocating the memory using `dealloc_many_in` with the
+///   same allocator
+///
+/// # Returns
+/// - Returns a dangling pointer if count is 0 or T is zero-sized
+/// - Otherwise returns a non-null, aligned pointer to allocated memory
+#[inline]
+pub unsafe fn try_alloc_many_in<T, A: Allocator + ?Sized>(
+    allocator: &A,
+    count: usize,
+) -> Result<*mut T, AllocError> {
+    if count == 0 || core::mem::size_of::<T>() == 0 {
+        return Ok(core::ptr::NonNull::dangling().as_ptr());
+    }
+
+    let size = array_size::<T>(count)?;
+    let align = core::mem::align_of::<T>();
+
+    Ok(allocator.allocate(size, align)?.as_ptr() as *mut T)
+}
+
+// ------------------------------ Reallocate ------------------------------- //
+
+/// Resizes memory previously allocated for `old_count` items of type T to hold `new_count`.
+///
+/// The first `min(old_count, new_count)` items are preserved. On failure, null is returned
+/// and the old memory is left untouched.
+///
+/// # Safety
+/// - `ptr` must have been allocated using `alloc_many<T>` (or this function) with `old_count`
+/// - On success, `ptr` must not be accessed anymore; use the returned pointer instead
+///
+/// # Returns
+/// - Returns a dangling pointer if new_count is 0 or T is zero-sized
+/// - Returns null on allocation failure or size overflow
+/// - Otherwise returns a aligned pointer to allocated memory
+#[inline]
+pub unsafe fn realloc_many<T>(ptr: *mut T, old_count: usize, new_count: usize) -> *mut T {
+    unsafe { try_realloc_many(ptr, old_count, new_count).unwrap_or(core::ptr::null_mut()) }
+}
+
+/// Resizes memory previously allocated for `old_count` items of type T, reporting failures
+/// as an `AllocError`.
+///
+/// # Safety
+/// Same as `realloc_many`.
+#[inline(always)]
+pub unsafe fn try_realloc_many<T>(
+    ptr: *mut T,
+    old_count: usize,
+    new_count: usize,
+) -> Result<*mut T, AllocError> {
+    unsafe { try_realloc_many_in(&Global, ptr, old_count, new_count) }
+}
+
+/// Resizes memory previously allocated from `allocator` for `old_count` items of type T.
+///
+/// # Safety
+/// Same as `realloc_many`, with `ptr` allocated from `allocator`.
+pub unsafe fn try_realloc_many_in<T, A: Allocator + ?Sized>(
+    allocator: &A,
+    ptr: *mut T,
+    old_count: usize,
+    new_count: usize,
+) -> Result<*mut T, AllocError> {
+    // Nothing was allocated before; this is a plain allocation
+    if ptr.is_null() || old_count == 0 || core::mem::size_of::<T>() == 0 {
+        return unsafe { try_alloc_many_in(allocator, new_count) };
+    }
+
+    if new_count == 0 {
+        unsafe { dealloc_many_in(allocator, ptr, old_count) };
+
+        return Ok(core::ptr::NonNull::dangling().as_ptr());
+    }
+
+    let old_size = core::mem::size_of::<T>() * old_count;
+    let new_size = array_size::<T>(new_count)?;
+    let align = core::mem::align_of::<T>();
+
+    // SAFETY: `ptr` is non-null and was allocated from `allocator` with `old_size` bytes
+    let new_ptr = unsafe {
+        allocator.reallocate(
+            core::ptr::NonNull::new_unchecked(ptr as *mut u8),
+            old_size,
+            align,
+            new_size,
+        )?
+    };
+
+    Ok(new_ptr.as_ptr() as *mut T)
 }
 
 // ------------------------------ Deallocate ------------------------------- //
@@ -77,7 +207,7 @@ pub unsafe fn dealloc<T>(ptr: *mut T) {
     let size = core::mem::size_of::<T>();
     let align = core::mem::align_of::<T>();
 
-    unsafe { _dealloc_raw(ptr as *mut u8, size, align) };
+    unsafe { raw_dealloc(ptr as *mut u8, size, align) };
 }
 
 /// Deallocates memory previously allocated for `count` items of type T.
@@ -88,24 +218,305 @@ pub unsafe fn dealloc<T>(ptr: *mut T) {
 /// - This function must not be called twice with the same pointer
 #[inline(always)]
 pub unsafe fn dealloc_many<T>(ptr: *mut T, count: usize) {
+    unsafe { dealloc_many_in(&Global, ptr, count) };
+}
+
+/// Deallocates memory previously allocated from `allocator` for `count` items of type T.
+///
+/// # Safety
+/// - `ptr` must have been allocated from `allocator` using `try_alloc_many_in<T>` with the
+///   same count
+/// - The memory must not be accessed after this call
+/// - This function must not be called twice with the same pointer
+#[inline]
+pub unsafe fn dealloc_many_in<T, A: Allocator + ?Sized>(allocator: &A, ptr: *mut T, count: usize) {
     // Handle null pointers, zero count, and zero-sized types(ZSTs)
     if ptr.is_null() || count == 0 || core::mem::size_of::<T>() == 0 {
         return;
     }
 
     // Check for size overflow
-    let size = match core::mem::size_of::<T>().checked_mul(count) {
-        Some(size) => size,
-        None => return,
+    let size = match array_size::<T>(count) {
+        Ok(size) => size,
+        Err(_) => return,
+    };
+    let align = core::mem::align_of::<T>();
+
+    // SAFETY: `ptr` is non-null and was allocated from `allocator` with `size` bytes
+    unsafe {
+        allocator.deallocate(
+            core::ptr::NonNull::new_unchecked(ptr as *mut u8),
+            size,
+            align,
+        )
+    };
+}
+
+// ------------------------------ AllocError ------------------------------- //
+
+/// The reason an allocation failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocError {
+    /// The requested size doesn't fit in `isize::MAX` bytes.
+    CapacityOverflow,
+    /// The allocator returned null for a request of `size` bytes aligned to `align`.
+    OutOfMemory { size: usize, align: usize },
+}
+
+impl core::fmt::Display for AllocError {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CapacityOverflow => write!(f, "capacity overflow"),
+            Self::OutOfMemory { size, align } => {
+                write!(
+                    f,
+                    "out of memory allocating {size} bytes aligned to {align}"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocError {}
+
+/// A handler called when an infallible allocation runs out of memory.
+///
+/// Receives the size and alignment of the failed request and must not return.
+pub type OomHook = fn(size: usize, align: usize) -> !;
+
+/// The installed `OomHook`, or null for the default panic.
+static OOM_HOOK: core::sync::atomic::AtomicPtr<()> =
+    core::sync::atomic::AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs `hook` to be called by `handle_alloc_error` when memory runs out.
+///
+/// On targets where unwinding is unavailable or unwanted, the hook can log the failure and
+/// abort or reset instead of panicking.
+#[inline]
+pub fn set_oom_hook(hook: OomHook) {
+    OOM_HOOK.store(hook as *mut (), core::sync::atomic::Ordering::Release);
+}
+
+/// Removes the hook installed by `set_oom_hook`, restoring the default panic.
+#[inline]
+pub fn take_oom_hook() -> Option<OomHook> {
+    #[cfg(target_has_atomic = "ptr")]
+    let hook = OOM_HOOK.swap(core::ptr::null_mut(), core::sync::atomic::Ordering::AcqRel);
+
+    // Without compare-and-swap, a hook installed between the load and the store is lost
+    #[cfg(not(target_has_atomic = "ptr"))]
+    let hook = {
+        let hook = OOM_HOOK.load(core::sync::atomic::Ordering::Acquire);
+
+        OOM_HOOK.store(core::ptr::null_mut(), core::sync::atomic::Ordering::Release);
+
+        hook
     };
+
+    if hook.is_null() {
+        return None;
+    }
+
+    // SAFETY: Only `OomHook` function pointers are ever stored in `OOM_HOOK`
+    Some(unsafe { core::mem::transmute::<*mut (), OomHook>(hook) })
+}
+
+/// Diverges on an allocation failure in an infallible context.
+///
+/// Capacity overflows always panic. Running out of memory calls the hook installed with
+/// `set_oom_hook`, or panics if there is none.
+#[cold]
+pub fn handle_alloc_error(error: AllocError) -> ! {
+    match error {
+        AllocError::CapacityOverflow => panic!("Capacity overflow"),
+        AllocError::OutOfMemory { size, align } => {
+            let hook = OOM_HOOK.load(core::sync::atomic::Ordering::Acquire);
+
+            if !hook.is_null() {
+                // SAFETY: Only `OomHook` function pointers are ever stored in `OOM_HOOK`
+                let hook = unsafe { core::mem::transmute::<*mut (), OomHook>(hook) };
+
+                hook(size, align);
+            }
+
+            panic!("Out of memory: failed to allocate {size} bytes aligned to {align}");
+        }
+    }
+}
+
+// ------------------------------ Allocator -------------------------------- //
+
+/// A source of memory for collections.
+///
+/// Collections take an allocator as a type parameter, defaulting to `Global`. Allocators are
+/// usually passed by reference (`&A` is an allocator too), so several collections can share
+/// one, such as a per-frame arena that is reset in one shot.
+///
+/// # Safety
+/// Memory returned by `allocate` and `reallocate` must be valid for reads and writes of `size`
+/// bytes, aligned to `align`, and must not be handed out again until it is deallocated.
+pub unsafe trait Allocator {
+    /// Allocates `size` bytes aligned to `align`, a power of two.
+    ///
+    /// `size` is never zero; collections don't allocate for zero-sized requests.
+    fn allocate(&self, size: usize, align: usize) -> Result<core::ptr::NonNull<u8>, AllocError>;
+
+    /// Deallocates memory returned by this allocator.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated by this allocator with the same `size` and `align`.
+    unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, size: usize, align: usize);
+
+    /// Resizes memory returned by this allocator, preserving its first `min(old_size, new_size)`
+    /// bytes. On failure, the old memory is left untouched.
+    ///
+    /// The default implementation allocates, copies and deallocates.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated by this allocator with `old_size` and `align`.
+    unsafe fn reallocate(
+        &self,
+        ptr: core::ptr::NonNull<u8>,
+        old_size: usize,
+        align: usize,
+        new_size: usize,
+    ) -> Result<core::ptr::NonNull<u8>, AllocError> {
+        let new_ptr = self.allocate(new_size, align)?;
+
+        // SAFETY: Both blocks are valid for `min(old_size, new_size)` bytes and don't overlap
+        unsafe {
+            core::ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_size.min(new_size));
+
+            self.deallocate(ptr, old_size, align);
+        }
+
+        Ok(new_ptr)
+    }
+}
+
+unsafe impl<A: Allocator + ?Sized> Allocator for &A {
+    #[inline(always)]
+    fn allocate(&self, size: usize, align: usize) -> Result<core::ptr::NonNull<u8>, AllocError> {
+        (**self).allocate(size, align)
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, size: usize, align: usize) {
+        unsafe { (**self).deallocate(ptr, size, align) }
+    }
+
+    #[inline(always)]
+    unsafe fn reallocate(
+        &self,
+        ptr: core::ptr::NonNull<u8>,
+        old_size: usize,
+        align: usize,
+        new_size: usize,
+    ) -> Result<core::ptr::NonNull<u8>, AllocError> {
+        unsafe { (**self).reallocate(ptr, old_size, align, new_size) }
+    }
+}
+
+// -------------------------------- Global --------------------------------- //
+
+/// The global allocator, backed by the `_alloc_raw` and `_dealloc_raw` interfaces, and by
+/// `_realloc_raw` with the `std` or `realloc-raw` feature.
+#[derive(Clone, Copy, Default)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+    #[inline(always)]
+    fn allocate(&self, size: usize, align: usize) -> Result<core::ptr::NonNull<u8>, AllocError> {
+        // SAFETY: `size` and `align` describe a valid layout, as required by the trait
+        let ptr = unsafe { raw_alloc(size, align) };
+
+        core::ptr::NonNull::new(ptr).ok_or(AllocError::OutOfMemory { size, align })
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, size: usize, align: usize) {
+        unsafe { raw_dealloc(ptr.as_ptr(), size, align) };
+    }
+
+    #[cfg(any(feature = "std", feature = "realloc-raw"))]
+    #[inline(always)]
+    unsafe fn reallocate(
+        &self,
+        ptr: core::ptr::NonNull<u8>,
+        old_size: usize,
+        align: usize,
+        new_size: usize,
+    ) -> Result<core::ptr::NonNull<u8>, AllocError> {
+        let new_ptr = unsafe { raw_realloc(ptr.as_ptr(), old_size, align, new_size) };
+
+        core::ptr::NonNull::new(new_ptr).ok_or(AllocError::OutOfMemory {
+            size: new_size,
+            align,
+        })
+    }
+}
+
+// ------------------------------- Helpers --------------------------------- //
+
+/// Returns the size in bytes of `count` items of type T, if a `Layout` can describe it.
+#[inline(always)]
+fn array_size<T>(count: usize) -> Result<usize, AllocError> {
     let align = core::mem::align_of::<T>();
 
-    unsafe { _dealloc_raw(ptr as *mut u8, size, align) };
+    match core::mem::size_of::<T>().checked_mul(count) {
+        // A layout's size, rounded up to its alignment, must not exceed `isize::MAX`
+        Some(size) if size <= isize::MAX as usize - (align - 1) => Ok(size),
+        _ => Err(AllocError::CapacityOverflow),
+    }
 }
 
 // ------------------------------ Interfaces ------------------------------- //
 
+/// Calls `_alloc_raw`, recording the allocation when tracking is enabled.
+#[inline(always)]
+unsafe fn raw_alloc(size: usize, align: usize) -> *mut u8 {
+    let ptr = unsafe { _alloc_raw(size, align) };
+
+    #[cfg(feature = "alloc-debug")]
+    if !ptr.is_null() {
+        crate::alloc_debug::record_alloc(ptr, size, align);
+    }
+
+    ptr
+}
+
+/// Calls `_dealloc_raw`, checking the deallocation first when tracking is enabled.
+#[inline(always)]
+unsafe fn raw_dealloc(ptr: *mut u8, size: usize, align: usize) {
+    #[cfg(feature = "alloc-debug")]
+    crate::alloc_debug::record_dealloc(ptr, size, align);
+
+    unsafe { _dealloc_raw(ptr, size, align) };
+}
+
+/// Calls `_realloc_raw`, checking and recording the move when tracking is enabled.
+#[cfg(any(feature = "std", feature = "realloc-raw"))]
+#[inline(always)]
+unsafe fn raw_realloc(ptr: *mut u8, old_size: usize, align: usize, new_size: usize) -> *mut u8 {
+    #[cfg(feature = "alloc-debug")]
+    crate::alloc_debug::check_dealloc(ptr, old_size, align);
+
+    let new_ptr = unsafe { _realloc_raw(ptr, old_size, align, new_size) };
+
+    #[cfg(feature = "alloc-debug")]
+    if !new_ptr.is_null() {
+        crate::alloc_debug::record_dealloc(ptr, old_size, align);
+        crate::alloc_debug::record_alloc(new_ptr, new_size, align);
+    }
+
+    new_ptr
+}
+
 extern "C" {
     fn _alloc_raw(size: usize, align: usize) -> *mut u8;
     fn _dealloc_raw(ptr: *mut u8, size: usize, align: usize) -> *mut u8;
+    #[cfg(any(feature = "std", feature = "realloc-raw"))]
+    fn _realloc_raw(ptr: *mut u8, old_size: usize, align: usize, new_size: usize) -> *mut u8;
 }
@@ -0,0 +1,388 @@
+// ---------------------------- alloc_debug.rs ----------------------------- //
+
+//! Allocation tracking and leak detection.
+//!
+//! This module is only compiled with the `alloc-debug` feature. Every call to
+//! the global allocation interfaces is then recorded in a fixed-size table, to
+//! keep statistics on live and peak memory usage and to catch misuse:
+//!
+//! - Freeing a pointer that isn't live (a double free or a foreign pointer)
+//! - Freeing with a different size or alignment than it was allocated with
+//!
+//! Misuse panics with a description of the problem, before the allocator is
+//! reached. Outstanding allocations can be listed with `for_each_live`, or
+//! printed with `dump_live` under the `std` feature, typically at shutdown.
+//!
+//! The table holds `MAX_TRACKED` allocations, set with the
+//! `CELUI_ALLOC_DEBUG_CAPACITY` environment variable at build time. Allocations
+//! beyond that are still counted in the statistics but can't be checked or
+//! listed: while any of them is live, freeing an unknown pointer can't be told
+//! apart from freeing one of them, so double frees go unnoticed. Once that has
+//! happened, `AllocStats::overflowed` stays set and `dump_live` warns about it.
+
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// The number of live allocations that can be tracked individually.
+///
+/// Defaults to 4096, or the value of `CELUI_ALLOC_DEBUG_CAPACITY` at build time. The table
+/// takes about `6 * size_of::<usize>()` bytes of static memory per allocation, 192 KiB for
+/// the default on 64-bit targets.
+pub const MAX_TRACKED: usize = match option_env!("CELUI_ALLOC_DEBUG_CAPACITY") {
+    Some(capacity) => parse_capacity(capacity),
+    None => 4096,
+};
+
+const TABLE_SIZE: usize = (MAX_TRACKED * 2).next_power_of_two(); // Keeps the probes short
+const TABLE_MASK: usize = TABLE_SIZE - 1;
+
+// ------------------------------ AllocStats ------------------------------- //
+
+/// A snapshot of the global allocation statistics.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Number of live allocations.
+    pub live_allocations: usize,
+    /// Number of bytes in live allocations.
+    pub live_bytes: usize,
+    /// Largest number of live allocations at any point.
+    pub peak_allocations: usize,
+    /// Largest number of bytes in live allocations at any point.
+    pub peak_bytes: usize,
+    /// Number of allocations made so far, reallocations included.
+    pub total_allocations: usize,
+    /// Number of live allocations that didn't fit in the table and aren't checked.
+    pub untracked_allocations: usize,
+    /// Whether the table has ever been full. Frees may have gone unchecked since, so a clean
+    /// report can't rule out double frees.
+    pub overflowed: bool,
+}
+
+/// Returns a snapshot of the global allocation statistics.
+///
+/// # Example
+/// ```
+/// use celui_sys::{alloc, alloc_debug};
+///
+/// let before = alloc_debug::stats();
+/// let ptr = unsafe { alloc::alloc_many::<u32>(16) };
+///
+/// let during = alloc_debug::stats();
+/// assert!(during.live_bytes >= before.live_bytes + 64);
+/// assert!(!during.overflowed);
+///
+/// unsafe { alloc::dealloc_many(ptr, 16) };
+/// ```
+#[inline]
+pub fn stats() -> AllocStats {
+    TRACKER.lock(|tracker| tracker.stats)
+}
+
+/// Calls `f` with the pointer, size and alignment of every tracked live allocation.
+///
+/// The allocator must not be used from `f`.
+pub fn for_each_live<F: FnMut(*const u8, usize, usize)>(mut f: F) {
+    TRACKER.lock(|tracker| {
+        for entry in tracker.entries.iter().filter(|entry| entry.ptr != 0) {
+            f(entry.ptr as *const u8, entry.size, entry.align);
+        }
+    });
+}
+
+/// Prints the statistics and every tracked live allocation to `stderr`.
+///
+/// Call it at shutdown, once everything should have been freed, to find leaks.
+#[cfg(feature = "std")]
+pub fn dump_live() {
+    // Copied out first, since printing may allocate
+    let stats = stats();
+    let mut live = [(0usize, 0usize, 0usize); 32];
+    let mut count = 0;
+
+    for_each_live(|ptr, size, align| {
+        if count < live.len() {
+            live[count] = (ptr as usize, size, align);
+        }
+
+        count += 1;
+    });
+
+    eprintln!("{stats:?}");
+
+    for &(ptr, size, align) in live.iter().take(count) {
+        eprintln!("  live: {ptr:#x}, {size} bytes aligned to {align}");
+    }
+
+    if count > live.len() {
+        eprintln!("  ... and {} more", count - live.len());
+    }
+
+    if stats.overflowed {
+        eprintln!(
+            "  warning: more than {MAX_TRACKED} allocations were live at once, so some frees \
+             were not checked; raise CELUI_ALLOC_DEBUG_CAPACITY to check them all"
+        );
+    }
+}
+
+// ------------------------------- Recording ------------------------------- //
+
+/// Records a new allocation.
+pub(crate) fn record_alloc(ptr: *mut u8, size: usize, align: usize) {
+    TRACKER.lock(|tracker| tracker.insert(ptr as usize, size, align));
+}
+
+/// Checks that a deallocation matches a live allocation, then forgets it.
+pub(crate) fn record_dealloc(ptr: *mut u8, size: usize, align: usize) {
+    TRACKER.lock(|tracker| tracker.remove(ptr as usize, size, align));
+}
+
+/// Checks that a deallocation matches a live allocation, without forgetting it.
+#[cfg(any(feature = "std", feature = "realloc-raw"))]
+pub(crate) fn check_dealloc(ptr: *mut u8, size: usize, align: usize) {
+    TRACKER.lock(|tracker| {
+        if let Some(i) = tracker.find(ptr as usize) {
+            tracker.check(i, size, align);
+        } else if tracker.stats.untracked_allocations == 0 {
+            invalid_free(ptr as usize, tracker.stats.overflowed);
+        }
+    });
+}
+
+#[cold]
+fn invalid_free(ptr: usize, overflowed: bool) -> ! {
+    let note = if overflowed {
+        "; the table overflowed earlier, so the first misuse may not be this one"
+    } else {
+        ""
+    };
+
+    panic!(
+        "alloc-debug: freeing {ptr:#x}, which is not live (double free or foreign pointer){note}"
+    );
+}
+
+/// Parses `CELUI_ALLOC_DEBUG_CAPACITY`, failing the build if it isn't a positive number.
+const fn parse_capacity(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut capacity = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        assert!(
+            bytes[i].is_ascii_digit(),
+            "CELUI_ALLOC_DEBUG_CAPACITY must be a number"
+        );
+
+        capacity = capacity * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+
+    assert!(capacity > 0, "CELUI_ALLOC_DEBUG_CAPACITY must be positive");
+
+    capacity
+}
+
+// -------------------------------- Tracker -------------------------------- //
+
+/// A live allocation; `ptr` is 0 for empty table slots.
+#[derive(Clone, Copy)]
+struct Entry {
+    ptr: usize,
+    size: usize,
+    align: usize,
+}
+
+impl Entry {
+    const EMPTY: Self = Self {
+        ptr: 0,
+        size: 0,
+        align: 0,
+    };
+}
+
+/// The global table of live allocations, with open addressing and linear probing.
+struct Tracker {
+    entries: [Entry; TABLE_SIZE],
+    tracked: usize,
+    stats: AllocStats,
+}
+
+impl Tracker {
+    /// Returns the preferred slot of `ptr`.
+    #[inline(always)]
+    fn home(ptr: usize) -> usize {
+        // Fibonacci hashing; the low bits of pointers are mostly alignment
+        ((ptr as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32) as usize & TABLE_MASK
+    }
+
+    /// Returns the slot holding `ptr`, if it is tracked.
+    fn find(&self, ptr: usize) -> Option<usize> {
+        let mut i = Self::home(ptr);
+
+        loop {
+            match self.entries[i].ptr {
+                0 => return None,
+                current if current == ptr => return Some(i),
+                _ => i = (i + 1) & TABLE_MASK,
+            }
+        }
+    }
+
+    fn insert(&mut self, ptr: usize, size: usize, align: usize) {
+        let stats = &mut self.stats;
+
+        stats.live_allocations += 1;
+        stats.live_bytes += size;
+        stats.total_allocations += 1;
+        stats.peak_allocations = stats.peak_allocations.max(stats.live_allocations);
+        stats.peak_bytes = stats.peak_bytes.max(stats.live_bytes);
+
+        if self.tracked == MAX_TRACKED {
+            stats.untracked_allocations += 1;
+            stats.overflowed = true;
+
+            return;
+        }
+
+        let mut i = Self::home(ptr);
+
+        while self.entries[i].ptr != 0 {
+            i = (i + 1) & TABLE_MASK;
+        }
+
+        self.entries[i] = Entry { ptr, size, align };
+        self.tracked += 1;
+    }
+
+    fn remove(&mut self, ptr: usize, size: usize, align: usize) {
+        let Some(mut i) = self.find(ptr) else {
+            // An untracked allocation, or an invalid free if everything is tracked
+            if self.stats.untracked_allocations == 0 {
+                invalid_free(ptr, self.stats.overflowed);
+            }
+
+            self.stats.untracked_allocations -= 1;
+            self.stats.live_allocations -= 1;
+            self.stats.live_bytes -= size;
+
+            return;
+        };
+
+        self.check(i, size, align);
+
+        self.stats.live_allocations -= 1;
+        self.stats.live_bytes -= size;
+        self.tracked -= 1;
+
+        // Backward-shift deletion keeps every entry reachable from its home slot
+        let mut j = i;
+
+        loop {
+            j = (j + 1) & TABLE_MASK;
+
+            let entry = self.entries[j];
+
+            if entry.ptr == 0 {
+                break;
+            }
+
+            let home = Self::home(entry.ptr);
+            let distance_to_hole = i.wrapping_sub(home) & TABLE_MASK;
+            let distance_to_entry = j.wrapping_sub(home) & TABLE_MASK;
+
+            if distance_to_hole < distance_to_entry {
+                self.entries[i] = entry;
+                i = j;
+            }
+        }
+
+        self.entries[i] = Entry::EMPTY;
+    }
+
+    /// Panics if the allocation in slot `i` doesn't have the given size and alignment.
+    fn check(&self, i: usize, size: usize, align: usize) {
+        let entry = self.entries[i];
+
+        if entry.size != size || entry.align != align {
+            panic!(
+                "alloc-debug: freeing {:#x} as {size} bytes aligned to {align}, \
+                 but it was allocated as {} bytes aligned to {}",
+                entry.ptr, entry.size, entry.align
+            );
+        }
+    }
+}
+
+// --------------------------------- Lock ---------------------------------- //
+
+/// A `Tracker` behind a spin lock, usable from a `static`.
+struct Locked {
+    locked: AtomicBool,
+    tracker: UnsafeCell<Tracker>,
+}
+
+// SAFETY: The tracker is only accessed while `locked` is held
+unsafe impl Sync for Locked {}
+
+impl Locked {
+    /// Runs `f` with exclusive access to the tracker.
+    ///
+    /// The lock is released even if `f` panics, so a reported misuse doesn't deadlock the
+    /// allocator afterwards.
+    fn lock<R, F: FnOnce(&mut Tracker) -> R>(&self, f: F) -> R {
+        struct Unlock<'a>(&'a AtomicBool);
+
+        impl Drop for Unlock<'_> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                self.0.store(false, Ordering::Release);
+            }
+        }
+
+        #[cfg(target_has_atomic = "8")]
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+
+        // Targets without compare-and-swap have a single core, so a held lock means this call
+        // interrupted the holder, which spinning would deadlock
+        #[cfg(not(target_has_atomic = "8"))]
+        {
+            assert!(
+                !self.locked.load(Ordering::Acquire),
+                "alloc-debug: allocation from an interrupt handler while tracking another one"
+            );
+
+            self.locked.store(true, Ordering::Relaxed);
+        }
+
+        let _unlock = Unlock(&self.locked);
+
+        // SAFETY: The lock is held until `_unlock` is dropped
+        f(unsafe { &mut *self.tracker.get() })
+    }
+}
+
+static TRACKER: Locked = Locked {
+    locked: AtomicBool::new(false),
+    tracker: UnsafeCell::new(Tracker {
+        entries: [Entry::EMPTY; TABLE_SIZE],
+        tracked: 0,
+        stats: AllocStats {
+            live_allocations: 0,
+            live_bytes: 0,
+            peak_allocations: 0,
+            peak_bytes: 0,
+            total_allocations: 0,
+            untracked_allocations: 0,
+            overflowed: false,
+        },
+    }),
+};
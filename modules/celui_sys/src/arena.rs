@@ -0,0 +1,379 @@
+// ------------------------------- arena.rs -------------------------------- //
+
+//! Bump allocation for short-lived, per-frame data.
+//!
+//! An `Arena` hands out memory by bumping a cursor through large chunks, which
+//! makes allocating almost free. Individual deallocations are ignored (except
+//! for the most recent one), and everything is released at once with `reset`,
+//! which keeps the chunks around for the next frame.
+//!
+//! `Arena` implements `Allocator`, so collections can live in it through a
+//! shared reference. Since `reset` takes `&mut self`, the borrow checker
+//! guarantees that no collection still uses the arena when it is reset.
+
+use core::{cell::Cell, ptr::NonNull};
+
+use crate::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+const DEFAULT_CHUNK_SIZE: usize = 16 * 1024; // Usable bytes per chunk, unless a request is larger
+
+// -------------------------------- Chunk ---------------------------------- //
+
+/// The header in front of each chunk's usable bytes.
+struct Chunk {
+    next: *mut Chunk, // Next chunk in allocation order, or null
+    size: usize,      // Number of usable bytes after the header
+}
+
+impl Chunk {
+    const HEADER_SIZE: usize = core::mem::size_of::<Chunk>();
+    const ALIGN: usize = core::mem::align_of::<Chunk>();
+
+    /// Returns the first usable byte of `chunk`.
+    ///
+    /// # SAFETY
+    /// `chunk` must point to a live chunk.
+    #[inline(always)]
+    unsafe fn start(chunk: *mut Chunk) -> *mut u8 {
+        unsafe { (chunk as *mut u8).add(Self::HEADER_SIZE) }
+    }
+
+    /// Returns one past the last usable byte of `chunk`.
+    ///
+    /// # SAFETY
+    /// `chunk` must point to a live chunk.
+    #[inline(always)]
+    unsafe fn end(chunk: *mut Chunk) -> *mut u8 {
+        unsafe { Self::start(chunk).add((*chunk).size) }
+    }
+}
+
+// -------------------------------- Arena ---------------------------------- //
+
+/// A bump allocator with frame-reset semantics.
+///
+/// # Example
+/// ```
+/// use celui_sys::{alloc::Allocator, arena::Arena};
+///
+/// let mut arena = Arena::new();
+///
+/// for _frame in 0..3 {
+///     let a = arena.allocate(100, 8).unwrap();
+///     let b = arena.allocate(50, 4).unwrap();
+///
+///     assert_ne!(a, b);
+///     assert_eq!(arena.allocated_bytes(), 150);
+///
+///     // Everything from this frame is released at once
+///     arena.reset();
+/// }
+///
+/// assert_eq!(arena.allocated_bytes(), 0);
+/// assert_eq!(arena.high_water_mark(), 150);
+/// assert_eq!(arena.chunk_count(), 1);
+/// ```
+pub struct Arena<A: Allocator = Global> {
+    first: Cell<*mut Chunk>,   // Head of the chunk list
+    current: Cell<*mut Chunk>, // Chunk being bumped through
+    cursor: Cell<*mut u8>,     // Next free byte in `current`
+    end: Cell<*mut u8>,        // One past the last usable byte of `current`
+    chunk_size: usize,         // Usable bytes of newly allocated chunks
+    allocated: Cell<usize>,    // Bytes handed out since the last reset
+    high_water: Cell<usize>,   // Largest `allocated` ever reached
+    capacity: Cell<usize>,     // Usable bytes across all chunks
+    chunks: Cell<usize>,       // Number of chunks
+    allocator: A,
+}
+
+impl Arena {
+    /// Creates an empty arena; the first chunk is allocated on first use.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::with_chunk_size_in(DEFAULT_CHUNK_SIZE, Global)
+    }
+
+    /// Creates an empty arena that allocates chunks of `chunk_size` usable bytes.
+    #[inline(always)]
+    pub const fn with_chunk_size(chunk_size: usize) -> Self {
+        Self::with_chunk_size_in(chunk_size, Global)
+    }
+}
+
+impl<A: Allocator> Arena<A> {
+    /// Creates an empty arena that allocates chunks of `chunk_size` usable bytes from `allocator`.
+    #[inline(always)]
+    pub const fn with_chunk_size_in(chunk_size: usize, allocator: A) -> Self {
+        Self {
+            first: Cell::new(core::ptr::null_mut()),
+            current: Cell::new(core::ptr::null_mut()),
+            cursor: Cell::new(core::ptr::null_mut()),
+            end: Cell::new(core::ptr::null_mut()),
+            chunk_size,
+            allocated: Cell::new(0),
+            high_water: Cell::new(0),
+            capacity: Cell::new(0),
+            chunks: Cell::new(0),
+            allocator,
+        }
+    }
+
+    /// Returns the number of bytes handed out since the last reset.
+    #[inline(always)]
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated.get()
+    }
+
+    /// Returns the largest number of bytes ever handed out between two resets.
+    #[inline(always)]
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water.get()
+    }
+
+    /// Returns the number of usable bytes across all chunks.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.capacity.get()
+    }
+
+    /// Returns the number of chunks allocated so far.
+    #[inline(always)]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.get()
+    }
+
+    /// Releases everything allocated from the arena, keeping its chunks for reuse.
+    ///
+    /// Values living in the arena are not dropped.
+    pub fn reset(&mut self) {
+        let first = self.first.get();
+
+        self.current.set(first);
+        self.allocated.set(0);
+
+        if first.is_null() {
+            return;
+        }
+
+        // SAFETY: `first` is a live chunk
+        unsafe {
+            self.cursor.set(Chunk::start(first));
+            self.end.set(Chunk::end(first));
+        }
+    }
+
+    /// Moves a value into the arena and returns a reference to it.
+    ///
+    /// The value is never dropped; the memory is reclaimed by `reset`.
+    #[inline]
+    #[allow(clippy::mut_from_ref)] // Every call returns a new, unaliased allocation
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        let ptr = if core::mem::size_of::<T>() == 0 {
+            NonNull::<T>::dangling()
+        } else {
+            self.allocate(core::mem::size_of::<T>(), core::mem::align_of::<T>())
+                .unwrap_or_else(|error| handle_alloc_error(error))
+                .cast::<T>()
+        };
+
+        // SAFETY: `ptr` is valid and aligned for a `T`, and no one else refers to it
+        unsafe {
+            ptr.as_ptr().write(value);
+
+            &mut *ptr.as_ptr()
+        }
+    }
+
+    /// Bumps the cursor of the current chunk, or returns `None` if the request doesn't fit.
+    #[inline(always)]
+    fn bump(&self, size: usize, align: usize) -> Option<NonNull<u8>> {
+        let cursor = self.cursor.get();
+        let end = self.end.get();
+
+        let padding = (cursor as usize).wrapping_neg() & (align - 1);
+        let available = (end as usize).wrapping_sub(cursor as usize);
+
+        if cursor.is_null() || padding.checked_add(size)? > available {
+            return None;
+        }
+
+        // SAFETY: `padding + size` fits between `cursor` and `end` in the current chunk
+        unsafe {
+            let ptr = cursor.add(padding);
+
+            self.cursor.set(ptr.add(size));
+            self.record(size as isize);
+
+            Some(NonNull::new_unchecked(ptr))
+        }
+    }
+
+    /// Makes a chunk that fits `size` bytes aligned to `align` current, reusing later chunks
+    /// before allocating a new one.
+    fn next_chunk(&self, size: usize, align: usize) -> Result<(), AllocError> {
+        let needed = size
+            .checked_add(align - 1)
+            .ok_or(AllocError::CapacityOverflow)?;
+
+        let mut last = self.current.get();
+
+        // SAFETY: Every chunk in the list is live
+        unsafe {
+            let mut chunk = if last.is_null() {
+                self.first.get()
+            } else {
+                (*last).next
+            };
+
+            while !chunk.is_null() {
+                if (*chunk).size >= needed {
+                    self.set_current(chunk);
+
+                    return Ok(());
+                }
+
+                last = chunk;
+                chunk = (*chunk).next;
+            }
+        }
+
+        let data_size = self.chunk_size.max(needed);
+        let total = data_size
+            .checked_add(Chunk::HEADER_SIZE)
+            .ok_or(AllocError::CapacityOverflow)?;
+
+        let chunk = self.allocator.allocate(total, Chunk::ALIGN)?.as_ptr() as *mut Chunk;
+
+        // SAFETY: `chunk` was just allocated with room for the header, and `last` is live
+        unsafe {
+            chunk.write(Chunk {
+                next: core::ptr::null_mut(),
+                size: data_size,
+            });
+
+            if last.is_null() {
+                self.first.set(chunk);
+            } else {
+                (*last).next = chunk;
+            }
+
+            self.set_current(chunk);
+        }
+
+        self.capacity.set(self.capacity.get() + data_size);
+        self.chunks.set(self.chunks.get() + 1);
+
+        Ok(())
+    }
+
+    /// Starts bumping through `chunk`.
+    ///
+    /// # SAFETY
+    /// `chunk` must be a live chunk of this arena.
+    #[inline(always)]
+    unsafe fn set_current(&self, chunk: *mut Chunk) {
+        self.current.set(chunk);
+
+        unsafe {
+            self.cursor.set(Chunk::start(chunk));
+            self.end.set(Chunk::end(chunk));
+        }
+    }
+
+    /// Adds `delta` bytes to the allocation statistics.
+    #[inline(always)]
+    fn record(&self, delta: isize) {
+        let allocated = self.allocated.get().wrapping_add_signed(delta);
+
+        self.allocated.set(allocated);
+        self.high_water.set(self.high_water.get().max(allocated));
+    }
+}
+
+impl Default for Arena {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<A: Allocator> Allocator for Arena<A> {
+    #[inline]
+    fn allocate(&self, size: usize, align: usize) -> Result<NonNull<u8>, AllocError> {
+        if let Some(ptr) = self.bump(size, align) {
+            return Ok(ptr);
+        }
+
+        self.next_chunk(size, align)?;
+
+        // The new current chunk is large enough for the aligned request
+        Ok(self.bump(size, align).expect("Chunk too small"))
+    }
+
+    /// Only reclaims the memory if it was the most recent allocation.
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, size: usize, _align: usize) {
+        let ptr = ptr.as_ptr();
+
+        // SAFETY: `ptr` was allocated with `size` bytes, so `ptr + size` is in bounds
+        if unsafe { ptr.add(size) } == self.cursor.get() {
+            self.cursor.set(ptr);
+            self.record(-(size as isize));
+        }
+    }
+
+    /// Grows or shrinks in place if `ptr` is the most recent allocation and the chunk has room.
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_size: usize,
+        align: usize,
+        new_size: usize,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let start = ptr.as_ptr();
+
+        // SAFETY: `ptr` was allocated with `old_size` bytes, so `ptr + old_size` is in bounds
+        let is_last = unsafe { start.add(old_size) } == self.cursor.get();
+
+        if is_last && new_size <= self.end.get() as usize - start as usize {
+            // SAFETY: `start + new_size` is within the current chunk
+            self.cursor.set(unsafe { start.add(new_size) });
+            self.record(new_size as isize - old_size as isize);
+
+            return Ok(ptr);
+        }
+
+        if new_size <= old_size {
+            return Ok(ptr);
+        }
+
+        let new_ptr = self.allocate(new_size, align)?;
+
+        // SAFETY: Both blocks are valid for `old_size` bytes and don't overlap
+        unsafe { core::ptr::copy_nonoverlapping(start, new_ptr.as_ptr(), old_size) };
+
+        Ok(new_ptr)
+    }
+}
+
+impl<A: Allocator> Drop for Arena<A> {
+    fn drop(&mut self) {
+        let mut chunk = self.first.get();
+
+        while !chunk.is_null() {
+            // SAFETY: `chunk` is live and was allocated with its header and `size` bytes
+            unsafe {
+                let next = (*chunk).next;
+                let total = (*chunk).size + Chunk::HEADER_SIZE;
+
+                self.allocator.deallocate(
+                    NonNull::new_unchecked(chunk as *mut u8),
+                    total,
+                    Chunk::ALIGN,
+                );
+
+                chunk = next;
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod alloc;
+#[cfg(feature = "alloc-debug")]
+pub mod alloc_debug;
+pub mod arena;
+pub mod pool;
 
 #[cfg(feature = "std")]
 mod std;
@@ -0,0 +1,329 @@
+// -------------------------------- pool.rs -------------------------------- //
+
+//! Fixed-size object pools.
+//!
+//! A `Pool<T>` carves blocks of memory into slots that each hold one `T`, and
+//! recycles freed slots through a free list. Allocating and freeing are O(1)
+//! and never fragment, which suits widgets, nodes and other objects that are
+//! created and destroyed in large numbers.
+//!
+//! `Pool` also implements `Allocator`: requests that fit in a slot are served
+//! from the pool, and larger ones are forwarded to the backing allocator.
+
+use core::{cell::Cell, marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+use crate::alloc::{handle_alloc_error, AllocError, Allocator, Global};
+
+const DEFAULT_BLOCK_CAPACITY: usize = 64; // Slots per block
+
+// -------------------------------- Block ---------------------------------- //
+
+/// A free slot links to the next one; a used slot holds a value.
+union Slot<T> {
+    next: *mut Slot<T>,
+    _value: ManuallyDrop<T>,
+}
+
+/// The header in front of each block's slots.
+struct Block {
+    next: *mut Block, // Next block in allocation order, or null
+}
+
+// --------------------------------- Pool ---------------------------------- //
+
+/// A typed slab allocator for objects of type `T`.
+///
+/// # Example
+/// ```
+/// use celui_sys::pool::Pool;
+///
+/// let pool = Pool::with_block_capacity(4);
+///
+/// let a = pool.alloc([1u32; 8]);
+/// let b = pool.alloc([2u32; 8]);
+///
+/// assert_eq!(pool.live(), 2);
+/// assert_eq!(pool.capacity(), 4);
+///
+/// // SAFETY: `a` came from this pool and is freed once
+/// let value = unsafe { pool.free(a) };
+/// assert_eq!(value, [1; 8]);
+///
+/// // The freed slot is reused
+/// let c = pool.alloc([3u32; 8]);
+/// assert_eq!(c, a);
+///
+/// unsafe {
+///     pool.free(b);
+///     pool.free(c);
+/// }
+///
+/// assert_eq!(pool.live(), 0);
+/// assert_eq!(pool.high_water_mark(), 2);
+/// ```
+pub struct Pool<T, A: Allocator = Global> {
+    free: Cell<*mut Slot<T>>, // Head of the free list
+    blocks: Cell<*mut Block>, // Head of the block list
+    block_capacity: usize,    // Slots per block
+    live: Cell<usize>,        // Slots in use
+    high_water: Cell<usize>,  // Largest `live` ever reached
+    capacity: Cell<usize>,    // Slots across all blocks
+    allocator: A,
+
+    _marker: PhantomData<T>,
+}
+
+impl<T> Pool<T> {
+    /// Creates an empty pool; the first block is allocated on first use.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::with_block_capacity_in(DEFAULT_BLOCK_CAPACITY, Global)
+    }
+
+    /// Creates an empty pool that allocates blocks of `block_capacity` slots.
+    #[inline(always)]
+    pub const fn with_block_capacity(block_capacity: usize) -> Self {
+        Self::with_block_capacity_in(block_capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> Pool<T, A> {
+    const SLOT_SIZE: usize = core::mem::size_of::<Slot<T>>();
+    const SLOT_ALIGN: usize = core::mem::align_of::<Slot<T>>();
+
+    /// Offset of the first slot, after the block header.
+    const SLOTS_OFFSET: usize =
+        core::mem::size_of::<Block>().next_multiple_of(core::mem::align_of::<Slot<T>>());
+
+    /// Alignment of a whole block.
+    const BLOCK_ALIGN: usize = if Self::SLOT_ALIGN > core::mem::align_of::<Block>() {
+        Self::SLOT_ALIGN
+    } else {
+        core::mem::align_of::<Block>()
+    };
+
+    /// Creates an empty pool that allocates blocks of `block_capacity` slots from `allocator`.
+    ///
+    /// # Panics
+    /// Panics if `block_capacity` is 0.
+    #[inline(always)]
+    pub const fn with_block_capacity_in(block_capacity: usize, allocator: A) -> Self {
+        assert!(block_capacity > 0, "Block capacity must not be 0");
+
+        Self {
+            free: Cell::new(core::ptr::null_mut()),
+            blocks: Cell::new(core::ptr::null_mut()),
+            block_capacity,
+            live: Cell::new(0),
+            high_water: Cell::new(0),
+            capacity: Cell::new(0),
+            allocator,
+
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of slots in use.
+    #[inline(always)]
+    pub fn live(&self) -> usize {
+        self.live.get()
+    }
+
+    /// Returns the largest number of slots ever in use at once.
+    #[inline(always)]
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water.get()
+    }
+
+    /// Returns the number of slots across all blocks.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.capacity.get()
+    }
+
+    /// Moves `value` into a free slot, allocating a new block if there is none.
+    #[inline]
+    pub fn alloc(&self, value: T) -> NonNull<T> {
+        self.try_alloc(value)
+            .unwrap_or_else(|error| handle_alloc_error(error))
+    }
+
+    /// Moves `value` into a free slot, or returns an error if allocating a new block fails.
+    #[inline]
+    pub fn try_alloc(&self, value: T) -> Result<NonNull<T>, AllocError> {
+        let slot = self.pop()?.cast::<T>();
+
+        // SAFETY: The slot is free, and large and aligned enough for a `T`
+        unsafe { slot.as_ptr().write(value) };
+
+        Ok(slot)
+    }
+
+    /// Moves the value out of the slot at `ptr` and returns the slot to the pool.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by `alloc` or `try_alloc` on this pool and not freed since.
+    #[inline]
+    pub unsafe fn free(&self, ptr: NonNull<T>) -> T {
+        // SAFETY: `ptr` points to a live value in one of this pool's slots
+        unsafe {
+            let value = ptr.as_ptr().read();
+
+            self.push(ptr.cast());
+
+            value
+        }
+    }
+
+    /// Returns every slot to the pool, keeping its blocks for reuse.
+    ///
+    /// Values still in the pool are not dropped.
+    pub fn reset(&mut self) {
+        self.free.set(core::ptr::null_mut());
+        self.live.set(0);
+
+        let mut block = self.blocks.get();
+
+        while !block.is_null() {
+            // SAFETY: Every block in the list is live
+            unsafe {
+                self.link_slots(block);
+
+                block = (*block).next;
+            }
+        }
+    }
+
+    /// Takes a slot from the free list, allocating a new block if it is empty.
+    #[inline]
+    fn pop(&self) -> Result<NonNull<Slot<T>>, AllocError> {
+        if self.free.get().is_null() {
+            self.grow()?;
+        }
+
+        let slot = self.free.get();
+
+        // SAFETY: `slot` is a free slot, so it holds the link to the next one
+        unsafe { self.free.set((*slot).next) };
+
+        let live = self.live.get() + 1;
+
+        self.live.set(live);
+        self.high_water.set(self.high_water.get().max(live));
+
+        // SAFETY: The free list is not empty after growing
+        Ok(unsafe { NonNull::new_unchecked(slot) })
+    }
+
+    /// Puts `slot` back at the head of the free list.
+    ///
+    /// # SAFETY
+    /// `slot` must be one of this pool's slots and not already free.
+    #[inline]
+    unsafe fn push(&self, slot: NonNull<Slot<T>>) {
+        unsafe { (*slot.as_ptr()).next = self.free.get() };
+
+        self.free.set(slot.as_ptr());
+        self.live.set(self.live.get() - 1);
+    }
+
+    /// Allocates a new block and adds all of its slots to the free list.
+    fn grow(&self) -> Result<(), AllocError> {
+        let size = Self::SLOT_SIZE
+            .checked_mul(self.block_capacity)
+            .and_then(|size| size.checked_add(Self::SLOTS_OFFSET))
+            .ok_or(AllocError::CapacityOverflow)?;
+
+        let block = self.allocator.allocate(size, Self::BLOCK_ALIGN)?.as_ptr() as *mut Block;
+
+        // SAFETY: `block` was just allocated with room for the header and its slots
+        unsafe {
+            block.write(Block {
+                next: self.blocks.get(),
+            });
+
+            self.link_slots(block);
+        }
+
+        self.blocks.set(block);
+        self.capacity.set(self.capacity.get() + self.block_capacity);
+
+        Ok(())
+    }
+
+    /// Pushes every slot of `block` onto the free list, without touching `live`.
+    ///
+    /// # SAFETY
+    /// `block` must be a live block of this pool whose slots are not in the free list.
+    unsafe fn link_slots(&self, block: *mut Block) {
+        unsafe {
+            let slots = (block as *mut u8).add(Self::SLOTS_OFFSET) as *mut Slot<T>;
+
+            // Link backwards so slots are handed out in address order
+            for i in (0..self.block_capacity).rev() {
+                let slot = slots.add(i);
+
+                (*slot).next = self.free.get();
+
+                self.free.set(slot);
+            }
+        }
+    }
+
+    /// Returns `true` if a request of `size` bytes aligned to `align` fits in a slot.
+    #[inline(always)]
+    const fn fits(size: usize, align: usize) -> bool {
+        size <= Self::SLOT_SIZE && align <= Self::SLOT_ALIGN
+    }
+}
+
+impl<T> Default for Pool<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T, A: Allocator> Allocator for Pool<T, A> {
+    /// Serves requests that fit in a slot from the pool, and forwards larger ones.
+    #[inline]
+    fn allocate(&self, size: usize, align: usize) -> Result<NonNull<u8>, AllocError> {
+        if Self::fits(size, align) {
+            return Ok(self.pop()?.cast());
+        }
+
+        self.allocator.allocate(size, align)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, size: usize, align: usize) {
+        if Self::fits(size, align) {
+            // SAFETY: Requests that fit were served from a slot of this pool
+            return unsafe { self.push(ptr.cast()) };
+        }
+
+        unsafe { self.allocator.deallocate(ptr, size, align) };
+    }
+}
+
+impl<T, A: Allocator> Drop for Pool<T, A> {
+    fn drop(&mut self) {
+        let size = Self::SLOT_SIZE * self.block_capacity + Self::SLOTS_OFFSET;
+        let mut block = self.blocks.get();
+
+        while !block.is_null() {
+            // SAFETY: `block` is live and was allocated with `size` bytes
+            unsafe {
+                let next = (*block).next;
+
+                self.allocator.deallocate(
+                    NonNull::new_unchecked(block as *mut u8),
+                    size,
+                    Self::BLOCK_ALIGN,
+                );
+
+                block = next;
+            }
+        }
+    }
+}
@@ -31,3 +31,32 @@ unsafe extern "C" fn _dealloc_raw(ptr: *mut u8, size: usize, align: usize) {
 
     std::alloc::dealloc(ptr, layout);
 }
+
+#[no_mangle]
+unsafe extern "C" fn _realloc_raw(
+    ptr: *mut u8,
+    old_size: usize,
+    align: usize,
+    new_size: usize,
+) -> *mut u8 {
+    let layout = std::alloc::Layout::from_size_align_unchecked(old_size, align);
+
+    std::alloc::realloc(ptr, layout, new_size)
+}
+
+// ------------------------------ AllocStats ------------------------------- //
+
+#[cfg(feature = "alloc-debug")]
+impl std::fmt::Debug for crate::alloc_debug::AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AllocStats")
+            .field("live_allocations", &self.live_allocations)
+            .field("live_bytes", &self.live_bytes)
+            .field("peak_allocations", &self.peak_allocations)
+            .field("peak_bytes", &self.peak_bytes)
+            .field("total_allocations", &self.total_allocations)
+            .field("untracked_allocations", &self.untracked_allocations)
+            .field("overflowed", &self.overflowed)
+            .finish()
+    }
+}
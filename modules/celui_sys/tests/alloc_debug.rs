@@ -0,0 +1,201 @@
+//! Tests for the `alloc-debug` tracker: statistics, double frees, size mismatches and a full
+//! table.
+//!
+//! The statistics are global, so every test holds `SERIAL` while it allocates.
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Mutex, MutexGuard},
+};
+
+use celui_sys::{
+    alloc::{self, Allocator, Global},
+    alloc_debug::{self, MAX_TRACKED},
+};
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Waits for the other tests to stop allocating, even if one of them panicked.
+fn serial() -> MutexGuard<'static, ()> {
+    SERIAL.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Runs `f`, which must panic, and returns its panic message.
+fn panic_message<F: FnOnce()>(f: F) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(f)).expect_err("Expected a panic");
+
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn stats_follow_allocations() {
+    let _serial = serial();
+    let before = alloc_debug::stats();
+
+    // SAFETY: Every pointer is freed once, with the count it was allocated with
+    unsafe {
+        let small = alloc::alloc_many::<u32>(16);
+        let large = alloc::alloc_many::<u64>(100);
+
+        let during = alloc_debug::stats();
+
+        assert_eq!(during.live_allocations, before.live_allocations + 2);
+        assert_eq!(during.live_bytes, before.live_bytes + 64 + 800);
+        assert_eq!(during.total_allocations, before.total_allocations + 2);
+        assert!(during.peak_bytes >= during.live_bytes);
+
+        let mut found = 0;
+
+        alloc_debug::for_each_live(|ptr, size, align| {
+            if ptr == small as *const u8 {
+                assert_eq!((size, align), (64, 4));
+                found += 1;
+            } else if ptr == large as *const u8 {
+                assert_eq!((size, align), (800, 8));
+                found += 1;
+            }
+        });
+
+        assert_eq!(found, 2);
+
+        // A reallocation counts as a new allocation and moves the bytes over
+        let grown = alloc::realloc_many(large, 100, 200);
+
+        let after_realloc = alloc_debug::stats();
+
+        assert_eq!(after_realloc.live_bytes, during.live_bytes + 800);
+        assert_eq!(
+            after_realloc.total_allocations,
+            during.total_allocations + 1
+        );
+
+        alloc::dealloc_many(small, 16);
+        alloc::dealloc_many(grown, 200);
+    }
+
+    let after = alloc_debug::stats();
+
+    assert_eq!(after.live_allocations, before.live_allocations);
+    assert_eq!(after.live_bytes, before.live_bytes);
+}
+
+#[test]
+fn a_double_free_panics_before_reaching_the_allocator() {
+    let _serial = serial();
+
+    // SAFETY: The second free is caught by the tracker, which panics before freeing again
+    unsafe {
+        let ptr = alloc::alloc_many::<u32>(8);
+
+        alloc::dealloc_many(ptr, 8);
+
+        let message = panic_message(|| alloc::dealloc_many(ptr, 8));
+
+        assert!(message.contains("not live"), "{message}");
+    }
+
+    // The lock was released by the panic, so the allocator still works
+    let ptr = Global.allocate(32, 8).unwrap();
+
+    // SAFETY: `ptr` is freed once, with the size and alignment it was allocated with
+    unsafe { Global.deallocate(ptr, 32, 8) };
+}
+
+#[test]
+fn a_size_or_alignment_mismatch_panics_and_keeps_the_allocation() {
+    let _serial = serial();
+    let before = alloc_debug::stats();
+    let ptr = Global.allocate(48, 16).unwrap();
+
+    // SAFETY: Both mismatched frees and the mismatched reallocation panic before reaching the
+    // allocator, and `ptr` is then freed once with the right size and alignment
+    unsafe {
+        let message = panic_message(|| Global.deallocate(ptr, 40, 16));
+
+        assert!(
+            message.contains("allocated as 48 bytes aligned to 16"),
+            "{message}"
+        );
+
+        let message = panic_message(|| Global.deallocate(ptr, 48, 8));
+
+        assert!(
+            message.contains("allocated as 48 bytes aligned to 16"),
+            "{message}"
+        );
+
+        let message = panic_message(|| {
+            let _ = Global.reallocate(ptr, 64, 16, 128);
+        });
+
+        assert!(
+            message.contains("allocated as 48 bytes aligned to 16"),
+            "{message}"
+        );
+
+        assert_eq!(
+            alloc_debug::stats().live_allocations,
+            before.live_allocations + 1
+        );
+
+        Global.deallocate(ptr, 48, 16);
+    }
+
+    assert_eq!(
+        alloc_debug::stats().live_allocations,
+        before.live_allocations
+    );
+}
+
+#[test]
+fn allocations_beyond_the_table_are_counted_but_not_tracked() {
+    let _serial = serial();
+    let before = alloc_debug::stats();
+    let count = MAX_TRACKED + 10;
+
+    // SAFETY: Every pointer is freed once, with the count it was allocated with
+    let ptrs: Vec<_> = (0..count)
+        .map(|_| unsafe { alloc::alloc_many::<u64>(1) })
+        .collect();
+
+    let full = alloc_debug::stats();
+    let untracked = before.live_allocations + count - MAX_TRACKED;
+
+    assert!(full.overflowed);
+    assert_eq!(full.untracked_allocations, untracked);
+    assert_eq!(full.live_allocations, before.live_allocations + count);
+
+    let mut listed = 0;
+
+    alloc_debug::for_each_live(|_, _, _| listed += 1);
+    assert_eq!(listed, MAX_TRACKED);
+
+    for ptr in ptrs {
+        // SAFETY: As above
+        unsafe { alloc::dealloc_many(ptr, 1) };
+    }
+
+    let after = alloc_debug::stats();
+
+    // The flag stays set, but once everything is freed, frees are checked again
+    assert!(after.overflowed);
+    assert_eq!(after.untracked_allocations, 0);
+    assert_eq!(after.live_allocations, before.live_allocations);
+
+    // SAFETY: The second free is caught, as in `a_double_free_panics_before_reaching_the_allocator`
+    unsafe {
+        let ptr = alloc::alloc_many::<u64>(1);
+
+        alloc::dealloc_many(ptr, 1);
+
+        let message = panic_message(|| alloc::dealloc_many(ptr, 1));
+
+        assert!(
+            message.contains("the table overflowed earlier"),
+            "{message}"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Tests for `Arena`: chunk growth and reuse, in-place resizing of the most recent allocation,
+//! and random allocations that must never overlap.
+
+use std::ptr::NonNull;
+
+use celui_sys::{alloc::Allocator, arena::Arena};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Fills `size` bytes at `ptr` with `byte`.
+fn fill(ptr: NonNull<u8>, size: usize, byte: u8) {
+    // SAFETY: The callers pass live allocations of at least `size` bytes
+    unsafe { ptr.as_ptr().write_bytes(byte, size) };
+}
+
+/// Returns `true` if all `size` bytes at `ptr` are `byte`.
+fn holds(ptr: NonNull<u8>, size: usize, byte: u8) -> bool {
+    // SAFETY: The callers pass live allocations of at least `size` bytes
+    unsafe { std::slice::from_raw_parts(ptr.as_ptr(), size) }
+        .iter()
+        .all(|&other| other == byte)
+}
+
+#[test]
+fn chunks_are_added_as_needed_and_reused_after_reset() {
+    let mut arena = Arena::with_chunk_size(256);
+
+    assert_eq!(arena.chunk_count(), 0);
+
+    for frame in 0..4 {
+        // Two 100-byte blocks fit in a chunk, the third starts a new one
+        for _ in 0..6 {
+            arena.allocate(100, 8).unwrap();
+        }
+
+        // After the first frame, the large chunk is skipped over while looking for room
+        assert_eq!(arena.chunk_count(), if frame == 0 { 3 } else { 4 });
+        assert_eq!(arena.allocated_bytes(), 600);
+
+        // Larger than a chunk, so it gets one of its own on the first frame only
+        let large = arena.allocate(1000, 16).unwrap();
+
+        assert_eq!(large.as_ptr() as usize % 16, 0);
+        assert_eq!(arena.chunk_count(), 4);
+        assert!(arena.capacity() >= 3 * 256 + 1000);
+
+        fill(large, 1000, frame);
+        assert!(holds(large, 1000, frame));
+
+        arena.reset();
+
+        assert_eq!(arena.allocated_bytes(), 0);
+        assert_eq!(arena.high_water_mark(), 1600);
+    }
+}
+
+#[test]
+fn the_most_recent_allocation_is_resized_in_place() {
+    let arena = Arena::with_chunk_size(1024);
+    let first = arena.allocate(64, 8).unwrap();
+    let last = arena.allocate(64, 8).unwrap();
+
+    fill(first, 64, 1);
+    fill(last, 64, 2);
+
+    // SAFETY: Every pointer is resized with the size it currently has
+    unsafe {
+        let grown = arena.reallocate(last, 64, 8, 512).unwrap();
+
+        assert_eq!(grown, last);
+        assert_eq!(arena.allocated_bytes(), 576);
+        assert!(holds(grown, 64, 2));
+
+        let shrunk = arena.reallocate(grown, 512, 8, 32).unwrap();
+
+        assert_eq!(shrunk, last);
+        assert_eq!(arena.allocated_bytes(), 96);
+
+        // Not the most recent one, so it is moved and copied
+        let moved = arena.reallocate(first, 64, 8, 128).unwrap();
+
+        assert_ne!(moved, first);
+        assert!(holds(moved, 64, 1));
+        assert_eq!(arena.allocated_bytes(), 96 + 128);
+
+        // The most recent one, but the chunk is too small, so it moves to a new chunk
+        let spilled = arena.reallocate(moved, 128, 8, 2048).unwrap();
+
+        assert_ne!(spilled, moved);
+        assert!(holds(spilled, 64, 1));
+        assert_eq!(arena.chunk_count(), 2);
+
+        // Freeing the most recent allocation gives its bytes back, freeing another does not
+        let before = arena.allocated_bytes();
+
+        arena.deallocate(last, 32, 8);
+        assert_eq!(arena.allocated_bytes(), before);
+
+        arena.deallocate(spilled, 2048, 8);
+        assert_eq!(arena.allocated_bytes(), before - 2048);
+    }
+}
+
+#[test]
+fn random_allocations_never_overlap() {
+    let mut arena = Arena::with_chunk_size(4096);
+    let mut chunks = 0;
+
+    for frame in 0..20u64 {
+        // Frames cycle through four sequences, so once each has run no chunk is added
+        let mut rng = Rng((frame % 4 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut blocks: Vec<(NonNull<u8>, usize, u8)> = Vec::new();
+        let mut expected = 0;
+
+        for i in 0..300 {
+            let size = rng.below(300) as usize + 1;
+            let align = 1 << rng.below(7);
+            let ptr = arena.allocate(size, align).unwrap();
+            let byte = (i % 251) as u8;
+
+            assert_eq!(ptr.as_ptr() as usize % align, 0);
+
+            fill(ptr, size, byte);
+            blocks.push((ptr, size, byte));
+            expected += size;
+        }
+
+        assert!(blocks
+            .iter()
+            .all(|&(ptr, size, byte)| holds(ptr, size, byte)));
+        assert_eq!(arena.allocated_bytes(), expected);
+        assert!(arena.capacity() >= expected);
+
+        if frame >= 4 {
+            assert_eq!(arena.chunk_count(), chunks);
+        }
+
+        chunks = arena.chunk_count();
+        arena.reset();
+    }
+
+    assert!(arena.capacity() >= arena.high_water_mark());
+}
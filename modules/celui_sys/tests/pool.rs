@@ -0,0 +1,171 @@
+//! Tests for `Pool`: free-list reuse, block growth, and random churn that must never mix up
+//! the values of live slots.
+
+use std::{cell::Cell, ptr::NonNull, rc::Rc};
+
+use celui_sys::{alloc::Allocator, pool::Pool};
+
+/// A xorshift generator, so failures reproduce from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+#[test]
+fn freed_slots_are_reused_last_in_first_out() {
+    let pool = Pool::with_block_capacity(8);
+    let slots: Vec<_> = (0..8u64).map(|value| pool.alloc(value)).collect();
+
+    assert_eq!(pool.capacity(), 8);
+
+    // SAFETY: Every pointer came from this pool and is freed once
+    unsafe {
+        assert_eq!(pool.free(slots[2]), 2);
+        assert_eq!(pool.free(slots[5]), 5);
+    }
+
+    assert_eq!(pool.live(), 6);
+    assert_eq!(pool.alloc(50), slots[5]);
+    assert_eq!(pool.alloc(20), slots[2]);
+
+    // The block is full again, so a new one is added
+    let extra = pool.alloc(100);
+
+    assert!(!slots.contains(&extra));
+    assert_eq!(pool.capacity(), 16);
+    assert_eq!(pool.high_water_mark(), 9);
+
+    // SAFETY: As above
+    unsafe {
+        assert_eq!(pool.free(extra), 100);
+
+        for (i, &slot) in slots.iter().enumerate() {
+            let expected = [0, 1, 20, 3, 4, 50, 6, 7][i];
+
+            assert_eq!(pool.free(slot), expected);
+        }
+    }
+
+    assert_eq!(pool.live(), 0);
+}
+
+#[test]
+fn random_churn_keeps_every_value_intact() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    let mut pool = Pool::with_block_capacity(16);
+
+    for _ in 0..5 {
+        let mut live: Vec<(NonNull<[u64; 3]>, u64)> = Vec::new();
+        let mut most_live = 0;
+
+        for step in 0..5000u64 {
+            if live.is_empty() || rng.below(5) < 3 {
+                let ptr = pool.alloc([step, !step, step * 3]);
+
+                assert!(live.iter().all(|&(other, _)| other != ptr));
+
+                live.push((ptr, step));
+            } else {
+                let (ptr, step) = live.swap_remove(rng.below(live.len() as u64) as usize);
+
+                // SAFETY: `ptr` came from this pool and is freed once
+                assert_eq!(unsafe { pool.free(ptr) }, [step, !step, step * 3]);
+            }
+
+            most_live = most_live.max(live.len());
+
+            assert_eq!(pool.live(), live.len());
+        }
+
+        // SAFETY: Every pointer is live
+        assert!(live
+            .iter()
+            .all(|&(ptr, step)| unsafe { ptr.as_ptr().read() } == [step, !step, step * 3]));
+
+        // Blocks are only added once every slot is in use
+        assert!(pool.capacity() < pool.high_water_mark() + 16);
+        assert!(pool.high_water_mark() >= most_live);
+
+        let capacity = pool.capacity();
+
+        pool.reset();
+
+        assert_eq!(pool.live(), 0);
+        assert_eq!(pool.capacity(), capacity);
+    }
+}
+
+/// A value that counts how many of its kind are alive.
+struct Counted(Rc<Cell<i64>>);
+
+impl Counted {
+    fn new(live: &Rc<Cell<i64>>) -> Self {
+        live.set(live.get() + 1);
+
+        Self(live.clone())
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
+#[test]
+fn values_are_moved_out_on_free_and_never_dropped_by_the_pool() {
+    let live = Rc::new(Cell::new(0));
+    let pool = Pool::with_block_capacity(4);
+    let slots: Vec<_> = (0..10).map(|_| pool.alloc(Counted::new(&live))).collect();
+
+    assert_eq!(live.get(), 10);
+
+    // SAFETY: Every pointer came from this pool and is freed once
+    for &slot in &slots[..6] {
+        drop(unsafe { pool.free(slot) });
+    }
+
+    assert_eq!(live.get(), 4);
+
+    // Values still in the pool are leaked, like `reset` documents
+    drop(pool);
+    assert_eq!(live.get(), 4);
+}
+
+#[test]
+fn small_requests_are_served_from_slots_and_large_ones_forwarded() {
+    let pool: Pool<[u64; 4]> = Pool::with_block_capacity(4);
+
+    // SAFETY: Every pointer is freed once, with the size and alignment it was allocated with
+    unsafe {
+        let small = pool.allocate(24, 8).unwrap();
+
+        assert_eq!(pool.live(), 1);
+        assert_eq!(pool.capacity(), 4);
+
+        let large = pool.allocate(4096, 8).unwrap();
+
+        assert_eq!(pool.live(), 1);
+
+        large.as_ptr().write_bytes(0xAB, 4096);
+        pool.deallocate(large, 4096, 8);
+
+        pool.deallocate(small, 24, 8);
+        assert_eq!(pool.live(), 0);
+
+        // The slot that was just freed is handed out again
+        assert_eq!(pool.allocate(32, 8).unwrap(), small);
+        pool.deallocate(small, 32, 8);
+    }
+}